@@ -62,11 +62,49 @@ fn bench_bloom_filter_false_positive_rates(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_bloom_filter_batch(c: &mut Criterion) {
+    let items: Vec<u64> = (0..100_000).collect();
+    let probes: Vec<u64> = (50_000..150_000).collect();
+    let mut filter = BloomFilter::new(items.len(), 0.01);
+    filter.insert_batch(&items);
+
+    let mut group = c.benchmark_group("bloom_filter_batch_100000");
+    group.bench_function("contains_per_item", |b| {
+        b.iter(|| {
+            probes
+                .iter()
+                .map(|probe| filter.contains(black_box(probe)))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("contains_batch", |b| {
+        b.iter(|| filter.contains_batch(black_box(&probes)))
+    });
+    group.bench_function("insert_per_item", |b| {
+        b.iter(|| {
+            let mut fresh = BloomFilter::new(items.len(), 0.01);
+            for item in &items {
+                fresh.insert(black_box(item));
+            }
+            fresh
+        })
+    });
+    group.bench_function("insert_batch", |b| {
+        b.iter(|| {
+            let mut fresh = BloomFilter::new(items.len(), 0.01);
+            fresh.insert_batch(black_box(&items));
+            fresh
+        })
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_bloom_filter_insert,
     bench_bloom_filter_contains,
     bench_bloom_filter_different_sizes,
-    bench_bloom_filter_false_positive_rates
+    bench_bloom_filter_false_positive_rates,
+    bench_bloom_filter_batch
 );
 criterion_main!(benches);
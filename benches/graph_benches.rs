@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use proptest::prelude::Rng;
+use proptest::test_runner::{RngAlgorithm, TestRng};
+use rust_ds_lib_bee::graph::adjacency_list::GraphType;
+use rust_ds_lib_bee::Graph;
+
+fn million_edge_graph() -> Graph<u32> {
+    let mut graph = Graph::with_vertex_capacity(100_000, GraphType::Directed);
+    let mut rng = TestRng::deterministic_rng(RngAlgorithm::ChaCha);
+    while graph.edge_count() < 1_000_000 {
+        graph.add_edge(rng.random_range(0..100_000), rng.random_range(0..100_000));
+    }
+    graph
+}
+
+fn graph_load_benchmark(c: &mut Criterion) {
+    let graph = million_edge_graph();
+    let mut binary = Vec::new();
+    graph.write_binary(&mut binary).unwrap();
+    let dot = graph.to_dot();
+
+    let mut group = c.benchmark_group("graph_load_1m_edges");
+    group.sample_size(10);
+    group.bench_function("read_binary", |b| {
+        b.iter(|| black_box(Graph::<u32>::read_binary(black_box(binary.as_slice())).unwrap()))
+    });
+    group.bench_function("from_dot", |b| {
+        b.iter(|| black_box(Graph::from_dot(black_box(&dot)).unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, graph_load_benchmark);
+criterion_main!(benches);
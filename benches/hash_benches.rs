@@ -0,0 +1,35 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_ds_lib_bee::HashSet;
+
+fn set_operations_benchmark(c: &mut Criterion) {
+    let a: HashSet<_> = (0..10_000).collect();
+    let b: HashSet<_> = (5_000..15_000).collect();
+
+    // Results are pre-sized from the operand lengths, so none of them rehash
+    for result in [
+        a.union(&b),
+        a.intersection(&b),
+        a.difference(&b),
+        a.symmetric_difference(&b),
+    ] {
+        assert_eq!(result.resize_count(), 0);
+    }
+
+    let mut group = c.benchmark_group("hash_set_operations_10000");
+    group.bench_function("union", |bench| {
+        bench.iter(|| black_box(a.union(black_box(&b))))
+    });
+    group.bench_function("intersection", |bench| {
+        bench.iter(|| black_box(a.intersection(black_box(&b))))
+    });
+    group.bench_function("difference", |bench| {
+        bench.iter(|| black_box(a.difference(black_box(&b))))
+    });
+    group.bench_function("symmetric_difference", |bench| {
+        bench.iter(|| black_box(a.symmetric_difference(black_box(&b))))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, set_operations_benchmark);
+criterion_main!(benches);
@@ -1,5 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rust_ds_lib_bee::{AvlTree, BinaryHeap, BinarySearchTree, HashMap, PriorityQueue, Trie};
+use rust_ds_lib_bee::{
+    AvlTree, BinaryHeap, BinarySearchTree, HashMap, OpenHashMap, PriorityQueue, Trie,
+};
 
 fn bst_insert_benchmark(c: &mut Criterion) {
     c.bench_function("bst_insert_1000", |b| {
@@ -70,6 +72,32 @@ fn hashmap_get_benchmark(c: &mut Criterion) {
     });
 }
 
+fn hashmap_get_comparison_benchmark(c: &mut Criterion) {
+    let mut chained = HashMap::new();
+    let mut open = OpenHashMap::new();
+    for i in 0..10_000 {
+        chained.insert(i, i * 10);
+        open.insert(i, i * 10);
+    }
+
+    let mut group = c.benchmark_group("hashmap_get_10000");
+    group.bench_function("chaining", |b| {
+        b.iter(|| {
+            for i in 0..10_000 {
+                black_box(chained.get(&black_box(i)));
+            }
+        })
+    });
+    group.bench_function("open_addressing", |b| {
+        b.iter(|| {
+            for i in 0..10_000 {
+                black_box(open.get(&black_box(i)));
+            }
+        })
+    });
+    group.finish();
+}
+
 fn hashmap_collision_benchmark(c: &mut Criterion) {
     c.bench_function("hashmap_collision_handling", |b| {
         b.iter(|| {
@@ -142,6 +170,7 @@ criterion_group!(
     avl_insert_benchmark,
     hashmap_insert_benchmark,
     hashmap_get_benchmark,
+    hashmap_get_comparison_benchmark,
     hashmap_collision_benchmark,
     heap_benchmark,
     priority_queue_benchmark,
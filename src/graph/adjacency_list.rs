@@ -1,5 +1,6 @@
+use crate::utils::display::{self, DisplayLimited, DISPLAY_LIMIT};
 use crate::utils::{Clear, Size};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::hash::Hash;
 
@@ -9,6 +10,12 @@ pub enum GraphType {
     Undirected,
 }
 
+/// A graph stored as adjacency lists
+///
+/// Undirected edges are stored in both endpoints' lists, except self-loops,
+/// which are stored once. A self-loop counts as one edge in `edge_count` and
+/// is yielded once by `edges()`, contributes 2 to the undirected degree of its
+/// vertex, and makes the graph cyclic.
 pub struct Graph<T> {
     adjacency_list: HashMap<T, Vec<T>>,
     graph_type: GraphType,
@@ -27,6 +34,14 @@ where
         }
     }
 
+    pub fn with_vertex_capacity(capacity: usize, graph_type: GraphType) -> Self {
+        Self {
+            adjacency_list: HashMap::with_capacity(capacity),
+            graph_type,
+            edge_count: 0,
+        }
+    }
+
     pub fn directed() -> Self {
         Self::new(GraphType::Directed)
     }
@@ -46,6 +61,15 @@ where
         }
     }
 
+    /// Adds each vertex that is not already present, returning how many were new
+    pub fn add_vertices<I: IntoIterator<Item = T>>(&mut self, vertices: I) -> usize {
+        vertices
+            .into_iter()
+            .map(|vertex| self.add_vertex(vertex))
+            .filter(|&added| added)
+            .count()
+    }
+
     pub fn add_edge(&mut self, from: T, to: T) -> bool {
         self.add_vertex(from.clone());
         self.add_vertex(to.clone());
@@ -75,10 +99,11 @@ where
         let outgoing_edges = neighbors.len();
         self.edge_count -= outgoing_edges;
 
-        for (_, adj_list) in self.adjacency_list.iter_mut() {
+        for (from, adj_list) in self.adjacency_list.iter_mut() {
             if let Some(pos) = adj_list.iter().position(|x| x == vertex) {
                 adj_list.remove(pos);
-                if self.graph_type == GraphType::Directed {
+                // A directed self-loop was already counted as an outgoing edge
+                if self.graph_type == GraphType::Directed && from != vertex {
                     self.edge_count -= 1;
                 }
             }
@@ -107,6 +132,90 @@ where
         false
     }
 
+    /// Contracts the edge between `u` and `v` into a single vertex `merged_label`
+    ///
+    /// Edges of both endpoints are redirected to the merged vertex. The
+    /// contracted edge and any other edge between `u` and `v` disappear instead
+    /// of becoming self-loops, and parallel edges collapse into one since the
+    /// graph stores at most one edge per pair. Returns `false` and leaves the
+    /// graph unchanged if there is no edge `u -> v`.
+    pub fn contract_edge(&mut self, u: &T, v: &T, merged_label: T) -> bool {
+        if !self.has_edge(u, v) {
+            return false;
+        }
+        self.merge_vertices(&[u.clone(), v.clone()], merged_label)
+    }
+
+    /// Replaces every vertex of `group` with a single vertex `label`
+    ///
+    /// Edges leaving or entering the group are redirected to `label` and
+    /// edges inside the group are dropped. If `label` is already a vertex
+    /// outside `group` it is merged in as well. Returns `false` if no vertex
+    /// of `group` is in the graph.
+    pub fn merge_vertices(&mut self, group: &[T], label: T) -> bool {
+        let mut members: HashSet<T> = group
+            .iter()
+            .filter(|vertex| self.has_vertex(vertex))
+            .cloned()
+            .collect();
+        if members.is_empty() {
+            return false;
+        }
+        if self.has_vertex(&label) {
+            members.insert(label.clone());
+        }
+
+        let outgoing: Vec<T> = members
+            .iter()
+            .flat_map(|vertex| &self.adjacency_list[vertex])
+            .filter(|neighbor| !members.contains(*neighbor))
+            .cloned()
+            .collect();
+        let incoming: Vec<T> = match self.graph_type {
+            GraphType::Directed => self
+                .adjacency_list
+                .iter()
+                .filter(|(from, list)| {
+                    !members.contains(*from) && list.iter().any(|to| members.contains(to))
+                })
+                .map(|(from, _)| from.clone())
+                .collect(),
+            GraphType::Undirected => Vec::new(),
+        };
+
+        for vertex in &members {
+            self.remove_vertex(vertex);
+        }
+        self.add_vertex(label.clone());
+        for to in outgoing {
+            self.add_edge(label.clone(), to);
+        }
+        for from in incoming {
+            self.add_edge(from, label.clone());
+        }
+        true
+    }
+
+    /// Replaces the edge `from -> to` with `to -> from`, keeping `edge_count`
+    ///
+    /// Returns `false` and leaves the graph unchanged if the edge is absent or
+    /// its reverse already exists. Undirected edges have no direction, so on
+    /// an undirected graph this only reports whether the edge exists.
+    pub fn reverse_edge(&mut self, from: &T, to: &T) -> bool {
+        if !self.has_edge(from, to) {
+            return false;
+        }
+        if self.graph_type == GraphType::Undirected || from == to {
+            return true;
+        }
+        if self.has_edge(to, from) {
+            return false;
+        }
+
+        self.remove_edge(from, to);
+        self.add_edge(to.clone(), from.clone())
+    }
+
     pub fn has_vertex(&self, vertex: &T) -> bool {
         self.adjacency_list.contains_key(vertex)
     }
@@ -121,11 +230,17 @@ where
         self.adjacency_list.get(vertex)
     }
 
+    /// Returns an owned copy of the neighbors, so the graph can be mutated
+    /// while iterating them. Missing vertices have no neighbors.
+    pub fn neighbors_cloned(&self, vertex: &T) -> Vec<T> {
+        self.adjacency_list.get(vertex).cloned().unwrap_or_default()
+    }
+
     pub fn vertices(&self) -> impl Iterator<Item = &T> {
         self.adjacency_list.keys()
     }
 
-    pub fn edges(&self) -> EdgeIterator<T> {
+    pub fn edges(&self) -> EdgeIterator<'_, T> {
         EdgeIterator::new(self)
     }
 
@@ -141,14 +256,39 @@ where
         &self.graph_type
     }
 
+    pub fn density(&self) -> f64 {
+        let vertices = self.vertex_count();
+        if vertices < 2 {
+            return 0.0;
+        }
+
+        let max_edges = match self.graph_type {
+            GraphType::Directed => vertices * (vertices - 1),
+            GraphType::Undirected => vertices * (vertices - 1) / 2,
+        };
+        self.edge_count as f64 / max_edges as f64
+    }
+
+    /// Returns the number of edge endpoints at `vertex`
+    ///
+    /// For directed graphs this is the out-degree. In undirected graphs a
+    /// self-loop touches the vertex twice, so it contributes 2.
     pub fn degree(&self, vertex: &T) -> Option<usize> {
-        self.adjacency_list.get(vertex).map(|list| list.len())
+        self.adjacency_list.get(vertex).map(|list| {
+            let self_loop = self.graph_type == GraphType::Undirected && list.contains(vertex);
+            list.len() + usize::from(self_loop)
+        })
     }
 
+    /// Returns the number of edges ending at `vertex`; equal to
+    /// [`Graph::degree`] for undirected graphs
     pub fn in_degree(&self, vertex: &T) -> Option<usize> {
         if !self.has_vertex(vertex) {
             return None;
         }
+        if self.graph_type == GraphType::Undirected {
+            return self.degree(vertex);
+        }
 
         let count = self
             .adjacency_list
@@ -162,8 +302,201 @@ where
     pub fn out_degree(&self, vertex: &T) -> Option<usize> {
         self.degree(vertex)
     }
+
+    /// Returns a vertex of highest [`Graph::degree`] together with that degree
+    ///
+    /// For directed graphs this is the out-degree. Ties are broken
+    /// arbitrarily; returns `None` for an empty graph.
+    pub fn max_degree_vertex(&self) -> Option<(&T, usize)> {
+        self.vertex_degrees().max_by_key(|&(_, degree)| degree)
+    }
+
+    /// Returns a vertex of lowest [`Graph::degree`] together with that degree
+    ///
+    /// For directed graphs this is the out-degree. Ties are broken
+    /// arbitrarily; returns `None` for an empty graph.
+    pub fn min_degree_vertex(&self) -> Option<(&T, usize)> {
+        self.vertex_degrees().min_by_key(|&(_, degree)| degree)
+    }
+
+    fn vertex_degrees(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.adjacency_list
+            .keys()
+            .filter_map(|vertex| Some((vertex, self.degree(vertex)?)))
+    }
+
+    /// Returns the vertices with no incident edges
+    ///
+    /// For directed graphs a vertex must have neither outgoing nor incoming
+    /// edges to count as isolated.
+    pub fn isolated_vertices(&self) -> Vec<&T> {
+        let targets: HashSet<&T> = self.adjacency_list.values().flatten().collect();
+
+        self.adjacency_list
+            .iter()
+            .filter(|(vertex, list)| list.is_empty() && !targets.contains(vertex))
+            .map(|(vertex, _)| vertex)
+            .collect()
+    }
+
+    /// Returns an iterator over the vertices satisfying `pred`
+    pub fn vertices_where<F>(&self, mut pred: F) -> impl Iterator<Item = &T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.adjacency_list
+            .keys()
+            .filter(move |vertex| pred(vertex))
+    }
+
+    pub fn clear_edges(&mut self) {
+        for neighbors in self.adjacency_list.values_mut() {
+            neighbors.clear();
+        }
+        self.edge_count = 0;
+    }
+
+    /// Adds every vertex and edge of `other` that is not already present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graphs have different [`GraphType`]s.
+    pub fn union_with(&mut self, other: &Graph<T>) {
+        assert_eq!(
+            self.graph_type, other.graph_type,
+            "cannot merge graphs of different types"
+        );
+
+        for (vertex, neighbors) in &other.adjacency_list {
+            self.add_vertex(vertex.clone());
+            for neighbor in neighbors {
+                self.add_edge(vertex.clone(), neighbor.clone());
+            }
+        }
+    }
+
+    /// Builds a graph containing both inputs side by side.
+    ///
+    /// Every vertex is relabeled with `tag(side, vertex)`, where `side` is 0 for
+    /// `a` and 1 for `b`; `tag` must keep the two sides apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graphs have different [`GraphType`]s.
+    pub fn disjoint_union<U, F>(a: &Graph<T>, b: &Graph<T>, mut tag: F) -> Graph<U>
+    where
+        U: Clone + Eq + Hash,
+        F: FnMut(usize, &T) -> U,
+    {
+        let mut union = a.relabel(|vertex| tag(0, vertex));
+        union.union_with(&b.relabel(|vertex| tag(1, vertex)));
+        union
+    }
+
+    /// Returns a copy of the graph with every vertex passed through `f`.
+    ///
+    /// Vertices that map to the same label are merged, keeping the first edge seen.
+    pub fn relabel<U, F>(&self, mut f: F) -> Graph<U>
+    where
+        U: Clone + Eq + Hash,
+        F: FnMut(&T) -> U,
+    {
+        let mut relabeled = Graph::new(self.graph_type.clone());
+
+        for (vertex, neighbors) in &self.adjacency_list {
+            let from = f(vertex);
+            relabeled.add_vertex(from.clone());
+            for neighbor in neighbors {
+                relabeled.add_edge(from.clone(), f(neighbor));
+            }
+        }
+
+        relabeled
+    }
+
+    /// Compacts the vertex set into dense `0..n` indices.
+    ///
+    /// Returns the indexed graph together with the vertex-to-index map and the
+    /// index-to-vertex table, so results computed on the indexed graph can be
+    /// translated back with [`Graph::from_indexed`].
+    pub fn to_indexed(&self) -> (Graph<usize>, HashMap<T, usize>, Vec<T>) {
+        let vertices: Vec<T> = self.adjacency_list.keys().cloned().collect();
+        let index: HashMap<T, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| (vertex.clone(), i))
+            .collect();
+
+        let indexed = self.relabel(|vertex| index[vertex]);
+        (indexed, index, vertices)
+    }
+
+    /// Translates an indexed graph back to the original vertex labels.
+    ///
+    /// Returns `None` if the graph references an index outside `vertices`.
+    pub fn from_indexed(indexed: &Graph<usize>, vertices: &[T]) -> Option<Self> {
+        if indexed.vertices().any(|&i| i >= vertices.len()) {
+            return None;
+        }
+
+        Some(indexed.relabel(|&i| vertices[i].clone()))
+    }
+
+    /// Returns a snapshot of the adjacency lists keyed by vertex
+    ///
+    /// Isolated vertices map to an empty `Vec`, and undirected edges appear in
+    /// both endpoints' lists.
+    pub fn to_adjacency_map(&self) -> HashMap<T, Vec<T>> {
+        self.adjacency_list.clone()
+    }
+
+    /// Builds a graph from adjacency lists keyed by vertex
+    ///
+    /// Every key becomes a vertex, so isolated vertices are kept. For an
+    /// undirected graph an edge may be listed under one or both endpoints; it
+    /// is counted once either way.
+    pub fn from_adjacency_map(map: HashMap<T, Vec<T>>, graph_type: GraphType) -> Self {
+        let mut graph = Self::with_vertex_capacity(map.len(), graph_type);
+        graph.add_vertices(map.keys().cloned());
+        for (from, neighbors) in map {
+            for to in neighbors {
+                graph.add_edge(from.clone(), to);
+            }
+        }
+        graph
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    /// Returns every vertex paired with its [`Graph::degree`], highest degree
+    /// first; vertices of equal degree are ordered ascending
+    pub fn vertices_by_degree(&self) -> Vec<(&T, usize)> {
+        let mut ranked: Vec<(&T, usize)> = self.vertex_degrees().collect();
+        ranked.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        ranked
+    }
+}
+
+/// Graphs are equal when they have the same type, vertices and edges,
+/// regardless of insertion order
+impl<T: Clone + Eq + Hash> PartialEq for Graph<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.graph_type == other.graph_type
+            && self.edge_count == other.edge_count
+            && self.adjacency_list.len() == other.adjacency_list.len()
+            && self.adjacency_list.iter().all(|(vertex, list)| {
+                other.adjacency_list.get(vertex).is_some_and(|theirs| {
+                    list.len() == theirs.len() && list.iter().all(|to| theirs.contains(to))
+                })
+            })
+    }
 }
 
+impl<T: Clone + Eq + Hash> Eq for Graph<T> {}
+
 impl<T: Clone + Eq + Hash> Default for Graph<T> {
     fn default() -> Self {
         Self::directed()
@@ -196,6 +529,37 @@ impl<T: fmt::Debug + Clone + Eq + Hash> fmt::Debug for Graph<T> {
     }
 }
 
+impl<T: Clone + Eq + Hash + Ord + fmt::Display> DisplayLimited for Graph<T> {
+    /// Writes `V={...} E={...}` with both sets sorted; the limit applies to each
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        let head = limit.saturating_add(1);
+        let vertices = display::smallest_sorted(self.vertices().collect(), head, Ord::cmp);
+        f.write_str("V=")?;
+        display::write_list(f, "{", "}", vertices, limit)?;
+
+        // Undirected edges are stored both ways; show each once, as `a--b`
+        let arrow = match self.graph_type {
+            GraphType::Directed => "->",
+            GraphType::Undirected => "--",
+        };
+        let edges: Vec<_> = self
+            .edges()
+            .filter(|(from, to)| self.graph_type == GraphType::Directed || from <= to)
+            .collect();
+        let edges = display::smallest_sorted(edges, head, Ord::cmp)
+            .into_iter()
+            .map(|(from, to)| display::display_fn(move |f| write!(f, "{from}{arrow}{to}")));
+        f.write_str(" E=")?;
+        display::write_list(f, "{", "}", edges, limit)
+    }
+}
+
+impl<T: Clone + Eq + Hash + Ord + fmt::Display> fmt::Display for Graph<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_limit(f, DISPLAY_LIMIT)
+    }
+}
+
 pub struct EdgeIterator<'a, T> {
     graph: &'a Graph<T>,
     vertex_iter: std::collections::hash_map::Keys<'a, T, Vec<T>>,
@@ -249,7 +613,6 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashSet;
 
     #[test]
     fn new_graph_is_empty() {
@@ -331,6 +694,48 @@ mod tests {
         assert_eq!(graph.in_degree(&2), Some(1));
     }
 
+    #[test]
+    fn vertices_ranked_by_degree() {
+        let mut star = Graph::undirected();
+        for leaf in 1..=4 {
+            star.add_edge(0, leaf);
+        }
+
+        let ranked = star.vertices_by_degree();
+        assert_eq!(ranked[0], (&0, 4));
+        assert_eq!(ranked[1..], [(&1, 1), (&2, 1), (&3, 1), (&4, 1)]);
+    }
+
+    #[test]
+    fn max_and_min_degree_vertex() {
+        let empty: Graph<i32> = Graph::undirected();
+        assert_eq!(empty.max_degree_vertex(), None);
+
+        let mut star = Graph::undirected();
+        for leaf in 1..=5 {
+            star.add_edge(0, leaf);
+        }
+        assert_eq!(star.max_degree_vertex(), Some((&0, 5)));
+        assert_eq!(star.min_degree_vertex().map(|(_, degree)| degree), Some(1));
+
+        star.add_vertex(9);
+        assert_eq!(star.min_degree_vertex(), Some((&9, 0)));
+    }
+
+    #[test]
+    fn isolated_and_filtered_vertices() {
+        let mut graph = Graph::directed();
+        graph.add_edge(1, 2);
+        graph.add_vertex(3);
+
+        // 2 has only an incoming edge, so it is not isolated
+        assert_eq!(graph.out_degree(&2), Some(0));
+        assert_eq!(graph.isolated_vertices(), vec![&3]);
+
+        let even: HashSet<_> = graph.vertices_where(|v| v % 2 == 0).collect();
+        assert_eq!(even, HashSet::from([&2]));
+    }
+
     #[test]
     fn edge_iterator() {
         let mut graph = Graph::directed();
@@ -357,6 +762,80 @@ mod tests {
         assert!(vertices.contains(&&3));
     }
 
+    #[test]
+    fn with_vertex_capacity() {
+        let n = 10_000;
+        let mut hinted = Graph::with_vertex_capacity(n, GraphType::Directed);
+        let mut plain = Graph::directed();
+        let reserved = hinted.adjacency_list.capacity();
+        assert!(reserved >= n);
+
+        for i in 0..n {
+            assert_eq!(
+                hinted.add_edge(i, (i + 1) % n),
+                plain.add_edge(i, (i + 1) % n)
+            );
+        }
+
+        assert_eq!(hinted.adjacency_list.capacity(), reserved);
+        assert_eq!(hinted.vertex_count(), plain.vertex_count());
+        assert_eq!(hinted.edge_count(), plain.edge_count());
+        assert_eq!(hinted.graph_type(), plain.graph_type());
+        for (from, to) in plain.edges() {
+            assert!(hinted.has_edge(from, to));
+        }
+    }
+
+    #[test]
+    fn indexed_round_trip() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "c");
+        graph.add_edge("c", "c");
+        graph.add_vertex("d");
+
+        let (indexed, index, vertices) = graph.to_indexed();
+        assert_eq!(indexed.vertex_count(), 4);
+        assert_eq!(indexed.edge_count(), graph.edge_count());
+
+        let mapped: HashSet<_> = index.keys().collect();
+        let original: HashSet<_> = graph.vertices().collect();
+        assert_eq!(mapped, original);
+        for (i, vertex) in vertices.iter().enumerate() {
+            assert_eq!(index[vertex], i);
+        }
+
+        let restored = Graph::from_indexed(&indexed, &vertices).unwrap();
+        assert_eq!(restored.vertex_count(), graph.vertex_count());
+        assert_eq!(restored.edge_count(), graph.edge_count());
+        for (from, to) in graph.edges() {
+            assert!(restored.has_edge(from, to));
+            assert!(indexed.has_edge(&index[from], &index[to]));
+        }
+
+        assert!(Graph::from_indexed(&indexed, &vertices[..2]).is_none());
+    }
+
+    #[test]
+    fn clear_edges_keeps_vertices() {
+        let mut graph = Graph::undirected();
+        graph.add_vertex(4);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 3);
+
+        graph.clear_edges();
+
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 0);
+        for from in 1..=4 {
+            assert!(graph.has_vertex(&from));
+            for to in 1..=4 {
+                assert!(!graph.has_edge(&from, &to));
+            }
+        }
+    }
+
     #[test]
     fn clear_graph() {
         let mut graph = Graph::directed();
@@ -369,4 +848,276 @@ mod tests {
         assert_eq!(graph.vertex_count(), 0);
         assert_eq!(graph.edge_count(), 0);
     }
+
+    #[test]
+    fn contract_triangle_edge() {
+        let mut triangle = Graph::undirected();
+        triangle.add_edge(1, 2);
+        triangle.add_edge(2, 3);
+        triangle.add_edge(3, 1);
+
+        assert!(!triangle.contract_edge(&1, &4, 14));
+        assert!(triangle.contract_edge(&1, &2, 12));
+        assert_eq!(triangle.vertex_count(), 2);
+        assert_eq!(triangle.edge_count(), 1);
+        assert!(triangle.has_edge(&12, &3));
+        assert!(!triangle.has_edge(&12, &12));
+        assert_eq!(triangle.degree(&12), Some(1));
+        assert_eq!(triangle.degree(&3), Some(1));
+
+        let mut cycle = Graph::directed();
+        cycle.add_edge(1, 2);
+        cycle.add_edge(2, 3);
+        cycle.add_edge(3, 1);
+
+        assert!(cycle.contract_edge(&1, &2, 12));
+        assert_eq!(cycle.edge_count(), 2);
+        assert!(cycle.has_edge(&12, &3));
+        assert!(cycle.has_edge(&3, &12));
+        assert_eq!(cycle.in_degree(&12), Some(1));
+    }
+
+    #[test]
+    fn merge_vertex_group() {
+        let mut path = Graph::undirected();
+        for i in 0..4 {
+            path.add_edge(i, i + 1);
+        }
+
+        assert!(!path.merge_vertices(&[7, 8], 9));
+        assert!(path.merge_vertices(&[1, 2, 3], 9));
+        assert_eq!(path.vertex_count(), 3);
+        assert_eq!(path.edge_count(), 2);
+        assert!(path.has_edge(&0, &9));
+        assert!(path.has_edge(&9, &4));
+        assert!(!path.has_vertex(&2));
+
+        let degree_sum: usize = path.vertices().filter_map(|v| path.degree(v)).sum();
+        assert_eq!(degree_sum, 2 * path.edge_count());
+    }
+
+    #[test]
+    fn union_with_overlapping_graphs() {
+        let mut graph = Graph::undirected();
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+
+        let mut other = Graph::undirected();
+        other.add_edge(3, 2);
+        other.add_edge(3, 4);
+        other.add_vertex(5);
+
+        graph.union_with(&other);
+        assert_eq!(graph.vertex_count(), 5);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.neighbors(&2).unwrap().len(), 2);
+        assert!(graph.has_edge(&4, &3));
+    }
+
+    #[test]
+    fn union_with_disjoint_triangles() {
+        let mut graph = Graph::undirected();
+        let mut other = Graph::undirected();
+        for (from, to) in [(0, 1), (1, 2), (2, 0)] {
+            graph.add_edge(from, to);
+            other.add_edge(from + 3, to + 3);
+        }
+
+        graph.union_with(&other);
+        assert_eq!(graph.vertex_count(), 6);
+        assert_eq!(graph.edge_count(), 6);
+        assert!(graph.has_edge(&5, &3));
+        assert!(!graph.has_edge(&2, &3));
+
+        // Overlapping edges are only counted once
+        graph.union_with(&other);
+        assert_eq!(graph.edge_count(), 6);
+    }
+
+    #[test]
+    fn disjoint_union_components() {
+        use crate::graph::algorithms::connected_components;
+
+        let mut a = Graph::undirected();
+        a.add_edge(1, 2);
+        a.add_vertex(3);
+
+        let mut b = Graph::undirected();
+        b.add_edge(1, 2);
+        b.add_edge(4, 5);
+        b.add_vertex(6);
+
+        let union = Graph::disjoint_union(&a, &b, |side, &v| (side, v));
+        assert_eq!(union.vertex_count(), a.vertex_count() + b.vertex_count());
+        assert_eq!(union.edge_count(), a.edge_count() + b.edge_count());
+        assert_eq!(
+            connected_components(&union).len(),
+            connected_components(&a).len() + connected_components(&b).len()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot merge graphs of different types")]
+    fn union_with_mismatched_types() {
+        let mut graph: Graph<i32> = Graph::undirected();
+        graph.union_with(&Graph::directed());
+    }
+
+    #[test]
+    fn density() {
+        let mut triangle = Graph::directed();
+        for (from, to) in [(1, 2), (2, 1), (2, 3), (3, 2), (1, 3), (3, 1)] {
+            triangle.add_edge(from, to);
+        }
+        assert_eq!(triangle.density(), 1.0);
+
+        let mut single = Graph::undirected();
+        single.add_edge(1, 2);
+        single.add_vertex(3);
+        single.add_vertex(4);
+        assert_eq!(single.density(), 1.0 / 6.0);
+
+        let mut lonely = Graph::directed();
+        lonely.add_vertex(1);
+        assert_eq!(lonely.density(), 0.0);
+    }
+
+    #[test]
+    fn neighbors_cloned_allows_mutation() {
+        let mut graph = Graph::directed();
+        for to in 2..=5 {
+            graph.add_edge(1, to);
+        }
+
+        for neighbor in graph.neighbors_cloned(&1) {
+            if neighbor % 2 == 0 {
+                graph.remove_edge(&1, &neighbor);
+            }
+        }
+
+        assert_eq!(graph.neighbors_cloned(&1), vec![3, 5]);
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.neighbors_cloned(&42).is_empty());
+    }
+
+    #[test]
+    fn self_loop_conventions() {
+        use crate::graph::algorithms::{connected_components, is_cyclic, is_forest, is_tree};
+
+        for graph_type in [GraphType::Directed, GraphType::Undirected] {
+            let undirected = graph_type == GraphType::Undirected;
+            let mut graph = Graph::new(graph_type);
+            graph.add_edge(1, 2);
+            assert!(graph.add_edge(1, 1));
+            assert!(!graph.add_edge(1, 1));
+
+            assert!(graph.has_edge(&1, &1));
+            assert_eq!(graph.edge_count(), 2);
+            assert_eq!(graph.edges().filter(|(from, to)| from == to).count(), 1);
+            assert_eq!(graph.degree(&1), Some(if undirected { 3 } else { 2 }));
+            assert_eq!(graph.in_degree(&1), Some(if undirected { 3 } else { 1 }));
+            assert_eq!(graph.out_degree(&1), graph.degree(&1));
+            assert!(is_cyclic(&graph));
+            assert!(!is_tree(&graph));
+            assert!(!is_forest(&graph));
+            assert_eq!(connected_components(&graph).len(), 1);
+
+            assert!(graph.remove_edge(&1, &1));
+            assert!(!graph.has_edge(&1, &1));
+            assert_eq!(graph.edge_count(), 1);
+            assert_eq!(graph.degree(&1), Some(1));
+            assert!(!is_cyclic(&graph));
+            assert!(is_tree(&graph));
+
+            graph.add_edge(2, 2);
+            assert!(graph.remove_vertex(&2));
+            assert_eq!(graph.edge_count(), 0);
+            assert_eq!(graph.degree(&1), Some(0));
+            assert_eq!(graph.edges().count(), 0);
+        }
+    }
+
+    #[test]
+    fn add_vertices_skips_existing() {
+        let mut graph: Graph<i32> = Graph::undirected();
+        assert_eq!(graph.add_vertices([1, 2, 2, 3]), 3);
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.add_vertices(vec![3, 4]), 1);
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn adjacency_map_round_trip() {
+        for graph_type in [GraphType::Directed, GraphType::Undirected] {
+            let mut graph = Graph::new(graph_type.clone());
+            graph.add_edge(1, 2);
+            graph.add_edge(2, 3);
+            graph.add_edge(3, 3);
+            graph.add_vertex(4);
+
+            let map = graph.to_adjacency_map();
+            assert_eq!(map[&4], Vec::<i32>::new());
+
+            let restored = Graph::from_adjacency_map(map, graph_type);
+            assert_eq!(restored.vertex_count(), 4);
+            assert_eq!(restored.edge_count(), graph.edge_count());
+            for (from, to) in graph.edges() {
+                assert!(restored.has_edge(from, to));
+            }
+        }
+    }
+
+    #[test]
+    fn from_adjacency_map_one_sided_undirected() {
+        let map = HashMap::from([
+            ("a", vec!["b", "c"]),
+            ("b", vec![]),
+            ("c", vec![]),
+            ("d", vec![]),
+        ]);
+        let graph = Graph::from_adjacency_map(map, GraphType::Undirected);
+
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.has_edge(&"c", &"a"));
+        assert_eq!(graph.degree(&"d"), Some(0));
+    }
+
+    #[test]
+    fn reverse_edge() {
+        let mut graph = Graph::directed();
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 2);
+
+        assert!(graph.reverse_edge(&1, &2));
+        assert!(graph.has_edge(&2, &1));
+        assert!(!graph.has_edge(&1, &2));
+        assert_eq!(graph.edge_count(), 3);
+
+        assert!(!graph.reverse_edge(&1, &2));
+        assert!(!graph.reverse_edge(&2, &3));
+        assert!(graph.has_edge(&2, &3));
+        assert_eq!(graph.edge_count(), 3);
+    }
+
+    #[test]
+    fn display_vertices_and_edges() {
+        let mut directed = Graph::directed();
+        directed.add_edge(2, 1);
+        directed.add_edge(1, 3);
+        directed.add_vertex(4);
+        assert_eq!(directed.to_string(), "V={1, 2, 3, 4} E={1->3, 2->1}");
+
+        let mut undirected = Graph::undirected();
+        undirected.add_edge("b", "a");
+        undirected.add_edge("b", "c");
+        undirected.add_edge("c", "c");
+        assert_eq!(undirected.to_string(), "V={a, b, c} E={a--b, b--c, c--c}");
+        assert_eq!(
+            undirected.fmt_limited(1).to_string(),
+            "V={a, ...} E={a--b, ...}"
+        );
+    }
 }
@@ -1,8 +1,11 @@
+use crate::graph::adjacency_list::GraphType;
 use crate::graph::{Graph, WeightedGraph};
 use crate::heap::BinaryHeap;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use std::hash::Hash;
+use std::ops::{Add, Sub};
 
 #[derive(Debug, Clone)]
 struct DijkstraNode<T, W> {
@@ -33,20 +36,30 @@ impl<T, W: Ord> Ord for DijkstraNode<T, W> {
 pub fn bfs<T>(graph: &Graph<T>, start: &T) -> Vec<T>
 where
     T: Clone + Eq + Hash,
+{
+    let mut result = Vec::new();
+    bfs_visit(graph, start, |vertex| result.push(vertex.clone()));
+    result
+}
+
+/// Calls `visit` on each vertex reachable from `start`, in breadth-first order
+pub fn bfs_visit<T, F>(graph: &Graph<T>, start: &T, mut visit: F)
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T),
 {
     let mut visited = HashSet::new();
     let mut queue = VecDeque::new();
-    let mut result = Vec::new();
 
     if !graph.has_vertex(start) {
-        return result;
+        return;
     }
 
     queue.push_back(start.clone());
     visited.insert(start.clone());
 
     while let Some(vertex) = queue.pop_front() {
-        result.push(vertex.clone());
+        visit(&vertex);
 
         if let Some(neighbors) = graph.neighbors(&vertex) {
             for neighbor in neighbors {
@@ -57,35 +70,42 @@ where
             }
         }
     }
+}
 
+pub fn dfs<T>(graph: &Graph<T>, start: &T) -> Vec<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut result = Vec::new();
+    dfs_visit(graph, start, |vertex| result.push(vertex.clone()));
     result
 }
 
-pub fn dfs<T>(graph: &Graph<T>, start: &T) -> Vec<T>
+/// Calls `visit` on each vertex reachable from `start`, in depth-first preorder
+pub fn dfs_visit<T, F>(graph: &Graph<T>, start: &T, mut visit: F)
 where
     T: Clone + Eq + Hash,
+    F: FnMut(&T),
 {
     let mut visited = HashSet::new();
-    let mut result = Vec::new();
 
     if graph.has_vertex(start) {
-        dfs_recursive(graph, start, &mut visited, &mut result);
+        dfs_recursive(graph, start, &mut visited, &mut visit);
     }
-
-    result
 }
 
-fn dfs_recursive<T>(graph: &Graph<T>, vertex: &T, visited: &mut HashSet<T>, result: &mut Vec<T>)
+fn dfs_recursive<T, F>(graph: &Graph<T>, vertex: &T, visited: &mut HashSet<T>, visit: &mut F)
 where
     T: Clone + Eq + Hash,
+    F: FnMut(&T),
 {
     visited.insert(vertex.clone());
-    result.push(vertex.clone());
+    visit(vertex);
 
     if let Some(neighbors) = graph.neighbors(vertex) {
         for neighbor in neighbors {
             if !visited.contains(neighbor) {
-                dfs_recursive(graph, neighbor, visited, result);
+                dfs_recursive(graph, neighbor, visited, visit);
             }
         }
     }
@@ -127,6 +147,43 @@ where
     false
 }
 
+/// Returns every vertex at most `max_hops` edges from `start`, including
+/// `start` itself; empty if `start` is not in the graph
+pub fn reachable_within<T>(graph: &Graph<T>, start: &T, max_hops: usize) -> HashSet<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut visited = HashSet::new();
+    if !graph.has_vertex(start) {
+        return visited;
+    }
+
+    let mut queue = VecDeque::new();
+    visited.insert(start.clone());
+    queue.push_back((start.clone(), 0));
+
+    while let Some((vertex, hops)) = queue.pop_front() {
+        if hops == max_hops {
+            continue;
+        }
+        for neighbor in graph.neighbors(&vertex).into_iter().flatten() {
+            if visited.insert(neighbor.clone()) {
+                queue.push_back((neighbor.clone(), hops + 1));
+            }
+        }
+    }
+
+    visited
+}
+
+/// Returns true if `end` can be reached from `start` in at most `max_hops` edges
+pub fn path_exists_within<T>(graph: &Graph<T>, start: &T, end: &T, max_hops: usize) -> bool
+where
+    T: Clone + Eq + Hash,
+{
+    reachable_within(graph, start, max_hops).contains(end)
+}
+
 pub fn shortest_path<T>(graph: &Graph<T>, start: &T, end: &T) -> Option<Vec<T>>
 where
     T: Clone + Eq + Hash,
@@ -139,9 +196,29 @@ where
         return Some(vec![start.clone()]);
     }
 
+    let parents = bfs_parents(graph, start);
+    if !parents.contains_key(end) {
+        return None;
+    }
+    Some(reconstruct_path(&parents, start, end))
+}
+
+/// Maps every vertex reached by a BFS from `start`, other than `start` itself,
+/// to the vertex it was discovered from
+///
+/// Following the parents from any vertex leads back to `start` along a
+/// shortest path, so one traversal can answer many path queries.
+pub fn bfs_parents<T>(graph: &Graph<T>, start: &T) -> HashMap<T, T>
+where
+    T: Clone + Eq + Hash,
+{
     let mut visited = HashSet::new();
     let mut queue = VecDeque::new();
-    let mut parent: HashMap<T, T> = HashMap::new();
+    let mut parents = HashMap::new();
+
+    if !graph.has_vertex(start) {
+        return parents;
+    }
 
     queue.push_back(start.clone());
     visited.insert(start.clone());
@@ -149,21 +226,67 @@ where
     while let Some(vertex) = queue.pop_front() {
         if let Some(neighbors) = graph.neighbors(&vertex) {
             for neighbor in neighbors {
-                if neighbor == end {
-                    parent.insert(neighbor.clone(), vertex.clone());
-                    return Some(reconstruct_path(&parent, start, end));
-                }
-
                 if !visited.contains(neighbor) {
                     visited.insert(neighbor.clone());
-                    parent.insert(neighbor.clone(), vertex.clone());
+                    parents.insert(neighbor.clone(), vertex.clone());
                     queue.push_back(neighbor.clone());
                 }
             }
         }
     }
 
-    None
+    parents
+}
+
+/// Returns the BFS tree rooted at `start` as a directed graph with edges
+/// from parent to child
+pub fn bfs_tree<T>(graph: &Graph<T>, start: &T) -> Graph<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut tree = Graph::directed();
+    if graph.has_vertex(start) {
+        tree.add_vertex(start.clone());
+    }
+    for (child, parent) in bfs_parents(graph, start) {
+        tree.add_edge(parent, child);
+    }
+    tree
+}
+
+/// Returns the DFS tree rooted at `start` as a directed graph with edges
+/// from parent to child
+pub fn dfs_tree<T>(graph: &Graph<T>, start: &T) -> Graph<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut tree = Graph::directed();
+    if graph.has_vertex(start) {
+        let mut visited = HashSet::new();
+        tree.add_vertex(start.clone());
+        dfs_tree_recursive(graph, start, &mut visited, &mut tree);
+    }
+    tree
+}
+
+fn dfs_tree_recursive<T>(
+    graph: &Graph<T>,
+    vertex: &T,
+    visited: &mut HashSet<T>,
+    tree: &mut Graph<T>,
+) where
+    T: Clone + Eq + Hash,
+{
+    visited.insert(vertex.clone());
+
+    if let Some(neighbors) = graph.neighbors(vertex) {
+        for neighbor in neighbors {
+            if !visited.contains(neighbor) {
+                tree.add_edge(vertex.clone(), neighbor.clone());
+                dfs_tree_recursive(graph, neighbor, visited, tree);
+            }
+        }
+    }
 }
 
 fn reconstruct_path<T>(parent: &HashMap<T, T>, start: &T, end: &T) -> Vec<T>
@@ -183,16 +306,30 @@ where
     path
 }
 
+/// Returns the connected components of the graph
+///
+/// Directed graphs are split into weakly connected components, following
+/// edges in both directions, so the result does not depend on the order in
+/// which vertices are visited. A self-loop never joins two components.
 pub fn connected_components<T>(graph: &Graph<T>) -> Vec<Vec<T>>
 where
     T: Clone + Eq + Hash,
 {
+    let mut incoming: HashMap<&T, Vec<&T>> = HashMap::new();
+    if *graph.graph_type() == GraphType::Directed {
+        for vertex in graph.vertices() {
+            for neighbor in graph.neighbors(vertex).into_iter().flatten() {
+                incoming.entry(neighbor).or_default().push(vertex);
+            }
+        }
+    }
+
     let mut visited = HashSet::new();
     let mut components = Vec::new();
 
     for vertex in graph.vertices() {
         if !visited.contains(vertex) {
-            let component = dfs_component(graph, vertex, &mut visited);
+            let component = dfs_component(graph, vertex, &incoming, &mut visited);
             components.push(component);
         }
     }
@@ -200,7 +337,12 @@ where
     components
 }
 
-fn dfs_component<T>(graph: &Graph<T>, start: &T, visited: &mut HashSet<T>) -> Vec<T>
+fn dfs_component<T>(
+    graph: &Graph<T>,
+    start: &T,
+    incoming: &HashMap<&T, Vec<&T>>,
+    visited: &mut HashSet<T>,
+) -> Vec<T>
 where
     T: Clone + Eq + Hash,
 {
@@ -212,11 +354,11 @@ where
             visited.insert(vertex.clone());
             component.push(vertex.clone());
 
-            if let Some(neighbors) = graph.neighbors(&vertex) {
-                for neighbor in neighbors {
-                    if !visited.contains(neighbor) {
-                        stack.push(neighbor.clone());
-                    }
+            let outgoing = graph.neighbors(&vertex).into_iter().flatten();
+            let backwards = incoming.get(&vertex).into_iter().flatten().copied();
+            for neighbor in outgoing.chain(backwards) {
+                if !visited.contains(neighbor) {
+                    stack.push(neighbor.clone());
                 }
             }
         }
@@ -225,12 +367,11 @@ where
     component
 }
 
+/// Returns true if the graph contains a cycle; a self-loop counts as one
 pub fn is_cyclic<T>(graph: &Graph<T>) -> bool
 where
     T: Clone + Eq + Hash,
 {
-    use crate::graph::adjacency_list::GraphType;
-
     match graph.graph_type() {
         GraphType::Directed => is_cyclic_directed(graph),
         GraphType::Undirected => is_cyclic_undirected(graph),
@@ -241,7 +382,7 @@ fn is_cyclic_directed<T>(graph: &Graph<T>) -> bool
 where
     T: Clone + Eq + Hash,
 {
-    #[derive(PartialEq)]
+    #[derive(Clone, Copy, PartialEq)]
     enum Color {
         White,
         Gray,
@@ -261,13 +402,9 @@ where
 
         if let Some(neighbors) = graph.neighbors(vertex) {
             for neighbor in neighbors {
-                match colors.get(neighbor) {
+                match colors.get(neighbor).copied() {
                     Some(Color::Gray) => return true,
-                    Some(Color::White) => {
-                        if dfs_cycle(graph, neighbor, colors) {
-                            return true;
-                        }
-                    }
+                    Some(Color::White) if dfs_cycle(graph, neighbor, colors) => return true,
                     _ => {}
                 }
             }
@@ -286,6 +423,80 @@ where
     false
 }
 
+/// Enumerates every elementary cycle of `graph`, each exactly once
+///
+/// A cycle is reported starting from whichever of its vertices comes first
+/// in `graph.vertices()`, without repeating that vertex at the end, so a
+/// self-loop yields a single-vertex cycle. In an undirected graph a cycle
+/// needs at least three vertices, since going back along the edge just
+/// walked is not a cycle, and each one is reported in a single direction.
+/// This is a plain backtracking search that is exponential in the worst
+/// case, so it is meant for small graphs.
+pub fn all_cycles<T>(graph: &Graph<T>) -> Vec<Vec<T>>
+where
+    T: Clone + Eq + Hash,
+{
+    struct CycleSearch<'a, T> {
+        graph: &'a Graph<T>,
+        rank: HashMap<&'a T, usize>,
+        undirected: bool,
+        path: Vec<&'a T>,
+        on_path: HashSet<&'a T>,
+        cycles: Vec<Vec<T>>,
+    }
+
+    impl<'a, T: Clone + Eq + Hash> CycleSearch<'a, T> {
+        // Only vertices ranked above `start` are explored, so each cycle is
+        // found from its lowest-ranked vertex and never twice
+        fn extend(&mut self, vertex: &'a T, start: &'a T) {
+            let graph = self.graph;
+            for next in graph.neighbors(vertex).into_iter().flatten() {
+                if next == start {
+                    if self.closes_new_cycle() {
+                        self.cycles
+                            .push(self.path.iter().map(|&v| v.clone()).collect());
+                    }
+                } else if self.rank[next] > self.rank[start] && self.on_path.insert(next) {
+                    self.path.push(next);
+                    self.extend(next, start);
+                    self.path.pop();
+                    self.on_path.remove(next);
+                }
+            }
+        }
+
+        // An undirected cycle is walked once in each direction, so only the
+        // walk whose second vertex ranks below its last one is kept
+        fn closes_new_cycle(&self) -> bool {
+            match self.path[..] {
+                [_] => true,
+                [_, _] => !self.undirected,
+                [_, second, .., last] => !self.undirected || self.rank[second] < self.rank[last],
+                [] => unreachable!("the start vertex is always on the path"),
+            }
+        }
+    }
+
+    let mut search = CycleSearch {
+        graph,
+        rank: graph.vertices().enumerate().map(|(i, v)| (v, i)).collect(),
+        undirected: *graph.graph_type() == GraphType::Undirected,
+        path: Vec::new(),
+        on_path: HashSet::new(),
+        cycles: Vec::new(),
+    };
+
+    for start in graph.vertices() {
+        search.path.push(start);
+        search.on_path.insert(start);
+        search.extend(start, start);
+        search.path.pop();
+        search.on_path.remove(start);
+    }
+
+    search.cycles
+}
+
 fn is_cyclic_undirected<T>(graph: &Graph<T>) -> bool
 where
     T: Clone + Eq + Hash,
@@ -305,6 +516,10 @@ where
 
         if let Some(neighbors) = graph.neighbors(vertex) {
             for neighbor in neighbors {
+                // A self-loop is stored once, so it is a cycle on its own
+                if neighbor == vertex {
+                    return true;
+                }
                 if Some(neighbor) == parent {
                     continue;
                 }
@@ -327,60 +542,238 @@ where
     false
 }
 
-pub fn dijkstra<T, W>(graph: &WeightedGraph<T, W>, start: &T) -> HashMap<T, W>
+/// Returns true if the graph is a single tree.
+///
+/// Undirected graphs must be connected and acyclic; directed graphs must be an
+/// arborescence (one root, every other vertex with exactly one incoming edge).
+/// The empty graph is treated as a forest with no trees, so it is not a tree.
+pub fn is_tree<T>(graph: &Graph<T>) -> bool
 where
     T: Clone + Eq + Hash,
-    W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
 {
-    let mut distances: HashMap<T, W> = HashMap::new();
-    let mut visited: HashSet<T> = HashSet::new();
-    let mut heap = BinaryHeap::max_heap();
+    let vertices = graph.vertex_count();
+    if vertices == 0 || graph.edge_count() != vertices - 1 {
+        return false;
+    }
 
-    if !graph.has_vertex(start) {
-        return distances;
+    match graph.graph_type() {
+        GraphType::Directed => is_forest(graph),
+        GraphType::Undirected => connected_components(graph).len() == 1,
     }
+}
 
-    distances.insert(start.clone(), W::default());
-    heap.push(DijkstraNode {
-        vertex: start.clone(),
-        distance: W::default(),
-    });
+/// Returns true if every component of the graph is a tree.
+///
+/// For directed graphs every component must be an arborescence.
+pub fn is_forest<T>(graph: &Graph<T>) -> bool
+where
+    T: Clone + Eq + Hash,
+{
+    match graph.graph_type() {
+        GraphType::Directed => {
+            graph
+                .vertices()
+                .all(|vertex| graph.in_degree(vertex).is_some_and(|degree| degree <= 1))
+                && !is_cyclic(graph)
+        }
+        GraphType::Undirected => !is_cyclic(graph),
+    }
+}
 
-    while let Some(current_node) = heap.pop() {
-        if visited.contains(&current_node.vertex) {
-            continue;
+/// Returns a graph with an edge `u -> v` whenever `v` is reachable from `u`
+/// by a non-empty path
+pub fn transitive_closure<T>(graph: &Graph<T>) -> Graph<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut closure = Graph::new(graph.graph_type().clone());
+
+    for vertex in graph.vertices() {
+        closure.add_vertex(vertex.clone());
+
+        // Seeding the search with the successors rather than the vertex
+        // itself means it is only reached again through a cycle
+        let mut visited = HashSet::new();
+        let mut queue: VecDeque<&T> = graph.neighbors(vertex).into_iter().flatten().collect();
+
+        while let Some(reachable) = queue.pop_front() {
+            if !visited.insert(reachable) {
+                continue;
+            }
+            closure.add_edge(vertex.clone(), reachable.clone());
+            queue.extend(graph.neighbors(reachable).into_iter().flatten());
         }
+    }
 
-        visited.insert(current_node.vertex.clone());
+    closure
+}
 
-        if let Some(neighbors) = graph.neighbors(&current_node.vertex) {
-            for edge in neighbors {
-                if !visited.contains(&edge.to) {
-                    let new_dist = current_node.distance.clone() + edge.weight.clone();
-
-                    let should_update = distances
-                        .get(&edge.to)
-                        .map_or(true, |existing_dist| new_dist < *existing_dist);
-
-                    if should_update {
-                        distances.insert(edge.to.clone(), new_dist.clone());
-                        heap.push(DijkstraNode {
-                            vertex: edge.to.clone(),
-                            distance: new_dist,
-                        });
-                    }
-                }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotADag;
+
+impl fmt::Display for NotADag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph is not a directed acyclic graph")
+    }
+}
+
+impl std::error::Error for NotADag {}
+
+/// Returns the DAG with the fewest edges that has the same reachability
+///
+/// An edge `u -> w` is dropped when another successor of `u` already reaches `w`.
+pub fn transitive_reduction<T>(graph: &Graph<T>) -> Result<Graph<T>, NotADag>
+where
+    T: Clone + Eq + Hash,
+{
+    if *graph.graph_type() != GraphType::Directed || is_cyclic(graph) {
+        return Err(NotADag);
+    }
+
+    let closure = transitive_closure(graph);
+    let mut reduction = Graph::directed();
+
+    for vertex in graph.vertices() {
+        reduction.add_vertex(vertex.clone());
+        let neighbors = graph.neighbors_cloned(vertex);
+        for target in &neighbors {
+            let implied = neighbors
+                .iter()
+                .any(|other| other != target && closure.has_edge(other, target));
+            if !implied {
+                reduction.add_edge(vertex.clone(), target.clone());
             }
         }
     }
 
-    distances
+    Ok(reduction)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DijkstraStats {
+    pub settled: usize,
+    pub relaxations: usize,
+    pub stale_skips: usize,
+}
+
+pub fn dijkstra<T, W>(graph: &WeightedGraph<T, W>, start: &T) -> HashMap<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
+{
+    dijkstra_core(graph, start).0
 }
 
 pub fn dijkstra_with_path<T, W>(
     graph: &WeightedGraph<T, W>,
     start: &T,
 ) -> (HashMap<T, W>, HashMap<T, T>)
+where
+    T: Clone + Eq + Hash,
+    W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
+{
+    let paths = dijkstra_paths(graph, start);
+    (paths.distances, paths.previous)
+}
+
+/// Shortest distances and paths from one start vertex, as computed by
+/// [`dijkstra_paths`]
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<T, W> {
+    start: T,
+    distances: HashMap<T, W>,
+    previous: HashMap<T, T>,
+}
+
+impl<T, W> ShortestPaths<T, W>
+where
+    T: Clone + Eq + Hash,
+{
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    pub fn distance_to(&self, vertex: &T) -> Option<&W> {
+        self.distances.get(vertex)
+    }
+
+    /// Returns the vertices from the start to `vertex`, both included, or
+    /// `None` if `vertex` is unreachable
+    pub fn path_to(&self, vertex: &T) -> Option<Vec<T>> {
+        if !self.is_reachable(vertex) {
+            return None;
+        }
+        reconstruct_dijkstra_path(&self.previous, &self.start, vertex)
+    }
+
+    pub fn is_reachable(&self, vertex: &T) -> bool {
+        self.distances.contains_key(vertex)
+    }
+
+    /// Iterates over every reachable vertex with its distance, in
+    /// unspecified order
+    pub fn iter_reachable(&self) -> impl Iterator<Item = (&T, &W)> {
+        self.distances.iter()
+    }
+}
+
+/// Runs Dijkstra from `start` and returns a lookup for distances and paths
+///
+/// A missing start vertex yields a result where nothing is reachable.
+pub fn dijkstra_paths<T, W>(graph: &WeightedGraph<T, W>, start: &T) -> ShortestPaths<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
+{
+    let (distances, previous, _) = dijkstra_core(graph, start);
+    ShortestPaths {
+        start: start.clone(),
+        distances,
+        previous,
+    }
+}
+
+/// Returns the shortest-path tree rooted at `start` as a directed weighted
+/// graph with edges from predecessor to successor
+///
+/// Every reachable vertex other than `start` has exactly one incoming edge,
+/// carrying the weight of the edge Dijkstra relaxed through. Unreachable
+/// vertices are left out.
+pub fn shortest_path_tree<T, W>(graph: &WeightedGraph<T, W>, start: &T) -> WeightedGraph<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
+{
+    let (distances, previous, _) = dijkstra_core(graph, start);
+    let mut tree = WeightedGraph::directed();
+    tree.add_vertices(distances.into_keys());
+
+    for (child, parent) in previous {
+        let weight = graph
+            .get_edge_weight(&parent, &child)
+            .cloned()
+            .expect("predecessor has an edge to its successor");
+        tree.add_edge(parent, child, weight);
+    }
+    tree
+}
+
+pub fn dijkstra_with_stats<T, W>(
+    graph: &WeightedGraph<T, W>,
+    start: &T,
+) -> (HashMap<T, W>, DijkstraStats)
+where
+    T: Clone + Eq + Hash,
+    W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
+{
+    let (distances, _, stats) = dijkstra_core(graph, start);
+    (distances, stats)
+}
+
+fn dijkstra_core<T, W>(
+    graph: &WeightedGraph<T, W>,
+    start: &T,
+) -> (HashMap<T, W>, HashMap<T, T>, DijkstraStats)
 where
     T: Clone + Eq + Hash,
     W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
@@ -388,10 +781,11 @@ where
     let mut distances: HashMap<T, W> = HashMap::new();
     let mut previous: HashMap<T, T> = HashMap::new();
     let mut visited: HashSet<T> = HashSet::new();
+    let mut stats = DijkstraStats::default();
     let mut heap = BinaryHeap::max_heap();
 
     if !graph.has_vertex(start) {
-        return (distances, previous);
+        return (distances, previous, stats);
     }
 
     distances.insert(start.clone(), W::default());
@@ -400,36 +794,42 @@ where
         distance: W::default(),
     });
 
-    while let Some(current_node) = heap.pop() {
-        if visited.contains(&current_node.vertex) {
+    while let Some(DijkstraNode { vertex, distance }) = heap.pop() {
+        // A vertex is pushed again each time its distance improves, so older
+        // entries carry outdated distances and must not settle it
+        if distance > distances[&vertex] || visited.contains(&vertex) {
+            stats.stale_skips += 1;
             continue;
         }
 
-        visited.insert(current_node.vertex.clone());
+        visited.insert(vertex.clone());
+        stats.settled += 1;
 
-        if let Some(neighbors) = graph.neighbors(&current_node.vertex) {
+        if let Some(neighbors) = graph.neighbors(&vertex) {
             for edge in neighbors {
-                if !visited.contains(&edge.to) {
-                    let new_dist = current_node.distance.clone() + edge.weight.clone();
-
-                    let should_update = distances
-                        .get(&edge.to)
-                        .map_or(true, |existing_dist| new_dist < *existing_dist);
-
-                    if should_update {
-                        distances.insert(edge.to.clone(), new_dist.clone());
-                        previous.insert(edge.to.clone(), current_node.vertex.clone());
-                        heap.push(DijkstraNode {
-                            vertex: edge.to.clone(),
-                            distance: new_dist,
-                        });
-                    }
+                if visited.contains(&edge.to) {
+                    continue;
+                }
+
+                let new_dist = distance.clone() + edge.weight.clone();
+                let should_update = distances
+                    .get(&edge.to)
+                    .map_or(true, |existing_dist| new_dist < *existing_dist);
+
+                if should_update {
+                    stats.relaxations += 1;
+                    distances.insert(edge.to.clone(), new_dist.clone());
+                    previous.insert(edge.to.clone(), vertex.clone());
+                    heap.push(DijkstraNode {
+                        vertex: edge.to.clone(),
+                        distance: new_dist,
+                    });
                 }
             }
         }
     }
 
-    (distances, previous)
+    (distances, previous, stats)
 }
 
 pub fn reconstruct_dijkstra_path<T>(previous: &HashMap<T, T>, start: &T, end: &T) -> Option<Vec<T>>
@@ -456,6 +856,23 @@ where
     Some(path)
 }
 
+/// Returns the distance and full path from `start` to every reachable vertex
+pub fn all_shortest_paths<T, W>(graph: &WeightedGraph<T, W>, start: &T) -> HashMap<T, (W, Vec<T>)>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
+{
+    let paths = dijkstra_paths(graph, start);
+
+    paths
+        .iter_reachable()
+        .filter_map(|(vertex, distance)| {
+            let path = paths.path_to(vertex)?;
+            Some((vertex.clone(), (distance.clone(), path)))
+        })
+        .collect()
+}
+
 pub fn dijkstra_shortest_path<T, W>(
     graph: &WeightedGraph<T, W>,
     start: &T,
@@ -465,12 +882,319 @@ where
     T: Clone + Eq + Hash,
     W: Clone + PartialOrd + Ord + Default + std::ops::Add<Output = W>,
 {
-    let (distances, previous) = dijkstra_with_path(graph, start);
+    let paths = dijkstra_paths(graph, start);
+    (paths.distance_to(end).cloned(), paths.path_to(end))
+}
+
+pub fn is_valid_path<T>(graph: &Graph<T>, path: &[T]) -> bool
+where
+    T: Clone + Eq + Hash,
+{
+    match path {
+        [] => false,
+        [vertex] => graph.has_vertex(vertex),
+        _ => path
+            .windows(2)
+            .all(|pair| graph.has_edge(&pair[0], &pair[1])),
+    }
+}
 
-    let distance = distances.get(end).cloned();
-    let path = reconstruct_dijkstra_path(&previous, start, end);
+pub fn path_weight<T, W>(graph: &WeightedGraph<T, W>, path: &[T]) -> Option<W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Default + std::ops::Add<Output = W>,
+{
+    match path {
+        [] => None,
+        [vertex] => graph.has_vertex(vertex).then(W::default),
+        _ => path.windows(2).try_fold(W::default(), |total, pair| {
+            let weight = graph.get_edge_weight(&pair[0], &pair[1])?;
+            Some(total + weight.clone())
+        }),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycleError;
 
-    (distance, path)
+impl fmt::Display for NegativeCycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a negative-weight cycle")
+    }
+}
+
+impl std::error::Error for NegativeCycleError {}
+
+pub fn bellman_ford<T, W>(
+    graph: &WeightedGraph<T, W>,
+    start: &T,
+) -> Result<HashMap<T, W>, NegativeCycleError>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Ord + Default + Add<Output = W>,
+{
+    let mut distances = HashMap::new();
+    if graph.has_vertex(start) {
+        distances.insert(start.clone(), W::default());
+    }
+    relax_until_stable(graph, distances)
+}
+
+fn relax_until_stable<T, W>(
+    graph: &WeightedGraph<T, W>,
+    mut distances: HashMap<T, W>,
+) -> Result<HashMap<T, W>, NegativeCycleError>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Ord + Default + Add<Output = W>,
+{
+    // An empty graph has nothing to relax, and no pass V to confirm it
+    if graph.vertex_count() == 0 {
+        return Ok(distances);
+    }
+
+    // A shortest path uses at most V - 1 edges, so any update on pass V means a
+    // negative cycle is reachable
+    for _ in 0..graph.vertex_count() {
+        let mut updated = false;
+
+        for vertex in graph.vertices() {
+            let Some(distance) = distances.get(vertex).cloned() else {
+                continue;
+            };
+            for edge in graph.neighbors(vertex).into_iter().flatten() {
+                let new_dist = distance.clone() + edge.weight.clone();
+                if distances
+                    .get(&edge.to)
+                    .map_or(true, |existing| new_dist < *existing)
+                {
+                    distances.insert(edge.to.clone(), new_dist);
+                    updated = true;
+                }
+            }
+        }
+
+        if !updated {
+            return Ok(distances);
+        }
+    }
+
+    Err(NegativeCycleError)
+}
+
+pub fn johnson<T, W>(graph: &WeightedGraph<T, W>) -> Result<HashMap<(T, T), W>, NegativeCycleError>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Ord + Default + Add<Output = W> + Sub<Output = W>,
+{
+    // Starting every vertex at zero is equivalent to running Bellman-Ford from a
+    // virtual source with a zero-weight edge to each vertex
+    let potentials = relax_until_stable(
+        graph,
+        graph
+            .vertices()
+            .map(|vertex| (vertex.clone(), W::default()))
+            .collect(),
+    )?;
+
+    let mut reweighted = WeightedGraph::directed();
+    for vertex in graph.vertices() {
+        reweighted.add_vertex(vertex.clone());
+        for edge in graph.neighbors(vertex).into_iter().flatten() {
+            let weight =
+                edge.weight.clone() + potentials[vertex].clone() - potentials[&edge.to].clone();
+            reweighted.add_edge(vertex.clone(), edge.to.clone(), weight);
+        }
+    }
+
+    let mut all_pairs = HashMap::new();
+    for from in graph.vertices() {
+        for (to, distance) in dijkstra(&reweighted, from) {
+            let distance = distance + potentials[&to].clone() - potentials[from].clone();
+            all_pairs.insert((from.clone(), to), distance);
+        }
+    }
+
+    Ok(all_pairs)
+}
+
+/// Orders the vertices so every edge points forward, using Kahn's algorithm
+///
+/// Fails if any cycle exists. Undirected edges are stored in both directions,
+/// so an undirected graph only succeeds when it has no edges.
+fn weighted_topological_order<T, W>(graph: &WeightedGraph<T, W>) -> Result<Vec<T>, NotADag>
+where
+    T: Clone + Eq + Hash,
+    W: Clone,
+{
+    let mut in_degree: HashMap<&T, usize> = graph.vertices().map(|vertex| (vertex, 0)).collect();
+    for vertex in graph.vertices() {
+        for edge in graph.neighbors(vertex).into_iter().flatten() {
+            *in_degree.get_mut(&edge.to).unwrap() += 1;
+        }
+    }
+
+    let mut ready: VecDeque<&T> = in_degree
+        .iter()
+        .filter(|&(_, &degree)| degree == 0)
+        .map(|(&vertex, _)| vertex)
+        .collect();
+    let mut order = Vec::with_capacity(graph.vertex_count());
+
+    while let Some(vertex) = ready.pop_front() {
+        order.push(vertex.clone());
+        for edge in graph.neighbors(vertex).into_iter().flatten() {
+            let degree = in_degree.get_mut(&edge.to).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push_back(&edge.to);
+            }
+        }
+    }
+
+    if order.len() == graph.vertex_count() {
+        Ok(order)
+    } else {
+        Err(NotADag)
+    }
+}
+
+/// Relaxes edges in topological order, keeping the longest distance to each
+/// vertex along with the predecessor that achieved it
+fn relax_longest<T, W>(
+    graph: &WeightedGraph<T, W>,
+    order: &[T],
+    distances: &mut HashMap<T, W>,
+) -> HashMap<T, T>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Ord + Add<Output = W>,
+{
+    let mut previous = HashMap::new();
+
+    for vertex in order {
+        let Some(distance) = distances.get(vertex).cloned() else {
+            continue;
+        };
+        for edge in graph.neighbors(vertex).into_iter().flatten() {
+            let new_dist = distance.clone() + edge.weight.clone();
+            if distances
+                .get(&edge.to)
+                .map_or(true, |existing| new_dist > *existing)
+            {
+                distances.insert(edge.to.clone(), new_dist);
+                previous.insert(edge.to.clone(), vertex.clone());
+            }
+        }
+    }
+
+    previous
+}
+
+/// Returns the longest distance from `start` to every vertex it reaches
+///
+/// Runs in O(V + E) by relaxing edges in topological order. Returns
+/// [`NotADag`] if the graph contains a cycle anywhere, even one `start`
+/// cannot reach.
+pub fn longest_path_dag<T, W>(
+    graph: &WeightedGraph<T, W>,
+    start: &T,
+) -> Result<HashMap<T, W>, NotADag>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Ord + Default + Add<Output = W>,
+{
+    let order = weighted_topological_order(graph)?;
+
+    let mut distances = HashMap::new();
+    if graph.has_vertex(start) {
+        distances.insert(start.clone(), W::default());
+    }
+    relax_longest(graph, &order, &mut distances);
+
+    Ok(distances)
+}
+
+/// Returns the number of edges on the longest path anywhere in a DAG
+///
+/// This is the unweighted counterpart of [`critical_path`], computed by
+/// dynamic programming over a topological order in O(V + E). Returns
+/// [`NotADag`] for undirected graphs with edges and for any cycle.
+pub fn longest_path_edges<T>(graph: &Graph<T>) -> Result<usize, NotADag>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut in_degree: HashMap<&T, usize> = graph.vertices().map(|vertex| (vertex, 0)).collect();
+    for vertex in graph.vertices() {
+        for neighbor in graph.neighbors(vertex).into_iter().flatten() {
+            *in_degree.get_mut(neighbor).unwrap() += 1;
+        }
+    }
+
+    let mut ready: VecDeque<&T> = in_degree
+        .iter()
+        .filter(|&(_, &degree)| degree == 0)
+        .map(|(&vertex, _)| vertex)
+        .collect();
+    // Edges on the longest path ending at each vertex
+    let mut lengths: HashMap<&T, usize> = HashMap::new();
+    let mut processed = 0;
+    let mut longest = 0;
+
+    while let Some(vertex) = ready.pop_front() {
+        processed += 1;
+        let length = lengths.get(vertex).copied().unwrap_or(0);
+        longest = longest.max(length);
+
+        for neighbor in graph.neighbors(vertex).into_iter().flatten() {
+            let best = lengths.entry(neighbor).or_insert(0);
+            *best = (*best).max(length + 1);
+
+            let degree = in_degree.get_mut(neighbor).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push_back(neighbor);
+            }
+        }
+    }
+
+    if processed == graph.vertex_count() {
+        Ok(longest)
+    } else {
+        Err(NotADag)
+    }
+}
+
+/// Returns the length and vertices of the longest path anywhere in a DAG
+///
+/// This is the critical path of a task graph whose edge weights are
+/// durations. When several paths tie, one of them is returned. An empty graph
+/// yields a zero length and an empty path.
+pub fn critical_path<T, W>(graph: &WeightedGraph<T, W>) -> Result<(W, Vec<T>), NotADag>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Ord + Default + Add<Output = W>,
+{
+    let order = weighted_topological_order(graph)?;
+
+    // Every vertex may start the path, as if fed by a zero-weight virtual source
+    let mut distances: HashMap<T, W> = order
+        .iter()
+        .map(|vertex| (vertex.clone(), W::default()))
+        .collect();
+    let previous = relax_longest(graph, &order, &mut distances);
+
+    let Some((end, length)) = distances.iter().max_by(|a, b| a.1.cmp(b.1)) else {
+        return Ok((W::default(), Vec::new()));
+    };
+
+    let mut path = vec![end.clone()];
+    while let Some(prev) = previous.get(path.last().unwrap()) {
+        path.push(prev.clone());
+    }
+    path.reverse();
+
+    Ok((length.clone(), path))
 }
 
 #[cfg(test)]
@@ -478,6 +1202,121 @@ mod tests {
     use super::*;
     use crate::graph::Graph;
 
+    #[test]
+    fn traversal_trees() {
+        let mut graph = Graph::undirected();
+        for (from, to) in [
+            (1, 2),
+            (1, 3),
+            (2, 4),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (2, 6),
+            (7, 8),
+        ] {
+            graph.add_edge(from, to);
+        }
+
+        let reachable = bfs(&graph, &1).len();
+        let tree = bfs_tree(&graph, &1);
+        assert_eq!(tree.vertex_count(), reachable);
+        assert_eq!(tree.edge_count(), reachable - 1);
+        assert!(!is_cyclic(&tree));
+        assert!(is_tree(&tree));
+
+        let parents = bfs_parents(&graph, &1);
+        for target in [1, 4, 5, 6] {
+            let through_tree = shortest_path(&tree, &1, &target).unwrap();
+            assert_eq!(through_tree, shortest_path(&graph, &1, &target).unwrap());
+            assert_eq!(
+                through_tree.len(),
+                shortest_path(&graph, &1, &target).unwrap().len()
+            );
+            if target != 1 {
+                assert_eq!(through_tree, reconstruct_path(&parents, &1, &target));
+            }
+        }
+        assert_eq!(
+            shortest_path(&graph, &1, &6).map(|path| path.len()),
+            Some(3)
+        );
+        assert_eq!(shortest_path(&graph, &1, &8), None);
+
+        let tree = dfs_tree(&graph, &1);
+        assert_eq!(tree.edge_count(), reachable - 1);
+        assert!(is_tree(&tree));
+        assert_eq!(bfs(&tree, &1).len(), reachable);
+        assert_eq!(dfs_tree(&graph, &99).vertex_count(), 0);
+    }
+
+    #[test]
+    fn transitive_closure_and_reduction() {
+        let mut graph = Graph::directed();
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(1, 3);
+        graph.add_vertex(4);
+
+        let closure = transitive_closure(&graph);
+        assert_eq!(closure.edge_count(), 3);
+        assert!(closure.has_vertex(&4));
+
+        let reduction = transitive_reduction(&graph).unwrap();
+        assert_eq!(reduction.vertex_count(), 4);
+        assert_eq!(reduction.edge_count(), 2);
+        assert!(reduction.has_edge(&1, &2));
+        assert!(reduction.has_edge(&2, &3));
+        assert!(!reduction.has_edge(&1, &3));
+
+        graph.add_edge(3, 1);
+        assert_eq!(transitive_reduction(&graph).unwrap_err(), NotADag);
+        assert!(transitive_closure(&graph).has_edge(&2, &2));
+    }
+
+    #[test]
+    fn transitive_closure_of_chain_and_cycle() {
+        let mut chain = Graph::directed();
+        for i in 1..5 {
+            chain.add_edge(i, i + 1);
+        }
+        let closure = transitive_closure(&chain);
+        assert_eq!(closure.edge_count(), 10);
+        assert!(closure.has_edge(&1, &5));
+        assert!(!closure.has_edge(&5, &1));
+        assert!(!closure.has_edge(&3, &3));
+
+        chain.add_edge(5, 1);
+        let closure = transitive_closure(&chain);
+        // Every vertex reaches every vertex, itself included
+        assert_eq!(closure.edge_count(), 25);
+    }
+
+    #[test]
+    fn visit_callbacks_match_traversals() {
+        let mut graph = Graph::directed();
+        graph.add_edge(1, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 4);
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 5);
+        graph.add_vertex(6);
+
+        let mut sum = 0;
+        bfs_visit(&graph, &1, |vertex| sum += vertex);
+        assert_eq!(sum, bfs(&graph, &1).iter().sum::<i32>());
+        assert_eq!(sum, 15);
+
+        let mut order = Vec::new();
+        dfs_visit(&graph, &1, |vertex| order.push(*vertex));
+        assert_eq!(order, dfs(&graph, &1));
+
+        let mut calls = 0;
+        bfs_visit(&graph, &99, |_| calls += 1);
+        dfs_visit(&graph, &6, |_| calls += 1);
+        assert_eq!(calls, 1);
+    }
+
     #[test]
     fn test_bfs() {
         let mut graph = Graph::directed();
@@ -530,6 +1369,7 @@ mod tests {
         assert_eq!(path.len(), 3);
         assert_eq!(path[0], 1);
         assert_eq!(path[2], 4);
+        assert!(is_valid_path(&graph, &path));
     }
 
     #[test]
@@ -543,6 +1383,22 @@ mod tests {
         assert_eq!(components.len(), 3);
     }
 
+    #[test]
+    fn reachable_within_hops() {
+        let mut path = Graph::undirected();
+        for i in 0..4 {
+            path.add_edge(i, i + 1);
+        }
+
+        assert_eq!(reachable_within(&path, &0, 2), HashSet::from([0, 1, 2]));
+        assert_eq!(reachable_within(&path, &0, 0), HashSet::from([0]));
+        assert_eq!(reachable_within(&path, &2, 10).len(), 5);
+        assert!(reachable_within(&path, &9, 3).is_empty());
+
+        assert!(path_exists_within(&path, &4, &1, 3));
+        assert!(!path_exists_within(&path, &4, &0, 3));
+    }
+
     #[test]
     fn test_cycle_detection() {
         let mut directed_cyclic = Graph::directed();
@@ -563,6 +1419,58 @@ mod tests {
         assert!(is_cyclic(&undirected_cyclic));
     }
 
+    #[test]
+    fn all_cycles_enumerates_each_once() {
+        let mut graph = Graph::directed();
+        for (from, to) in [(1, 2), (2, 3), (3, 1), (3, 4), (4, 2), (4, 5), (6, 6)] {
+            graph.add_edge(from, to);
+        }
+
+        let mut cycles: Vec<Vec<i32>> = all_cycles(&graph)
+            .into_iter()
+            .map(|mut cycle| {
+                let lowest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+                cycle.rotate_left(lowest);
+                cycle
+            })
+            .collect();
+        cycles.sort();
+        let found = cycles.len();
+        cycles.dedup();
+
+        assert_eq!(found, cycles.len());
+        assert_eq!(cycles, vec![vec![1, 2, 3], vec![2, 3, 4], vec![6]]);
+
+        let mut dag = Graph::directed();
+        dag.add_edge(1, 2);
+        dag.add_edge(1, 3);
+        dag.add_edge(2, 3);
+        assert!(all_cycles(&dag).is_empty());
+    }
+
+    #[test]
+    fn all_cycles_undirected() {
+        let mut triangle = Graph::undirected();
+        for (from, to) in [(1, 2), (2, 3), (3, 1), (3, 4)] {
+            triangle.add_edge(from, to);
+        }
+        let cycles = all_cycles(&triangle);
+        assert_eq!(cycles.len(), 1);
+        let mut cycle = cycles[0].clone();
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2, 3]);
+
+        // Two triangles sharing an edge form a third cycle around the outside
+        triangle.add_edge(4, 2);
+        assert_eq!(all_cycles(&triangle).len(), 3);
+
+        let mut path = Graph::undirected();
+        path.add_edge(1, 2);
+        path.add_edge(2, 3);
+        path.add_edge(4, 4);
+        assert_eq!(all_cycles(&path), vec![vec![4]]);
+    }
+
     #[test]
     fn test_dijkstra_basic() {
         let mut graph = WeightedGraph::directed();
@@ -592,6 +1500,7 @@ mod tests {
 
         assert_eq!(distance, Some(6));
         assert_eq!(path, Some(vec![1, 3, 2, 4]));
+        assert_eq!(path_weight(&graph, &path.unwrap()), distance);
     }
 
     #[test]
@@ -616,6 +1525,7 @@ mod tests {
         let (distance, path) = dijkstra_shortest_path(&graph, &1, &1);
         assert_eq!(distance, Some(0));
         assert_eq!(path, Some(vec![1]));
+        assert_eq!(path_weight(&graph, &path.unwrap()), distance);
     }
 
     #[test]
@@ -634,8 +1544,7 @@ mod tests {
         assert_eq!(distances.get(&"D"), Some(&40));
     }
 
-    #[test]
-    fn test_dijkstra_complex_graph() {
+    fn complex_graph() -> WeightedGraph<i32, i32> {
         let mut graph = WeightedGraph::directed();
 
         graph.add_edge(0, 1, 4);
@@ -652,7 +1561,30 @@ mod tests {
         graph.add_edge(6, 7, 1);
         graph.add_edge(6, 8, 6);
         graph.add_edge(7, 8, 7);
+        graph
+    }
 
+    #[test]
+    fn all_shortest_paths_complex_graph() {
+        let graph = complex_graph();
+        let distances = dijkstra(&graph, &0);
+        let paths = all_shortest_paths(&graph, &0);
+
+        assert_eq!(paths.len(), distances.len());
+        for (vertex, (distance, path)) in &paths {
+            assert_eq!(distances.get(vertex), Some(distance));
+            assert_eq!(path.first(), Some(&0));
+            assert_eq!(path.last(), Some(vertex));
+            assert_eq!(path_weight(&graph, path), Some(*distance));
+        }
+        assert_eq!(paths[&0], (0, vec![0]));
+        assert_eq!(paths[&6].1, vec![0, 1, 2, 5, 6]);
+        assert!(all_shortest_paths(&graph, &42).is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra_complex_graph() {
+        let graph = complex_graph();
         let distances = dijkstra(&graph, &0);
 
         assert_eq!(distances.get(&0), Some(&0));
@@ -660,5 +1592,343 @@ mod tests {
         assert_eq!(distances.get(&2), Some(&12));
         assert_eq!(distances.get(&5), Some(&16));
         assert_eq!(distances.get(&6), Some(&18));
+
+        let (_, previous) = dijkstra_with_path(&graph, &0);
+        for (vertex, distance) in &distances {
+            let path = reconstruct_dijkstra_path(&previous, &0, vertex).unwrap();
+            assert_eq!(path_weight(&graph, &path), Some(*distance));
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_on_indexed_graph() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge("A", "B", 25);
+        graph.add_edge("A", "C", 10);
+        graph.add_edge("B", "D", 15);
+        graph.add_edge("C", "D", 35);
+        graph.add_vertex("E");
+
+        let (indexed, index, vertices) = graph.to_indexed();
+        let indexed_distances = dijkstra(&indexed, &index[&"A"]);
+
+        let translated: HashMap<_, _> = indexed_distances
+            .into_iter()
+            .map(|(i, distance)| (vertices[i], distance))
+            .collect();
+
+        assert_eq!(translated, dijkstra(&graph, &"A"));
+    }
+
+    #[test]
+    fn test_is_valid_path() {
+        let mut graph = Graph::directed();
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 1);
+        graph.add_vertex(4);
+
+        assert!(is_valid_path(&graph, &[1, 2, 3]));
+        assert!(is_valid_path(&graph, &[1, 2, 3, 1, 2]));
+        assert!(!is_valid_path(&graph, &[1, 3]));
+        assert!(!is_valid_path(&graph, &[]));
+        assert!(is_valid_path(&graph, &[4]));
+        assert!(!is_valid_path(&graph, &[5]));
+        assert!(!is_valid_path(&graph, &[1, 1]));
+    }
+
+    #[test]
+    fn test_path_weight() {
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge("a", "b", 3);
+        graph.add_edge("b", "c", 4);
+
+        assert_eq!(path_weight(&graph, &["a", "b", "c"]), Some(7));
+        assert_eq!(path_weight(&graph, &["c", "b", "a", "b"]), Some(10));
+        assert_eq!(path_weight(&graph, &["a", "c"]), None);
+        assert_eq!(path_weight(&graph, &["a"]), Some(0));
+        assert_eq!(path_weight(&graph, &["z"]), None);
+        assert_eq!(path_weight::<&str, i32>(&graph, &[]), None);
+    }
+
+    #[test]
+    fn test_dijkstra_skips_stale_entries() {
+        let n = 30;
+        let mut graph = WeightedGraph::directed();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                graph.add_edge(i, j, (j - i) * (j - i));
+            }
+        }
+
+        let (distances, stats) = dijkstra_with_stats(&graph, &0);
+
+        for v in 0..n {
+            assert_eq!(distances.get(&v), Some(&v));
+        }
+        assert_eq!(stats.settled, n);
+        assert!(stats.relaxations > n);
+        assert!(stats.stale_skips > 0);
+        assert_eq!(stats.settled + stats.stale_skips, stats.relaxations + 1);
+        assert_eq!(distances, dijkstra(&graph, &0));
+    }
+
+    #[test]
+    fn shortest_paths_match_free_functions() {
+        let graph = complex_graph();
+        let paths = dijkstra_paths(&graph, &0);
+        let distances = dijkstra(&graph, &0);
+        let (_, previous) = dijkstra_with_path(&graph, &0);
+
+        assert_eq!(paths.start(), &0);
+        assert_eq!(paths.iter_reachable().count(), distances.len());
+        for (vertex, distance) in &distances {
+            assert_eq!(paths.distance_to(vertex), Some(distance));
+            assert_eq!(
+                paths.path_to(vertex),
+                reconstruct_dijkstra_path(&previous, &0, vertex)
+            );
+            assert_eq!(
+                dijkstra_shortest_path(&graph, &0, vertex),
+                (Some(*distance), paths.path_to(vertex))
+            );
+        }
+
+        assert_eq!(paths.path_to(&0), Some(vec![0]));
+        assert!(!paths.is_reachable(&42));
+        assert_eq!(paths.path_to(&42), None);
+        assert!(!dijkstra_paths(&graph, &42).is_reachable(&42));
+    }
+
+    #[test]
+    fn shortest_path_tree_matches_distances() {
+        let mut graph = complex_graph();
+        graph.add_edge(9, 0, 1);
+        graph.add_vertex(10);
+
+        let distances = dijkstra(&graph, &0);
+        let tree = shortest_path_tree(&graph, &0);
+
+        assert_eq!(tree.vertex_count(), distances.len());
+        assert_eq!(tree.edge_count(), distances.len() - 1);
+        assert!(!tree.has_vertex(&9));
+        assert!(!tree.has_vertex(&10));
+        assert_eq!(tree.in_degree(&0), Some(0));
+
+        for (vertex, distance) in &distances {
+            if *vertex != 0 {
+                assert_eq!(tree.in_degree(vertex), Some(1));
+            }
+            let path = dijkstra_paths(&tree, &0).path_to(vertex).unwrap();
+            assert_eq!(path_weight(&tree, &path), Some(*distance));
+            assert_eq!(path_weight(&graph, &path), Some(*distance));
+        }
+
+        assert_eq!(shortest_path_tree(&graph, &42).vertex_count(), 0);
+    }
+
+    #[test]
+    fn test_dijkstra_weight_ties() {
+        let mut graph = WeightedGraph::undirected();
+        for i in 0..10 {
+            graph.add_edge(i, i + 1, 1);
+            graph.add_edge(i, i + 2, 2);
+        }
+
+        let (distances, previous) = dijkstra_with_path(&graph, &0);
+        for v in 0..=11 {
+            assert_eq!(distances[&v], v);
+            let path = reconstruct_dijkstra_path(&previous, &0, &v).unwrap();
+            assert_eq!(path_weight(&graph, &path), Some(v));
+        }
+    }
+
+    #[test]
+    fn test_is_tree_and_forest() {
+        let mut path = Graph::undirected();
+        path.add_edge(1, 2);
+        path.add_edge(2, 3);
+        path.add_edge(3, 4);
+        assert!(is_tree(&path));
+        assert!(is_forest(&path));
+
+        path.add_edge(4, 1);
+        assert!(!is_tree(&path));
+        assert!(!is_forest(&path));
+
+        let mut two_paths = Graph::undirected();
+        two_paths.add_edge(1, 2);
+        two_paths.add_edge(3, 4);
+        two_paths.add_edge(4, 5);
+        assert!(!is_tree(&two_paths));
+        assert!(is_forest(&two_paths));
+
+        let empty: Graph<i32> = Graph::undirected();
+        assert!(!is_tree(&empty));
+        assert!(is_forest(&empty));
+    }
+
+    #[test]
+    fn test_is_tree_directed() {
+        let mut arborescence = Graph::directed();
+        arborescence.add_edge("root", "a");
+        arborescence.add_edge("root", "b");
+        arborescence.add_edge("a", "c");
+        assert!(is_tree(&arborescence));
+
+        arborescence.add_edge("b", "c");
+        assert!(!is_tree(&arborescence));
+        assert!(!is_forest(&arborescence));
+    }
+
+    fn floyd_warshall(graph: &WeightedGraph<i32, i64>) -> HashMap<(i32, i32), i64> {
+        let vertices: Vec<i32> = graph.vertices().cloned().collect();
+        let mut distances = HashMap::new();
+
+        for &v in &vertices {
+            distances.insert((v, v), 0);
+            for edge in graph.neighbors(&v).unwrap() {
+                let best = distances.entry((v, edge.to)).or_insert(edge.weight);
+                *best = (*best).min(edge.weight);
+            }
+        }
+
+        for &k in &vertices {
+            for &i in &vertices {
+                for &j in &vertices {
+                    if let (Some(&ik), Some(&kj)) = (distances.get(&(i, k)), distances.get(&(k, j)))
+                    {
+                        let best = distances.entry((i, j)).or_insert(ik + kj);
+                        *best = (*best).min(ik + kj);
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    #[test]
+    fn test_johnson_matches_floyd_warshall() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(0, 2, 8);
+        graph.add_edge(0, 4, -4);
+        graph.add_edge(1, 3, 1);
+        graph.add_edge(1, 4, 7);
+        graph.add_edge(2, 1, 4);
+        graph.add_edge(3, 0, 2);
+        graph.add_edge(3, 2, -5);
+        graph.add_edge(4, 3, 6);
+        graph.add_vertex(5);
+
+        let all_pairs = johnson(&graph).unwrap();
+        assert_eq!(all_pairs, floyd_warshall(&graph));
+        assert_eq!(all_pairs[&(0, 2)], -3);
+        assert_eq!(all_pairs[&(2, 4)], 3);
+        assert!(!all_pairs.contains_key(&(0, 5)));
+
+        let from_zero = bellman_ford(&graph, &0).unwrap();
+        for (v, distance) in from_zero {
+            assert_eq!(all_pairs[&(0, v)], distance);
+        }
+    }
+
+    #[test]
+    fn test_negative_cycle_detection() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("b", "c", -2);
+        graph.add_edge("c", "b", 1);
+        graph.add_vertex("d");
+
+        assert_eq!(bellman_ford(&graph, &"a"), Err(NegativeCycleError));
+        assert_eq!(
+            johnson(&graph).unwrap_err(),
+            bellman_ford(&graph, &"a").unwrap_err()
+        );
+        assert_eq!(bellman_ford(&graph, &"d").unwrap().len(), 1);
+
+        let mut undirected = WeightedGraph::undirected();
+        undirected.add_edge(1, 2, -1);
+        assert_eq!(johnson(&undirected), Err(NegativeCycleError));
+    }
+
+    #[test]
+    fn test_empty_graph_has_no_negative_cycle() {
+        let graph: WeightedGraph<i32, i32> = WeightedGraph::directed();
+        assert_eq!(bellman_ford(&graph, &0), Ok(HashMap::new()));
+        assert_eq!(johnson(&graph), Ok(HashMap::new()));
+    }
+
+    #[test]
+    fn longest_paths_and_critical_path() {
+        let mut tasks = WeightedGraph::directed();
+        for (from, to, duration) in [
+            ("a", "b", 3),
+            ("a", "c", 2),
+            ("b", "d", 4),
+            ("c", "d", 1),
+            ("d", "e", 2),
+            ("c", "e", 6),
+        ] {
+            tasks.add_edge(from, to, duration);
+        }
+        tasks.add_vertex("idle");
+
+        let longest = longest_path_dag(&tasks, &"a").unwrap();
+        assert_eq!(longest[&"d"], 7);
+        assert_eq!(longest[&"e"], 9);
+        assert!(!longest.contains_key(&"idle"));
+
+        assert_eq!(critical_path(&tasks), Ok((9, vec!["a", "b", "d", "e"])));
+        assert_eq!(
+            critical_path(&WeightedGraph::<u8, u32>::directed()),
+            Ok((0, vec![]))
+        );
+    }
+
+    #[test]
+    fn longest_path_edges_in_dag() {
+        let mut tasks = Graph::directed();
+        tasks.add_edge("design", "build");
+        tasks.add_edge("build", "test");
+        tasks.add_edge("test", "release");
+        tasks.add_edge("design", "docs");
+        tasks.add_edge("docs", "release");
+        tasks.add_edge("design", "release");
+        tasks.add_vertex("unrelated");
+        assert_eq!(longest_path_edges(&tasks), Ok(3));
+
+        assert_eq!(longest_path_edges(&Graph::<u8>::directed()), Ok(0));
+
+        tasks.add_edge("release", "design");
+        assert_eq!(longest_path_edges(&tasks), Err(NotADag));
+
+        let mut undirected = Graph::undirected();
+        undirected.add_edge(1, 2);
+        assert_eq!(longest_path_edges(&undirected), Err(NotADag));
+    }
+
+    #[test]
+    fn critical_path_ties_and_cycles() {
+        let mut tied = WeightedGraph::directed();
+        tied.add_edge(1, 2, 2);
+        tied.add_edge(2, 4, 2);
+        tied.add_edge(1, 3, 1);
+        tied.add_edge(3, 4, 3);
+
+        let (length, path) = critical_path(&tied).unwrap();
+        assert_eq!(length, 4);
+        assert!(path == [1, 2, 4] || path == [1, 3, 4]);
+        assert_eq!(path_weight(&tied, &path), Some(4));
+
+        tied.add_edge(4, 1, 1);
+        assert_eq!(critical_path(&tied), Err(NotADag));
+        assert_eq!(longest_path_dag(&tied, &1), Err(NotADag));
+
+        let mut undirected = WeightedGraph::undirected();
+        undirected.add_edge(1, 2, 5);
+        assert_eq!(critical_path(&undirected), Err(NotADag));
     }
 }
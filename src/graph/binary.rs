@@ -0,0 +1,472 @@
+//! Compact binary save and load for [`Graph`] and [`WeightedGraph`]
+//!
+//! All integers are little-endian `u64`. A file starts with the magic bytes
+//! `RDSG`, a format version, whether edges carry weights, and whether the
+//! graph is undirected, followed by the vertex count and one encoded id per
+//! vertex. Edges follow in CSR layout: for each vertex in order, its
+//! out-degree and then the indices of its targets into the vertex list, each
+//! followed by an encoded weight in the weighted format. Undirected edges are
+//! written once, from the endpoint listed first.
+
+use super::adjacency_list::{Graph, GraphType};
+use super::weighted_graph::{GraphType as WeightedGraphType, WeightedGraph};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8; 4] = b"RDSG";
+const VERSION: u8 = 1;
+
+#[derive(Debug)]
+pub enum DecodeError {
+    Io(io::Error),
+    Invalid(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Io(err) => write!(f, "failed to read graph: {err}"),
+            DecodeError::Invalid(reason) => write!(f, "invalid binary graph: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DecodeError::Io(err) => Some(err),
+            DecodeError::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for DecodeError {
+    fn from(err: io::Error) -> Self {
+        DecodeError::Io(err)
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: Clone + Eq + Hash,
+{
+    /// Writes the graph in the binary format, mapping each vertex to a `u64`
+    /// id with `encode`
+    ///
+    /// Ids must be distinct for [`Graph::read_binary_with`] to accept them.
+    pub fn write_binary_with<Wr, F>(&self, mut writer: Wr, encode: F) -> io::Result<()>
+    where
+        Wr: Write,
+        F: FnMut(&T) -> u64,
+    {
+        let undirected = self.graph_type() == &GraphType::Undirected;
+        let (vertices, index) =
+            write_vertices(&mut writer, self.vertices(), false, undirected, encode)?;
+
+        for (from, vertex) in vertices.iter().enumerate() {
+            let targets: Vec<u64> = self
+                .neighbors(vertex)
+                .into_iter()
+                .flatten()
+                .map(|neighbor| index[neighbor])
+                .filter(|&to| !undirected || to >= from as u64)
+                .collect();
+            write_u64(&mut writer, targets.len() as u64)?;
+            for to in targets {
+                write_u64(&mut writer, to)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a graph written by [`Graph::write_binary_with`], mapping ids
+    /// back to vertices with `decode`
+    ///
+    /// Fails on truncated input, on a weighted or unknown format, and when
+    /// `decode` rejects an id or two vertices share one.
+    pub fn read_binary_with<R, F>(mut reader: R, decode: F) -> Result<Self, DecodeError>
+    where
+        R: Read,
+        F: FnMut(u64) -> Option<T>,
+    {
+        let (undirected, vertices) = read_vertices(&mut reader, false, decode)?;
+        let graph_type = if undirected {
+            GraphType::Undirected
+        } else {
+            GraphType::Directed
+        };
+
+        let mut graph = Graph::with_vertex_capacity(vertices.len(), graph_type);
+        if graph.add_vertices(vertices.iter().cloned()) != vertices.len() {
+            return Err(DecodeError::Invalid("duplicate vertex id"));
+        }
+        for from in &vertices {
+            for _ in 0..read_u64(&mut reader)? {
+                let to = read_index(&mut reader, &vertices)?;
+                graph.add_edge(from.clone(), to.clone());
+            }
+        }
+        Ok(graph)
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: Copy + Eq + Hash + Into<u64> + TryFrom<u64>,
+{
+    /// Writes the graph in the binary format using the vertices as ids
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_ds_lib_bee::graph::Graph;
+    ///
+    /// let mut graph: Graph<u32> = Graph::undirected();
+    /// graph.add_edge(1, 2);
+    /// graph.add_edge(2, 3);
+    ///
+    /// let mut bytes = Vec::new();
+    /// graph.write_binary(&mut bytes).unwrap();
+    /// assert_eq!(Graph::<u32>::read_binary(bytes.as_slice()).unwrap(), graph);
+    /// ```
+    pub fn write_binary<Wr: Write>(&self, writer: Wr) -> io::Result<()> {
+        self.write_binary_with(writer, |&vertex| vertex.into())
+    }
+
+    /// Reads a graph written by [`Graph::write_binary`]
+    pub fn read_binary<R: Read>(reader: R) -> Result<Self, DecodeError> {
+        Self::read_binary_with(reader, |id| T::try_from(id).ok())
+    }
+}
+
+impl<T, W> WeightedGraph<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone,
+{
+    /// Writes the graph in the weighted binary format, mapping vertices with
+    /// `encode_vertex` and weights with `encode_weight`
+    ///
+    /// Parallel edges are all written, but reading keeps only the first.
+    pub fn write_binary_with<Wr, F, G>(
+        &self,
+        mut writer: Wr,
+        encode_vertex: F,
+        mut encode_weight: G,
+    ) -> io::Result<()>
+    where
+        Wr: Write,
+        F: FnMut(&T) -> u64,
+        G: FnMut(&W) -> u64,
+    {
+        let undirected = self.graph_type() == &WeightedGraphType::Undirected;
+        let (vertices, index) = write_vertices(
+            &mut writer,
+            self.vertices(),
+            true,
+            undirected,
+            encode_vertex,
+        )?;
+
+        for (from, vertex) in vertices.iter().enumerate() {
+            let targets: Vec<(u64, &W)> = self
+                .neighbors(vertex)
+                .into_iter()
+                .flatten()
+                .map(|edge| (index[&edge.to], &edge.weight))
+                .filter(|&(to, _)| !undirected || to >= from as u64)
+                .collect();
+            write_u64(&mut writer, targets.len() as u64)?;
+            for (to, weight) in targets {
+                write_u64(&mut writer, to)?;
+                write_u64(&mut writer, encode_weight(weight))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a graph written by [`WeightedGraph::write_binary_with`]
+    ///
+    /// Fails like [`Graph::read_binary_with`], and also when `decode_weight`
+    /// rejects a weight.
+    pub fn read_binary_with<R, F, G>(
+        mut reader: R,
+        decode_vertex: F,
+        mut decode_weight: G,
+    ) -> Result<Self, DecodeError>
+    where
+        R: Read,
+        F: FnMut(u64) -> Option<T>,
+        G: FnMut(u64) -> Option<W>,
+    {
+        let (undirected, vertices) = read_vertices(&mut reader, true, decode_vertex)?;
+        let graph_type = if undirected {
+            WeightedGraphType::Undirected
+        } else {
+            WeightedGraphType::Directed
+        };
+
+        let mut graph = WeightedGraph::with_vertex_capacity(vertices.len(), graph_type);
+        if graph.add_vertices(vertices.iter().cloned()) != vertices.len() {
+            return Err(DecodeError::Invalid("duplicate vertex id"));
+        }
+        for from in &vertices {
+            for _ in 0..read_u64(&mut reader)? {
+                let to = read_index(&mut reader, &vertices)?;
+                let weight = decode_weight(read_u64(&mut reader)?)
+                    .ok_or(DecodeError::Invalid("weight out of range"))?;
+                graph.add_edge(from.clone(), to.clone(), weight);
+            }
+        }
+        Ok(graph)
+    }
+}
+
+impl<T, W> WeightedGraph<T, W>
+where
+    T: Copy + Eq + Hash + Into<u64> + TryFrom<u64>,
+    W: Copy + Into<u64> + TryFrom<u64>,
+{
+    /// Writes the graph in the weighted binary format using the vertices
+    /// and weights as ids
+    ///
+    /// Floating-point weights can go through
+    /// [`write_binary_with`](Self::write_binary_with) with `f64::to_bits`.
+    pub fn write_binary<Wr: Write>(&self, writer: Wr) -> io::Result<()> {
+        self.write_binary_with(writer, |&vertex| vertex.into(), |&weight| weight.into())
+    }
+
+    /// Reads a graph written by [`WeightedGraph::write_binary`]
+    pub fn read_binary<R: Read>(reader: R) -> Result<Self, DecodeError> {
+        Self::read_binary_with(
+            reader,
+            |id| T::try_from(id).ok(),
+            |weight| W::try_from(weight).ok(),
+        )
+    }
+}
+
+// Writes the header and vertex ids, returning the vertex order and each
+// vertex's index in it
+fn write_vertices<'a, T, Wr, F>(
+    writer: &mut Wr,
+    vertices: impl Iterator<Item = &'a T>,
+    weighted: bool,
+    undirected: bool,
+    mut encode: F,
+) -> io::Result<(Vec<&'a T>, HashMap<&'a T, u64>)>
+where
+    T: Eq + Hash + 'a,
+    Wr: Write,
+    F: FnMut(&T) -> u64,
+{
+    let vertices: Vec<&T> = vertices.collect();
+    let index: HashMap<&T, u64> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, &vertex)| (vertex, i as u64))
+        .collect();
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION, weighted as u8, undirected as u8])?;
+    write_u64(writer, vertices.len() as u64)?;
+    for vertex in &vertices {
+        write_u64(writer, encode(vertex))?;
+    }
+    Ok((vertices, index))
+}
+
+// Reads the header and vertex ids, returning whether the graph is
+// undirected and the vertices in file order
+fn read_vertices<T, R, F>(
+    reader: &mut R,
+    weighted: bool,
+    mut decode: F,
+) -> Result<(bool, Vec<T>), DecodeError>
+where
+    R: Read,
+    F: FnMut(u64) -> Option<T>,
+{
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(DecodeError::Invalid("not a binary graph"));
+    }
+
+    let mut flags = [0; 3];
+    reader.read_exact(&mut flags)?;
+    let [version, has_weights, undirected] = flags;
+    if version != VERSION {
+        return Err(DecodeError::Invalid("unsupported format version"));
+    }
+    if has_weights != weighted as u8 {
+        return Err(DecodeError::Invalid(if weighted {
+            "graph has no edge weights"
+        } else {
+            "graph has edge weights"
+        }));
+    }
+    if undirected > 1 {
+        return Err(DecodeError::Invalid("unknown graph type"));
+    }
+
+    let count = read_u64(reader)?;
+    // The count is untrusted, so only reserve what a small graph would need
+    let mut vertices = Vec::with_capacity(count.min(1 << 16) as usize);
+    for _ in 0..count {
+        let id = read_u64(reader)?;
+        vertices.push(decode(id).ok_or(DecodeError::Invalid("vertex id out of range"))?);
+    }
+    Ok((undirected == 1, vertices))
+}
+
+fn read_index<'a, T, R: Read>(reader: &mut R, vertices: &'a [T]) -> Result<&'a T, DecodeError> {
+    let index = read_u64(reader)?;
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| vertices.get(index))
+        .ok_or(DecodeError::Invalid("edge target out of range"))
+}
+
+fn write_u64<Wr: Write>(writer: &mut Wr, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph(graph_type: GraphType) -> Graph<u32> {
+        let mut graph = Graph::new(graph_type);
+        for i in 0..20 {
+            graph.add_edge(i, (i * 7 + 3) % 20);
+            graph.add_edge(i, (i * 3 + 1) % 20);
+        }
+        graph.add_edge(5, 5);
+        graph.add_vertex(100);
+        graph
+    }
+
+    #[test]
+    fn round_trip_directed_and_undirected() {
+        for graph_type in [GraphType::Directed, GraphType::Undirected] {
+            let graph = sample_graph(graph_type);
+            let mut bytes = Vec::new();
+            graph.write_binary(&mut bytes).unwrap();
+
+            let loaded = Graph::<u32>::read_binary(bytes.as_slice()).unwrap();
+            assert_eq!(loaded.edge_count(), graph.edge_count());
+            assert_eq!(loaded.vertex_count(), 21);
+            assert_eq!(loaded, graph);
+        }
+
+        let mut bytes = Vec::new();
+        Graph::<u8>::directed().write_binary(&mut bytes).unwrap();
+        assert_eq!(
+            Graph::<u8>::read_binary(bytes.as_slice())
+                .unwrap()
+                .vertex_count(),
+            0
+        );
+    }
+
+    #[test]
+    fn weighted_round_trip_with_codec() {
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge("a", "b", 1.5);
+        graph.add_edge("b", "c", -2.25);
+        graph.add_edge("c", "c", 0.5);
+        graph.add_vertex("d");
+        let names = ["a", "b", "c", "d"];
+
+        let mut bytes = Vec::new();
+        graph
+            .write_binary_with(
+                &mut bytes,
+                |name| names.iter().position(|n| n == name).unwrap() as u64,
+                |weight: &f64| weight.to_bits(),
+            )
+            .unwrap();
+        let loaded = WeightedGraph::read_binary_with(
+            bytes.as_slice(),
+            |id| names.get(id as usize).copied(),
+            |bits| Some(f64::from_bits(bits)),
+        )
+        .unwrap();
+        assert_eq!(loaded, graph);
+        assert_eq!(loaded.edge_count(), 3);
+
+        let mut integral = WeightedGraph::directed();
+        integral.add_edge(1u16, 2u16, 30u32);
+        integral.add_edge(2, 1, 40);
+        let mut bytes = Vec::new();
+        integral.write_binary(&mut bytes).unwrap();
+        assert_eq!(
+            WeightedGraph::read_binary(bytes.as_slice()).unwrap(),
+            integral
+        );
+    }
+
+    #[test]
+    fn truncated_input_is_an_error() {
+        let mut bytes = Vec::new();
+        sample_graph(GraphType::Directed)
+            .write_binary(&mut bytes)
+            .unwrap();
+
+        for len in 0..bytes.len() {
+            match Graph::<u32>::read_binary(&bytes[..len]) {
+                Err(DecodeError::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof),
+                other => panic!("prefix of {len} bytes gave {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_rejected() {
+        let mut graph: Graph<u32> = Graph::directed();
+        graph.add_edge(1, 300);
+        let mut bytes = Vec::new();
+        graph.write_binary(&mut bytes).unwrap();
+
+        assert!(matches!(
+            Graph::<u8>::read_binary(bytes.as_slice()),
+            Err(DecodeError::Invalid("vertex id out of range"))
+        ));
+        assert!(matches!(
+            WeightedGraph::<u32, u32>::read_binary(bytes.as_slice()),
+            Err(DecodeError::Invalid("graph has no edge weights"))
+        ));
+        assert!(matches!(
+            Graph::<u32>::read_binary_with(bytes.as_slice(), |_| Some(0)),
+            Err(DecodeError::Invalid("duplicate vertex id"))
+        ));
+
+        // With a single self-loop the last word is the loop's target index
+        let mut self_loop: Graph<u32> = Graph::directed();
+        self_loop.add_edge(7, 7);
+        let mut corrupt = Vec::new();
+        self_loop.write_binary(&mut corrupt).unwrap();
+        let last = corrupt.len() - 8;
+        corrupt[last..].copy_from_slice(&9u64.to_le_bytes());
+        let err = Graph::<u32>::read_binary(corrupt.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid binary graph: edge target out of range"
+        );
+
+        bytes[0] = b'X';
+        assert!(matches!(
+            Graph::<u32>::read_binary(bytes.as_slice()),
+            Err(DecodeError::Invalid("not a binary graph"))
+        ));
+    }
+}
@@ -0,0 +1,189 @@
+//! Fluent builders for [`Graph`] and [`WeightedGraph`]
+
+use super::adjacency_list::{Graph, GraphType};
+use super::weighted_graph::{self, WeightedGraph};
+use std::hash::Hash;
+
+/// Collects vertices and edges, then builds a [`Graph`] of the chosen type
+///
+/// Graphs are directed unless [`GraphBuilder::undirected`] is called.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::graph::GraphBuilder;
+///
+/// let graph = GraphBuilder::new()
+///     .undirected()
+///     .edge(1, 2)
+///     .edge(2, 3)
+///     .vertex(4)
+///     .build();
+///
+/// assert_eq!(graph.vertex_count(), 4);
+/// assert!(graph.has_edge(&3, &2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GraphBuilder<T> {
+    graph_type: GraphType,
+    vertices: Vec<T>,
+    edges: Vec<(T, T)>,
+}
+
+impl<T> GraphBuilder<T>
+where
+    T: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self {
+            graph_type: GraphType::Directed,
+            vertices: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub fn directed(mut self) -> Self {
+        self.graph_type = GraphType::Directed;
+        self
+    }
+
+    pub fn undirected(mut self) -> Self {
+        self.graph_type = GraphType::Undirected;
+        self
+    }
+
+    pub fn vertex(mut self, vertex: T) -> Self {
+        self.vertices.push(vertex);
+        self
+    }
+
+    pub fn edge(mut self, from: T, to: T) -> Self {
+        self.edges.push((from, to));
+        self
+    }
+
+    pub fn build(self) -> Graph<T> {
+        let mut graph = Graph::with_vertex_capacity(self.vertices.len(), self.graph_type);
+        graph.add_vertices(self.vertices);
+        for (from, to) in self.edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+impl<T: Clone + Eq + Hash> Default for GraphBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects vertices and weighted edges, then builds a [`WeightedGraph`]
+///
+/// Graphs are directed unless [`WeightedGraphBuilder::undirected`] is called.
+/// A repeated edge keeps the first weight, as with [`WeightedGraph::add_edge`].
+#[derive(Debug, Clone)]
+pub struct WeightedGraphBuilder<T, W> {
+    graph_type: weighted_graph::GraphType,
+    vertices: Vec<T>,
+    edges: Vec<(T, T, W)>,
+}
+
+impl<T, W> WeightedGraphBuilder<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            graph_type: weighted_graph::GraphType::Directed,
+            vertices: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub fn directed(mut self) -> Self {
+        self.graph_type = weighted_graph::GraphType::Directed;
+        self
+    }
+
+    pub fn undirected(mut self) -> Self {
+        self.graph_type = weighted_graph::GraphType::Undirected;
+        self
+    }
+
+    pub fn vertex(mut self, vertex: T) -> Self {
+        self.vertices.push(vertex);
+        self
+    }
+
+    pub fn edge(mut self, from: T, to: T, weight: W) -> Self {
+        self.edges.push((from, to, weight));
+        self
+    }
+
+    pub fn build(self) -> WeightedGraph<T, W> {
+        let mut graph = WeightedGraph::with_vertex_capacity(self.vertices.len(), self.graph_type);
+        graph.add_vertices(self.vertices);
+        for (from, to, weight) in self.edges {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+}
+
+impl<T, W> Default for WeightedGraphBuilder<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fluent_graph_equals_imperative() {
+        let built = GraphBuilder::new()
+            .undirected()
+            .edge("a", "b")
+            .edge("b", "c")
+            .edge("c", "b")
+            .vertex("d")
+            .build();
+
+        let mut expected = Graph::undirected();
+        expected.add_vertex("d");
+        expected.add_edge("c", "b");
+        expected.add_edge("b", "a");
+        assert_eq!(built, expected);
+
+        let directed = GraphBuilder::new().edge("a", "b").edge("b", "c").build();
+        assert_ne!(directed, expected);
+        assert_eq!(directed.graph_type(), &GraphType::Directed);
+    }
+
+    #[test]
+    fn fluent_weighted_graph_equals_imperative() {
+        let built = WeightedGraphBuilder::new()
+            .directed()
+            .edge(1, 2, 4.5)
+            .edge(2, 3, 1.0)
+            .vertex(7)
+            .build();
+
+        let mut expected = WeightedGraph::directed();
+        expected.add_edge(2, 3, 1.0);
+        expected.add_edge(1, 2, 4.5);
+        expected.add_vertex(7);
+        assert_eq!(built, expected);
+
+        expected.remove_edge(&1, &2);
+        expected.add_edge(1, 2, 5.0);
+        assert_ne!(built, expected);
+    }
+}
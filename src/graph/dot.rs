@@ -0,0 +1,813 @@
+//! Graphviz DOT export and import for [`Graph`] and [`WeightedGraph`]
+
+use super::adjacency_list::{Graph, GraphType};
+use super::weighted_graph::{GraphType as WeightedGraphType, WeightedGraph};
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl DotParseError {
+    fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for DotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for DotParseError {}
+
+impl<T> Graph<T>
+where
+    T: Clone + Eq + Hash + fmt::Display,
+{
+    /// Renders the graph as a Graphviz `digraph` or `graph`
+    ///
+    /// Identifiers are always quoted and statements are sorted so the output
+    /// is stable across runs. Undirected edges are written once.
+    pub fn to_dot(&self) -> String {
+        let (keyword, op) = match self.graph_type() {
+            GraphType::Directed => ("digraph", "->"),
+            GraphType::Undirected => ("graph", "--"),
+        };
+
+        let mut statements = Vec::new();
+        let mut written = HashSet::new();
+        let mut connected = HashSet::new();
+
+        for (from, to) in self.edges() {
+            if *self.graph_type() == GraphType::Undirected && written.contains(&(to, from)) {
+                continue;
+            }
+            written.insert((from, to));
+            connected.insert(from);
+            connected.insert(to);
+            statements.push(format!("{} {op} {};", quote(from), quote(to)));
+        }
+
+        for vertex in self.vertices() {
+            if !connected.contains(vertex) {
+                statements.push(format!("{};", quote(vertex)));
+            }
+        }
+
+        statements.sort();
+
+        let mut dot = format!("{keyword} {{\n");
+        for statement in statements {
+            dot.push_str("    ");
+            dot.push_str(&statement);
+            dot.push('\n');
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl Graph<String> {
+    /// Parses a subset of the Graphviz DOT language
+    ///
+    /// Supports `digraph`/`graph` headers, node statements and chained edge
+    /// statements using `->` or `--`. Attribute lists in brackets are skipped,
+    /// as are `node`, `edge` and `graph` default attribute statements and
+    /// graph attributes such as `rankdir=LR`.
+    pub fn from_dot(input: &str) -> Result<Graph<String>, DotParseError> {
+        let document = parse(input)?;
+        let mut graph = Graph::new(document.graph_type);
+
+        graph.add_vertices(document.nodes);
+        for edge in document.edges {
+            graph.add_edge(edge.from, edge.to);
+        }
+
+        Ok(graph)
+    }
+}
+
+impl<T, W> WeightedGraph<T, W>
+where
+    T: Clone + Eq + Hash + fmt::Display,
+    W: Clone + fmt::Display,
+{
+    /// Renders the graph as a Graphviz `digraph` or `graph` with each edge
+    /// weight in a `weight` attribute
+    ///
+    /// Identifiers are always quoted and statements are sorted so the output
+    /// is stable across runs. Undirected edges are written once.
+    pub fn to_dot(&self) -> String {
+        let (keyword, op) = match self.graph_type() {
+            WeightedGraphType::Directed => ("digraph", "->"),
+            WeightedGraphType::Undirected => ("graph", "--"),
+        };
+
+        let mut statements = Vec::new();
+        let mut written = HashSet::new();
+        let mut connected = HashSet::new();
+
+        for from in self.vertices() {
+            for edge in self.neighbors(from).into_iter().flatten() {
+                let to = &edge.to;
+                if *self.graph_type() == WeightedGraphType::Undirected
+                    && written.contains(&(to, from))
+                {
+                    continue;
+                }
+                written.insert((from, to));
+                connected.insert(from);
+                connected.insert(to);
+                statements.push(format!(
+                    "{} {op} {} [weight={}];",
+                    quote(from),
+                    quote(to),
+                    quote(&edge.weight)
+                ));
+            }
+        }
+
+        for vertex in self.vertices() {
+            if !connected.contains(vertex) {
+                statements.push(format!("{};", quote(vertex)));
+            }
+        }
+
+        statements.sort();
+
+        let mut dot = format!("{keyword} {{\n");
+        for statement in statements {
+            dot.push_str("    ");
+            dot.push_str(&statement);
+            dot.push('\n');
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<W> WeightedGraph<String, W>
+where
+    W: Clone + FromStr,
+{
+    /// Parses the same DOT subset as [`Graph::from_dot`], reading each edge
+    /// weight from its `weight` attribute, or from `label` when `weight` is
+    /// absent
+    ///
+    /// Attributes on an edge chain apply to every edge of the chain. Unlike
+    /// the unweighted parser, edge attribute lists are read rather than
+    /// skipped, so they must be `key=value` pairs whose values are plain
+    /// identifiers, numbers or quoted strings; HTML labels such as
+    /// `label=<b>x</b>` are rejected. Node attribute lists and default
+    /// attribute statements are still skipped, so `edge [weight=1]` does not
+    /// give edges a weight.
+    /// An edge without a weight, or whose weight does not parse as `W`, is an
+    /// error.
+    pub fn from_dot(input: &str) -> Result<WeightedGraph<String, W>, DotParseError> {
+        let document = parse(input)?;
+        let graph_type = match document.graph_type {
+            GraphType::Directed => WeightedGraphType::Directed,
+            GraphType::Undirected => WeightedGraphType::Undirected,
+        };
+        let mut graph = WeightedGraph::new(graph_type);
+
+        graph.add_vertices(document.nodes);
+        for edge in document.edges {
+            let attributes = match &edge.attributes {
+                Some(list) => parse_attributes(list)?,
+                None => Vec::new(),
+            };
+            let attribute = ["weight", "label"].iter().find_map(|key| {
+                attributes
+                    .iter()
+                    .rev()
+                    .find(|attribute| attribute.key == *key)
+            });
+            let Some(attribute) = attribute else {
+                let (line, column) = edge.position;
+                return Err(DotParseError::new(
+                    line,
+                    column,
+                    format!("edge `{}` to `{}` has no weight", edge.from, edge.to),
+                ));
+            };
+            let weight = attribute.value.parse().map_err(|_| {
+                let (line, column) = attribute.position;
+                DotParseError::new(
+                    line,
+                    column,
+                    format!("invalid weight `{}`", attribute.value),
+                )
+            })?;
+            graph.add_edge(edge.from, edge.to, weight);
+        }
+
+        Ok(graph)
+    }
+}
+
+/// Statements of a parsed DOT graph, before they are applied to a graph type
+struct Document {
+    graph_type: GraphType,
+    nodes: Vec<String>,
+    edges: Vec<EdgeStatement>,
+}
+
+struct EdgeStatement {
+    from: String,
+    to: String,
+    attributes: Option<AttributeList>,
+    position: Position,
+}
+
+/// The raw text between `[` and `]`, kept unparsed so that only the weighted
+/// parser has to understand it
+#[derive(Debug, Clone, PartialEq)]
+struct AttributeList {
+    text: String,
+    start: Position,
+}
+
+struct Attribute {
+    key: String,
+    value: String,
+    position: Position,
+}
+
+fn parse(input: &str) -> Result<Document, DotParseError> {
+    let tokens = tokenize(input)?;
+    // Running out of tokens is reported where the input stopped making sense
+    let (end_line, end_column) = tokens.last().map_or((1, 1), |&(_, position)| position);
+    let mut tokens = tokens.into_iter().peekable();
+
+    let (graph_type, expected_op) = match tokens.next() {
+        Some((Token::Keyword("digraph"), _)) => (GraphType::Directed, "->"),
+        Some((Token::Keyword("graph"), _)) => (GraphType::Undirected, "--"),
+        Some((_, (line, column))) => {
+            return Err(DotParseError::new(
+                line,
+                column,
+                "expected `digraph` or `graph`",
+            ))
+        }
+        None => return Err(DotParseError::new(1, 1, "empty input")),
+    };
+
+    if let Some((Token::Id(_), _)) = tokens.peek() {
+        tokens.next();
+    }
+    match tokens.next() {
+        Some((Token::OpenBrace, _)) => {}
+        Some((_, (line, column))) => return Err(DotParseError::new(line, column, "expected `{`")),
+        None => return Err(DotParseError::new(end_line, end_column, "expected `{`")),
+    }
+
+    let mut document = Document {
+        graph_type,
+        nodes: Vec::new(),
+        edges: Vec::new(),
+    };
+
+    loop {
+        let (token, (line, column)) = match tokens.next() {
+            Some(next) => next,
+            None => {
+                return Err(DotParseError::new(
+                    end_line,
+                    end_column,
+                    "missing closing `}`",
+                ))
+            }
+        };
+
+        let mut current = match token {
+            Token::CloseBrace => break,
+            Token::Semicolon => continue,
+            Token::Id(id) => id,
+            // `node [...]`, `edge [...]` and `graph [...]` only set defaults
+            Token::Keyword("node" | "edge" | "graph") => match tokens.next() {
+                Some((Token::Attributes(_), _)) => continue,
+                Some((_, (line, column))) => {
+                    return Err(DotParseError::new(line, column, "expected `[`"))
+                }
+                None => {
+                    return Err(DotParseError::new(
+                        end_line,
+                        end_column,
+                        "missing closing `}`",
+                    ))
+                }
+            },
+            Token::Keyword(keyword) => {
+                return Err(DotParseError::new(
+                    line,
+                    column,
+                    format!("`{keyword}` is not supported here"),
+                ))
+            }
+            _ => return Err(DotParseError::new(line, column, "expected identifier")),
+        };
+
+        // A graph attribute such as `rankdir=LR` names no vertex
+        if tokens
+            .next_if(|(token, _)| *token == Token::Equals)
+            .is_some()
+        {
+            match tokens.next() {
+                Some((Token::Id(_), _)) => continue,
+                Some((_, (line, column))) => {
+                    return Err(DotParseError::new(line, column, "expected attribute value"))
+                }
+                None => {
+                    return Err(DotParseError::new(
+                        end_line,
+                        end_column,
+                        "expected attribute value",
+                    ))
+                }
+            }
+        }
+        document.nodes.push(current.clone());
+
+        let chain_start = document.edges.len();
+        while let Some(&(Token::Edge(op), (line, column))) = tokens.peek() {
+            if op != expected_op {
+                return Err(DotParseError::new(
+                    line,
+                    column,
+                    format!("`{op}` is not allowed here, expected `{expected_op}`"),
+                ));
+            }
+            tokens.next();
+
+            match tokens.next() {
+                Some((Token::Id(next), _)) => {
+                    document.edges.push(EdgeStatement {
+                        from: current,
+                        to: next.clone(),
+                        attributes: None,
+                        position: (line, column),
+                    });
+                    current = next;
+                }
+                Some((_, (line, column))) => {
+                    return Err(DotParseError::new(line, column, "expected identifier"))
+                }
+                None => return Err(DotParseError::new(line, column, "unterminated edge")),
+            }
+        }
+
+        if let Some((Token::Attributes(list), _)) =
+            tokens.next_if(|(token, _)| matches!(token, Token::Attributes(_)))
+        {
+            for edge in &mut document.edges[chain_start..] {
+                edge.attributes = Some(list.clone());
+            }
+        }
+    }
+
+    if let Some((_, (line, column))) = tokens.next() {
+        return Err(DotParseError::new(
+            line,
+            column,
+            "unexpected input after `}`",
+        ));
+    }
+
+    Ok(document)
+}
+
+/// Parses the `key=value` pairs of an attribute list
+fn parse_attributes(list: &AttributeList) -> Result<Vec<Attribute>, DotParseError> {
+    let mut tokens = tokenize_from(&list.text, list.start)?.into_iter();
+    let mut attributes = Vec::new();
+    let end =
+        |what: &str| DotParseError::new(list.start.0, list.start.1, format!("expected {what}"));
+
+    loop {
+        let key = match tokens.next() {
+            None => return Ok(attributes),
+            Some((Token::Semicolon, _)) => continue,
+            Some((Token::Id(key), _)) => key,
+            Some((_, (line, column))) => {
+                return Err(DotParseError::new(line, column, "expected attribute name"))
+            }
+        };
+
+        match tokens.next() {
+            Some((Token::Equals, _)) => {}
+            Some((_, (line, column))) => {
+                return Err(DotParseError::new(line, column, "expected `=`"))
+            }
+            None => return Err(end("`=`")),
+        }
+
+        match tokens.next() {
+            Some((Token::Id(value), position)) => attributes.push(Attribute {
+                key,
+                value,
+                position,
+            }),
+            Some((_, (line, column))) => {
+                return Err(DotParseError::new(line, column, "expected attribute value"))
+            }
+            None => return Err(end("attribute value")),
+        }
+    }
+}
+
+fn quote<T: fmt::Display>(value: &T) -> String {
+    format!(
+        "\"{}\"",
+        value.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Id(String),
+    /// An unquoted DOT keyword, which cannot name a vertex
+    Keyword(&'static str),
+    Edge(&'static str),
+    OpenBrace,
+    CloseBrace,
+    Attributes(AttributeList),
+    Equals,
+    Semicolon,
+}
+
+type Position = (usize, usize);
+
+/// DOT keywords are case-insensitive and only reserved when unquoted
+const KEYWORDS: [&str; 6] = ["digraph", "edge", "graph", "node", "strict", "subgraph"];
+
+fn tokenize(input: &str) -> Result<Vec<(Token, Position)>, DotParseError> {
+    tokenize_from(input, (1, 1))
+}
+
+/// Tokenizes `input` as if it began at `start` in the original document
+fn tokenize_from(input: &str, start: Position) -> Result<Vec<(Token, Position)>, DotParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    let (mut line, mut column) = start;
+
+    while let Some(&c) = chars.peek() {
+        let position = (line, column);
+        chars.next();
+        if c == '\n' {
+            line += 1;
+            column = 1;
+            continue;
+        }
+        column += 1;
+
+        match c {
+            c if c.is_whitespace() || c == ',' => {}
+            '{' => tokens.push((Token::OpenBrace, position)),
+            '}' => tokens.push((Token::CloseBrace, position)),
+            ';' => tokens.push((Token::Semicolon, position)),
+            '[' => {
+                let start = (line, column);
+                let mut text = String::new();
+                let mut quoted = false;
+                loop {
+                    let next = chars.next();
+                    match next {
+                        Some(']') if !quoted => {
+                            column += 1;
+                            break;
+                        }
+                        Some('\n') => {
+                            line += 1;
+                            column = 1;
+                        }
+                        Some('"') => {
+                            quoted = !quoted;
+                            column += 1;
+                        }
+                        Some('\\') if quoted => {
+                            column += 1;
+                            if let Some(escaped) = chars.next() {
+                                text.push('\\');
+                                text.push(escaped);
+                                column += 1;
+                                continue;
+                            }
+                        }
+                        Some(_) => column += 1,
+                        None => {
+                            return Err(DotParseError::new(position.0, position.1, "unclosed `[`"))
+                        }
+                    }
+                    text.extend(next);
+                }
+                tokens.push((Token::Attributes(AttributeList { text, start }), position));
+            }
+            '=' => tokens.push((Token::Equals, position)),
+            '-' if matches!(chars.peek(), Some('>') | Some('-')) => {
+                let op = if chars.next() == Some('>') {
+                    "->"
+                } else {
+                    "--"
+                };
+                column += 1;
+                tokens.push((Token::Edge(op), position));
+            }
+            '"' => {
+                let mut id = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(escaped) => {
+                                id.push(escaped);
+                                column += 2;
+                            }
+                            None => break,
+                        },
+                        Some('\n') => {
+                            return Err(DotParseError::new(line, column, "unclosed string"))
+                        }
+                        Some(other) => {
+                            id.push(other);
+                            column += 1;
+                        }
+                        None => {
+                            return Err(DotParseError::new(
+                                position.0,
+                                position.1,
+                                "unclosed string",
+                            ))
+                        }
+                    }
+                }
+                column += 1;
+                tokens.push((Token::Id(id), position));
+            }
+            c if c.is_alphanumeric()
+                || c == '_'
+                || c == '.'
+                || (c == '-' && chars.peek().is_some_and(|next| next.is_ascii_digit())) =>
+            {
+                let mut id = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_alphanumeric() || next == '_' || next == '.') {
+                        break;
+                    }
+                    id.push(next);
+                    chars.next();
+                    column += 1;
+                }
+                let token = match KEYWORDS
+                    .iter()
+                    .find(|keyword| keyword.eq_ignore_ascii_case(&id))
+                {
+                    Some(keyword) => Token::Keyword(keyword),
+                    None => Token::Id(id),
+                };
+                tokens.push((token, position));
+            }
+            other => {
+                return Err(DotParseError::new(
+                    position.0,
+                    position.1,
+                    format!("unexpected character `{other}`"),
+                ))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge_set(graph: &Graph<String>) -> HashSet<(String, String)> {
+        graph
+            .edges()
+            .map(|(from, to)| (from.clone(), to.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn to_dot_round_trip() {
+        let mut directed = Graph::directed();
+        directed.add_edge(1, 2);
+        directed.add_edge(2, 3);
+        directed.add_edge(3, 3);
+        directed.add_vertex(4);
+
+        let dot = directed.to_dot();
+        assert!(dot.starts_with("digraph {"));
+        let parsed = Graph::from_dot(&dot).unwrap();
+        let expected = directed.relabel(|v| v.to_string());
+
+        assert_eq!(parsed.graph_type(), &GraphType::Directed);
+        assert_eq!(parsed.vertex_count(), 4);
+        assert_eq!(parsed.edge_count(), expected.edge_count());
+        assert_eq!(edge_set(&parsed), edge_set(&expected));
+
+        let mut undirected = Graph::undirected();
+        undirected.add_edge("a b".to_string(), "\"c\"".to_string());
+        undirected.add_edge("c".to_string(), "a b".to_string());
+
+        let parsed = Graph::from_dot(&undirected.to_dot()).unwrap();
+        assert_eq!(parsed.graph_type(), &GraphType::Undirected);
+        assert_eq!(parsed.edge_count(), 2);
+        assert_eq!(edge_set(&parsed), edge_set(&undirected));
+    }
+
+    #[test]
+    fn from_dot_subset() {
+        let input = r#"
+            digraph routes {
+                a -> b -> c [color=red];
+                d [shape=box]
+                "e" -> a;
+            }
+        "#;
+
+        let graph = Graph::from_dot(input).unwrap();
+        assert_eq!(graph.vertex_count(), 5);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(graph.has_edge(&"b".to_string(), &"c".to_string()));
+        assert!(graph.has_edge(&"e".to_string(), &"a".to_string()));
+        assert!(graph.has_vertex(&"d".to_string()));
+    }
+
+    #[test]
+    fn from_dot_skips_any_attribute_list() {
+        let input = r#"
+            digraph {
+                a -> b [label=<b>x</b>];
+                c [pos=1:2, tooltip="a ] inside quotes"];
+            }
+        "#;
+
+        let graph = Graph::from_dot(input).unwrap();
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.edge_count(), 1);
+        assert!(graph.has_edge(&"a".to_string(), &"b".to_string()));
+
+        // The weighted parser reads edge attributes, so it only accepts
+        // `key=value` pairs there
+        let err = WeightedGraph::<String, i64>::from_dot(
+            "digraph {\n  a -> b [weight=2, label=<b>x</b>];\n  c [pos=1:2];\n}",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 27));
+
+        let weighted: WeightedGraph<String, i64> =
+            WeightedGraph::from_dot("digraph { a -> b [weight=2]; c [pos=1:2] }").unwrap();
+        assert_eq!(weighted.vertex_count(), 3);
+        assert_eq!(weighted.get_edge_weight(&"a".into(), &"b".into()), Some(&2));
+    }
+
+    #[test]
+    fn from_dot_malformed() {
+        assert!(Graph::from_dot("").is_err());
+        assert!(Graph::from_dot("tree { a -> b }").is_err());
+        assert!(Graph::from_dot("digraph { a -> }").is_err());
+        assert!(Graph::from_dot("digraph { a -> b").is_err());
+        assert!(Graph::from_dot("digraph { a [label=x }").is_err());
+
+        let err = Graph::from_dot("graph {\n  a -- b;\n  b -> c;\n}").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+    }
+
+    #[test]
+    fn from_dot_skips_default_attribute_statements() {
+        let input = r#"
+            digraph {
+                node [shape=box];
+                EDGE [color=gray]
+                graph [fontsize=10];
+                a -> b;
+                "node" -> "graph";
+            }
+        "#;
+
+        let graph = Graph::from_dot(input).unwrap();
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.has_edge(&"node".to_string(), &"graph".to_string()));
+        assert!(!graph.has_vertex(&"edge".to_string()));
+
+        let err = Graph::from_dot(
+            "digraph {
+  node -> a
+}",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+        let err = Graph::from_dot("digraph { subgraph { a } }").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+    }
+
+    #[test]
+    fn from_dot_skips_graph_attributes() {
+        let input = r#"
+            graph {
+                rankdir=LR;
+                label = "Routes"
+                a -- b
+            }
+        "#;
+
+        let graph = Graph::from_dot(input).unwrap();
+        assert_eq!(graph.vertex_count(), 2);
+        assert!(!graph.has_vertex(&"rankdir".to_string()));
+        assert!(graph.has_edge(&"b".to_string(), &"a".to_string()));
+
+        let weighted: WeightedGraph<String, i64> =
+            WeightedGraph::from_dot("digraph { rankdir=LR; a -> b [weight=4] }").unwrap();
+        assert_eq!(weighted.vertex_count(), 2);
+
+        let err = Graph::from_dot("digraph { rankdir = ; }").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 21));
+    }
+
+    #[test]
+    fn from_dot_reports_end_of_input() {
+        let err: DotParseError = Graph::from_dot(
+            "digraph
+  routes",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "2:3: expected `{`");
+
+        let err = Graph::from_dot(
+            "digraph {
+  a -> b;
+",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 9));
+        assert_eq!(err.message, "missing closing `}`");
+
+        let err = Graph::from_dot(
+            "graph {
+  node",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn weighted_to_dot_round_trip() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge(1, 2, -4i64);
+        graph.add_edge(2, 3, 7);
+        graph.add_vertex(9);
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("\"1\" -> \"2\" [weight=\"-4\"];"));
+        let parsed: WeightedGraph<String, i64> = WeightedGraph::from_dot(&dot).unwrap();
+        assert_eq!(parsed, graph.relabel(|v| v.to_string()));
+    }
+
+    #[test]
+    fn weighted_from_dot_attributes() {
+        let input = r#"
+            graph {
+                a -- b [weight=3];
+                b -- c -- d [color=red, label="2.5"];
+                d -- a [label=1, weight=-0.5]
+            }
+        "#;
+
+        let graph: WeightedGraph<String, f64> = WeightedGraph::from_dot(input).unwrap();
+        assert_eq!(graph.graph_type(), &WeightedGraphType::Undirected);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.get_edge_weight(&"b".into(), &"a".into()), Some(&3.0));
+        assert_eq!(graph.get_edge_weight(&"c".into(), &"d".into()), Some(&2.5));
+        assert_eq!(graph.get_edge_weight(&"a".into(), &"d".into()), Some(&-0.5));
+
+        let integers: Result<WeightedGraph<String, i64>, _> = WeightedGraph::from_dot(input);
+        let err = integers.unwrap_err();
+        assert_eq!((err.line, err.column), (4, 47));
+        assert!(err.message.contains("2.5"));
+
+        let err = WeightedGraph::<String, i64>::from_dot("digraph {\n  a -> b;\n}").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let err =
+            WeightedGraph::<String, i64>::from_dot("digraph { a -> b [weight 2] }").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 26));
+    }
+}
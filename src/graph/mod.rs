@@ -1,7 +1,13 @@
 pub mod adjacency_list;
 pub mod algorithms;
+pub mod binary;
+pub mod builder;
+pub mod dot;
 pub mod weighted_graph;
 
 pub use adjacency_list::Graph;
 pub use algorithms::*;
+pub use binary::DecodeError;
+pub use builder::{GraphBuilder, WeightedGraphBuilder};
+pub use dot::DotParseError;
 pub use weighted_graph::WeightedGraph;
@@ -1,7 +1,10 @@
+use crate::graph::adjacency_list::{self, Graph};
+use crate::utils::display::{self, DisplayLimited, DISPLAY_LIMIT};
 use crate::utils::{Clear, Size};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::hash::Hash;
+use std::ops::{Add, Sub};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum GraphType {
@@ -21,6 +24,11 @@ impl<T, W> Edge<T, W> {
     }
 }
 
+/// A graph with weighted edges stored as adjacency lists
+///
+/// Self-loops follow the same conventions as in [`Graph`]: an
+/// undirected self-loop is stored once, counts as one edge in `edge_count`,
+/// and contributes 2 to the degree of its vertex.
 pub struct WeightedGraph<T, W> {
     adjacency_list: HashMap<T, Vec<Edge<T, W>>>,
     graph_type: GraphType,
@@ -40,6 +48,14 @@ where
         }
     }
 
+    pub fn with_vertex_capacity(capacity: usize, graph_type: GraphType) -> Self {
+        Self {
+            adjacency_list: HashMap::with_capacity(capacity),
+            graph_type,
+            edge_count: 0,
+        }
+    }
+
     pub fn directed() -> Self {
         Self::new(GraphType::Directed)
     }
@@ -59,6 +75,15 @@ where
         }
     }
 
+    /// Adds each vertex that is not already present, returning how many were new
+    pub fn add_vertices<I: IntoIterator<Item = T>>(&mut self, vertices: I) -> usize {
+        vertices
+            .into_iter()
+            .map(|vertex| self.add_vertex(vertex))
+            .filter(|&added| added)
+            .count()
+    }
+
     pub fn add_edge(&mut self, from: T, to: T, weight: W) -> bool {
         self.add_vertex(from.clone());
         self.add_vertex(to.clone());
@@ -109,6 +134,20 @@ where
         self.adjacency_list.get(vertex)
     }
 
+    /// Returns the outgoing edges for direct manipulation
+    ///
+    /// Changes made through this bypass the graph's bookkeeping: mirrors of
+    /// undirected edges are not updated and `edge_count` is not adjusted.
+    pub fn neighbors_mut(&mut self, vertex: &T) -> Option<&mut Vec<Edge<T, W>>> {
+        self.adjacency_list.get_mut(vertex)
+    }
+
+    /// Returns an owned copy of the outgoing edges, so the graph can be mutated
+    /// while iterating them. Missing vertices have no edges.
+    pub fn neighbors_cloned(&self, vertex: &T) -> Vec<Edge<T, W>> {
+        self.adjacency_list.get(vertex).cloned().unwrap_or_default()
+    }
+
     pub fn vertices(&self) -> impl Iterator<Item = &T> {
         self.adjacency_list.keys()
     }
@@ -125,6 +164,89 @@ where
         &self.graph_type
     }
 
+    pub fn density(&self) -> f64 {
+        let vertices = self.vertex_count();
+        if vertices < 2 {
+            return 0.0;
+        }
+
+        let max_edges = match self.graph_type {
+            GraphType::Directed => vertices * (vertices - 1),
+            GraphType::Undirected => vertices * (vertices - 1) / 2,
+        };
+        self.edge_count as f64 / max_edges as f64
+    }
+
+    /// Returns the number of edge endpoints at `vertex`, with the same
+    /// conventions as [`Graph::degree`](crate::Graph::degree)
+    ///
+    /// For directed graphs this is the out-degree. In undirected graphs a
+    /// self-loop touches the vertex twice, so it contributes 2.
+    pub fn degree(&self, vertex: &T) -> Option<usize> {
+        self.adjacency_list.get(vertex).map(|edges| {
+            let self_loop = self.graph_type == GraphType::Undirected
+                && edges.iter().any(|edge| edge.to == *vertex);
+            edges.len() + usize::from(self_loop)
+        })
+    }
+
+    /// Returns the number of edges ending at `vertex`; equal to
+    /// [`WeightedGraph::degree`] for undirected graphs
+    pub fn in_degree(&self, vertex: &T) -> Option<usize> {
+        if !self.has_vertex(vertex) {
+            return None;
+        }
+        if self.graph_type == GraphType::Undirected {
+            return self.degree(vertex);
+        }
+
+        let count = self
+            .adjacency_list
+            .values()
+            .map(|edges| edges.iter().filter(|edge| edge.to == *vertex).count())
+            .sum();
+
+        Some(count)
+    }
+
+    pub fn out_degree(&self, vertex: &T) -> Option<usize> {
+        self.degree(vertex)
+    }
+
+    /// Returns true if every vertex can reach every other one when edge
+    /// directions and weights are ignored
+    ///
+    /// Directed graphs are therefore checked for weak connectivity. The empty
+    /// graph is considered connected.
+    pub fn is_connected(&self) -> bool {
+        let Some(start) = self.adjacency_list.keys().next() else {
+            return true;
+        };
+
+        let mut incoming: HashMap<&T, Vec<&T>> = HashMap::new();
+        if self.graph_type == GraphType::Directed {
+            for (from, edges) in &self.adjacency_list {
+                for edge in edges {
+                    incoming.entry(&edge.to).or_default().push(from);
+                }
+            }
+        }
+
+        let mut visited = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(vertex) = stack.pop() {
+            let forward = self.adjacency_list[vertex].iter().map(|edge| &edge.to);
+            let backward = incoming.get(vertex).into_iter().flatten().copied();
+            for neighbor in forward.chain(backward) {
+                if visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        visited.len() == self.adjacency_list.len()
+    }
+
     pub fn remove_vertex(&mut self, vertex: &T) -> bool {
         if !self.adjacency_list.contains_key(vertex) {
             return false;
@@ -133,10 +255,14 @@ where
         let edges_from_vertex = self.adjacency_list[vertex].len();
         self.edge_count -= edges_from_vertex;
 
-        for neighbors in self.adjacency_list.values_mut() {
+        for (from, neighbors) in self.adjacency_list.iter_mut() {
             let initial_len = neighbors.len();
             neighbors.retain(|edge| edge.to != *vertex);
-            self.edge_count -= initial_len - neighbors.len();
+            // Undirected mirrors and directed self-loops were already counted
+            // among the outgoing edges
+            if self.graph_type == GraphType::Directed && from != vertex {
+                self.edge_count -= initial_len - neighbors.len();
+            }
         }
 
         self.adjacency_list.remove(vertex);
@@ -164,6 +290,494 @@ where
 
         edge_removed
     }
+
+    /// Contracts the edge between `u` and `v` into a single vertex `merged_label`
+    ///
+    /// Edges of both endpoints are redirected to the merged vertex, and edges
+    /// between `u` and `v` disappear instead of becoming self-loops. The graph
+    /// keeps at most one edge per pair, so when `u` and `v` both have an edge to
+    /// the same vertex the two become one edge weighted
+    /// `combine(existing, incoming)`. Returns `false` and leaves the graph
+    /// unchanged if there is no edge `u -> v`.
+    pub fn contract_edge<F>(&mut self, u: &T, v: &T, merged_label: T, combine: F) -> bool
+    where
+        F: FnMut(&W, &W) -> W,
+    {
+        if !self.has_edge(u, v) {
+            return false;
+        }
+        self.merge_vertices(&[u.clone(), v.clone()], merged_label, combine)
+    }
+
+    /// Replaces every vertex of `group` with a single vertex `label`
+    ///
+    /// Edges leaving or entering the group are redirected to `label` and edges
+    /// inside the group are dropped. Redirected edges that land on the same
+    /// pair become one edge weighted `combine(existing, incoming)`. If `label` is already a
+    /// vertex outside `group` it is merged in as well. Returns `false` if no
+    /// vertex of `group` is in the graph.
+    pub fn merge_vertices<F>(&mut self, group: &[T], label: T, mut combine: F) -> bool
+    where
+        F: FnMut(&W, &W) -> W,
+    {
+        let mut members: HashSet<T> = group
+            .iter()
+            .filter(|vertex| self.has_vertex(vertex))
+            .cloned()
+            .collect();
+        if members.is_empty() {
+            return false;
+        }
+        if self.has_vertex(&label) {
+            members.insert(label.clone());
+        }
+
+        let outgoing: Vec<(T, W)> = members
+            .iter()
+            .flat_map(|vertex| &self.adjacency_list[vertex])
+            .filter(|edge| !members.contains(&edge.to))
+            .map(|edge| (edge.to.clone(), edge.weight.clone()))
+            .collect();
+        let mut incoming: Vec<(T, W)> = Vec::new();
+        if self.graph_type == GraphType::Directed {
+            for (from, edges) in &self.adjacency_list {
+                if members.contains(from) {
+                    continue;
+                }
+                incoming.extend(
+                    edges
+                        .iter()
+                        .filter(|edge| members.contains(&edge.to))
+                        .map(|edge| (from.clone(), edge.weight.clone())),
+                );
+            }
+        }
+
+        for vertex in &members {
+            self.remove_vertex(vertex);
+        }
+        self.add_vertex(label.clone());
+
+        let redirected = outgoing
+            .into_iter()
+            .map(|(to, weight)| (label.clone(), to, weight))
+            .chain(
+                incoming
+                    .into_iter()
+                    .map(|(from, weight)| (from, label.clone(), weight)),
+            );
+        for (from, to, weight) in redirected {
+            match self.get_edge_weight(&from, &to) {
+                Some(existing) => {
+                    let weight = combine(existing, &weight);
+                    self.set_edge_weight(&from, &to, weight);
+                }
+                None => {
+                    self.add_edge(from, to, weight);
+                }
+            }
+        }
+        true
+    }
+
+    /// Replaces the edge `from -> to` with `to -> from` of the same weight,
+    /// keeping `edge_count`
+    ///
+    /// Returns `false` and leaves the graph unchanged if the edge is absent or
+    /// its reverse already exists. On an undirected graph this only reports
+    /// whether the edge exists.
+    pub fn reverse_edge(&mut self, from: &T, to: &T) -> bool {
+        let Some(weight) = self.get_edge_weight(from, to).cloned() else {
+            return false;
+        };
+        if self.graph_type == GraphType::Undirected || from == to {
+            return true;
+        }
+        if self.has_edge(to, from) {
+            return false;
+        }
+
+        self.remove_edge(from, to);
+        self.add_edge(to.clone(), from.clone(), weight)
+    }
+
+    pub fn clear_edges(&mut self) {
+        for neighbors in self.adjacency_list.values_mut() {
+            neighbors.clear();
+        }
+        self.edge_count = 0;
+    }
+
+    /// Adds every vertex and edge of `other`, keeping the existing weight when
+    /// an edge is present in both graphs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graphs have different [`GraphType`]s.
+    pub fn union_with(&mut self, other: &WeightedGraph<T, W>) {
+        self.merge_with(other, |existing, _| existing.clone());
+    }
+
+    /// Adds every vertex and edge of `other`, resolving edges present in both
+    /// graphs with `combine(existing, incoming)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graphs have different [`GraphType`]s.
+    pub fn merge_with<F>(&mut self, other: &WeightedGraph<T, W>, mut combine: F)
+    where
+        F: FnMut(&W, &W) -> W,
+    {
+        assert_eq!(
+            self.graph_type, other.graph_type,
+            "cannot merge graphs of different types"
+        );
+
+        let mut seen = HashSet::new();
+        for (vertex, edges) in &other.adjacency_list {
+            self.add_vertex(vertex.clone());
+            for edge in edges {
+                if self.graph_type == GraphType::Undirected && seen.contains(&(&edge.to, vertex)) {
+                    continue;
+                }
+                seen.insert((vertex, &edge.to));
+
+                match self.get_edge_weight(vertex, &edge.to) {
+                    Some(existing) => {
+                        let weight = combine(existing, &edge.weight);
+                        self.set_edge_weight(vertex, &edge.to, weight);
+                    }
+                    None => {
+                        self.add_edge(vertex.clone(), edge.to.clone(), edge.weight.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Builds a graph containing both inputs side by side.
+    ///
+    /// Every vertex is relabeled with `tag(side, vertex)`, where `side` is 0 for
+    /// `a` and 1 for `b`; `tag` must keep the two sides apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graphs have different [`GraphType`]s.
+    pub fn disjoint_union<U, F>(
+        a: &WeightedGraph<T, W>,
+        b: &WeightedGraph<T, W>,
+        mut tag: F,
+    ) -> WeightedGraph<U, W>
+    where
+        U: Clone + Eq + Hash,
+        F: FnMut(usize, &T) -> U,
+    {
+        let mut union = a.relabel(|vertex| tag(0, vertex));
+        union.union_with(&b.relabel(|vertex| tag(1, vertex)));
+        union
+    }
+
+    /// Collapses parallel edges to the same destination into the lightest one
+    ///
+    /// For undirected graphs both directions of an edge end up with the
+    /// smaller of their weights. `edge_count` is recomputed afterwards.
+    pub fn keep_min_parallel_edges(&mut self)
+    where
+        W: Ord,
+    {
+        for edges in self.adjacency_list.values_mut() {
+            let mut kept: Vec<Edge<T, W>> = Vec::with_capacity(edges.len());
+            let mut positions: HashMap<T, usize> = HashMap::new();
+            for edge in edges.drain(..) {
+                match positions.get(&edge.to) {
+                    Some(&position) => {
+                        if edge.weight < kept[position].weight {
+                            kept[position].weight = edge.weight;
+                        }
+                    }
+                    None => {
+                        positions.insert(edge.to.clone(), kept.len());
+                        kept.push(edge);
+                    }
+                }
+            }
+            *edges = kept;
+        }
+
+        let stored: usize = self.adjacency_list.values().map(Vec::len).sum();
+        self.edge_count = match self.graph_type {
+            GraphType::Directed => stored,
+            GraphType::Undirected => {
+                let mut lighter_mirrors = Vec::new();
+                for (from, to, weight) in self.logical_edges() {
+                    if let Some(mirror) = self.get_edge_weight(to, from) {
+                        if mirror < weight {
+                            lighter_mirrors.push((from.clone(), to.clone(), mirror.clone()));
+                        } else if weight < mirror {
+                            lighter_mirrors.push((from.clone(), to.clone(), weight.clone()));
+                        }
+                    }
+                }
+                for (from, to, weight) in lighter_mirrors {
+                    self.set_edge_weight(&from, &to, weight);
+                }
+
+                let self_loops = self
+                    .adjacency_list
+                    .iter()
+                    .filter(|(vertex, edges)| edges.iter().any(|edge| edge.to == **vertex))
+                    .count();
+                (stored + self_loops) / 2
+            }
+        };
+    }
+
+    fn set_edge_weight(&mut self, from: &T, to: &T, weight: W) {
+        if self.graph_type == GraphType::Undirected {
+            if let Some(edge) = self
+                .adjacency_list
+                .get_mut(to)
+                .and_then(|edges| edges.iter_mut().find(|edge| edge.to == *from))
+            {
+                edge.weight = weight.clone();
+            }
+        }
+        if let Some(edge) = self
+            .adjacency_list
+            .get_mut(from)
+            .and_then(|edges| edges.iter_mut().find(|edge| edge.to == *to))
+        {
+            edge.weight = weight;
+        }
+    }
+
+    /// Returns a copy of the graph with every vertex passed through `f`.
+    ///
+    /// Vertices that map to the same label are merged, keeping the first edge seen.
+    pub fn relabel<U, F>(&self, mut f: F) -> WeightedGraph<U, W>
+    where
+        U: Clone + Eq + Hash,
+        F: FnMut(&T) -> U,
+    {
+        let mut relabeled = WeightedGraph::new(self.graph_type.clone());
+
+        for (vertex, edges) in &self.adjacency_list {
+            let from = f(vertex);
+            relabeled.add_vertex(from.clone());
+            for edge in edges {
+                relabeled.add_edge(from.clone(), f(&edge.to), edge.weight.clone());
+            }
+        }
+
+        relabeled
+    }
+
+    /// Returns a copy of the graph with every edge weight passed through `f`.
+    pub fn map_weights<W2, F>(&self, mut f: F) -> WeightedGraph<T, W2>
+    where
+        W2: Clone,
+        F: FnMut(&W) -> W2,
+    {
+        let adjacency_list = self
+            .adjacency_list
+            .iter()
+            .map(|(vertex, edges)| {
+                let mapped = edges
+                    .iter()
+                    .map(|edge| Edge::new(edge.to.clone(), f(&edge.weight)))
+                    .collect();
+                (vertex.clone(), mapped)
+            })
+            .collect();
+
+        WeightedGraph {
+            adjacency_list,
+            graph_type: self.graph_type.clone(),
+            edge_count: self.edge_count,
+        }
+    }
+
+    /// Returns an unweighted [`Graph`] with the same vertices, edges and
+    /// graph type, so the unweighted algorithms can run on it
+    pub fn to_unweighted(&self) -> Graph<T> {
+        let graph_type = match self.graph_type {
+            GraphType::Directed => adjacency_list::GraphType::Directed,
+            GraphType::Undirected => adjacency_list::GraphType::Undirected,
+        };
+
+        let mut graph = Graph::with_vertex_capacity(self.vertex_count(), graph_type);
+        graph.add_vertices(self.adjacency_list.keys().cloned());
+        for (vertex, edges) in &self.adjacency_list {
+            for edge in edges {
+                graph.add_edge(vertex.clone(), edge.to.clone());
+            }
+        }
+        graph
+    }
+
+    /// Compacts the vertex set into dense `0..n` indices.
+    ///
+    /// Returns the indexed graph together with the vertex-to-index map and the
+    /// index-to-vertex table, so results computed on the indexed graph can be
+    /// translated back with [`WeightedGraph::from_indexed`].
+    pub fn to_indexed(&self) -> (WeightedGraph<usize, W>, HashMap<T, usize>, Vec<T>) {
+        let vertices: Vec<T> = self.adjacency_list.keys().cloned().collect();
+        let index: HashMap<T, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| (vertex.clone(), i))
+            .collect();
+
+        let indexed = self.relabel(|vertex| index[vertex]);
+        (indexed, index, vertices)
+    }
+
+    /// Translates an indexed graph back to the original vertex labels.
+    ///
+    /// Returns `None` if the graph references an index outside `vertices`.
+    pub fn from_indexed(indexed: &WeightedGraph<usize, W>, vertices: &[T]) -> Option<Self> {
+        if indexed.vertices().any(|&i| i >= vertices.len()) {
+            return None;
+        }
+
+        Some(indexed.relabel(|&i| vertices[i].clone()))
+    }
+
+    /// Returns a snapshot of the adjacency lists as `(neighbor, weight)` pairs
+    ///
+    /// Isolated vertices map to an empty `Vec`, and undirected edges appear in
+    /// both endpoints' lists.
+    pub fn to_adjacency_map(&self) -> HashMap<T, Vec<(T, W)>> {
+        self.adjacency_list
+            .iter()
+            .map(|(vertex, edges)| {
+                let pairs = edges
+                    .iter()
+                    .map(|edge| (edge.to.clone(), edge.weight.clone()))
+                    .collect();
+                (vertex.clone(), pairs)
+            })
+            .collect()
+    }
+
+    /// Builds a graph from adjacency lists of `(neighbor, weight)` pairs
+    ///
+    /// Every key becomes a vertex, so isolated vertices are kept. For an
+    /// undirected graph an edge may be listed under one or both endpoints; it
+    /// is counted once. An edge listed more than once keeps the smallest of
+    /// its weights, whatever order the map is visited in.
+    pub fn from_adjacency_map(map: HashMap<T, Vec<(T, W)>>, graph_type: GraphType) -> Self
+    where
+        W: PartialOrd,
+    {
+        let mut graph = Self::with_vertex_capacity(map.len(), graph_type);
+        graph.add_vertices(map.keys().cloned());
+        for (from, edges) in map {
+            for (to, weight) in edges {
+                match graph.get_edge_weight(&from, &to) {
+                    Some(existing) if weight < *existing => {
+                        graph.set_edge_weight(&from, &to, weight);
+                    }
+                    Some(_) => {}
+                    None => {
+                        graph.add_edge(from.clone(), to, weight);
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    pub fn min_weight_edge(&self) -> Option<(&T, &T, &W)>
+    where
+        W: PartialOrd,
+    {
+        self.logical_edges()
+            .into_iter()
+            .reduce(|best, edge| if edge.2 < best.2 { edge } else { best })
+    }
+
+    pub fn max_weight_edge(&self) -> Option<(&T, &T, &W)>
+    where
+        W: PartialOrd,
+    {
+        self.logical_edges()
+            .into_iter()
+            .reduce(|best, edge| if edge.2 > best.2 { edge } else { best })
+    }
+
+    /// Sums the weights, counting each undirected edge once
+    pub fn weight_sum(&self) -> W
+    where
+        W: Default + Add<Output = W>,
+    {
+        self.logical_edges()
+            .into_iter()
+            .fold(W::default(), |sum, (_, _, weight)| sum + weight.clone())
+    }
+
+    /// Replaces every weight `w` with `max - w`, where `max` is the largest
+    /// weight, so the heaviest edges become the cheapest to traverse
+    pub fn invert_weights(&mut self)
+    where
+        W: PartialOrd + Sub<Output = W>,
+    {
+        let Some(max) = self.max_weight_edge().map(|(_, _, weight)| weight.clone()) else {
+            return;
+        };
+
+        for edges in self.adjacency_list.values_mut() {
+            for edge in edges {
+                edge.weight = max.clone() - edge.weight.clone();
+            }
+        }
+    }
+
+    /// Lists each logical edge once; undirected edges are reported in one direction
+    fn logical_edges(&self) -> Vec<(&T, &T, &W)> {
+        let mut seen = HashSet::new();
+        let mut edges = Vec::with_capacity(self.edge_count);
+
+        for (from, neighbors) in &self.adjacency_list {
+            for edge in neighbors {
+                if self.graph_type == GraphType::Undirected && seen.contains(&(&edge.to, from)) {
+                    continue;
+                }
+                seen.insert((from, &edge.to));
+                edges.push((from, &edge.to, &edge.weight));
+            }
+        }
+
+        edges
+    }
+}
+
+impl<T> WeightedGraph<T, f64>
+where
+    T: Clone + Eq + Hash,
+{
+    /// Rescales all weights linearly into `[0, 1]`
+    ///
+    /// If every edge has the same weight, all weights become `1.0`.
+    pub fn normalize_weights(&mut self) {
+        let (Some(min), Some(max)) = (
+            self.min_weight_edge().map(|(_, _, &weight)| weight),
+            self.max_weight_edge().map(|(_, _, &weight)| weight),
+        ) else {
+            return;
+        };
+
+        let range = max - min;
+        for edges in self.adjacency_list.values_mut() {
+            for edge in edges {
+                edge.weight = if range > 0.0 {
+                    (edge.weight - min) / range
+                } else {
+                    1.0
+                };
+            }
+        }
+    }
 }
 
 impl<T, W> Clear for WeightedGraph<T, W> {
@@ -183,6 +797,37 @@ where
     }
 }
 
+/// Graphs are equal when they have the same type, vertices and weighted
+/// edges, regardless of insertion order
+impl<T, W> PartialEq for WeightedGraph<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.graph_type == other.graph_type
+            && self.edge_count == other.edge_count
+            && self.adjacency_list.len() == other.adjacency_list.len()
+            && self.adjacency_list.iter().all(|(vertex, edges)| {
+                other.adjacency_list.get(vertex).is_some_and(|theirs| {
+                    edges.len() == theirs.len()
+                        && edges.iter().all(|edge| {
+                            theirs
+                                .iter()
+                                .any(|e| e.to == edge.to && e.weight == edge.weight)
+                        })
+                })
+            })
+    }
+}
+
+impl<T, W> Eq for WeightedGraph<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Clone + Eq,
+{
+}
+
 impl<T, W> Default for WeightedGraph<T, W>
 where
     T: Clone + Eq + Hash,
@@ -207,6 +852,49 @@ where
     }
 }
 
+impl<T, W> DisplayLimited for WeightedGraph<T, W>
+where
+    T: Clone + Eq + Hash + Ord + fmt::Display,
+    W: Clone + fmt::Display,
+{
+    /// Writes `V={...} E={a->b: w, ...}` with both sets sorted; the limit
+    /// applies to each
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        let head = limit.saturating_add(1);
+        let vertices = display::smallest_sorted(self.vertices().collect(), head, Ord::cmp);
+        f.write_str("V=")?;
+        display::write_list(f, "{", "}", vertices, limit)?;
+
+        let arrow = match self.graph_type {
+            GraphType::Directed => "->",
+            GraphType::Undirected => "--",
+        };
+        let edges: Vec<_> = self
+            .adjacency_list
+            .iter()
+            .flat_map(|(from, edges)| edges.iter().map(move |edge| (from, &edge.to, &edge.weight)))
+            .filter(|(from, to, _)| self.graph_type == GraphType::Directed || from <= to)
+            .collect();
+        let edges = display::smallest_sorted(edges, head, |a, b| (a.0, a.1).cmp(&(b.0, b.1)))
+            .into_iter()
+            .map(|(from, to, weight)| {
+                display::display_fn(move |f| write!(f, "{from}{arrow}{to}: {weight}"))
+            });
+        f.write_str(" E=")?;
+        display::write_list(f, "{", "}", edges, limit)
+    }
+}
+
+impl<T, W> fmt::Display for WeightedGraph<T, W>
+where
+    T: Clone + Eq + Hash + Ord + fmt::Display,
+    W: Clone + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_limit(f, DISPLAY_LIMIT)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,6 +907,36 @@ mod tests {
         assert_eq!(graph.edge_count(), 0);
     }
 
+    #[test]
+    fn self_loop_conventions() {
+        for graph_type in [GraphType::Directed, GraphType::Undirected] {
+            let undirected = graph_type == GraphType::Undirected;
+            let mut graph = WeightedGraph::new(graph_type);
+            graph.add_edge(1, 2, 1.0);
+            assert!(graph.add_edge(1, 1, 2.0));
+            assert!(!graph.add_edge(1, 1, 3.0));
+
+            assert!(graph.has_edge(&1, &1));
+            assert_eq!(graph.get_edge_weight(&1, &1), Some(&2.0));
+            assert_eq!(graph.edge_count(), 2);
+            assert_eq!(graph.degree(&1), Some(if undirected { 3 } else { 2 }));
+            assert_eq!(graph.in_degree(&1), Some(if undirected { 3 } else { 1 }));
+
+            assert!(graph.remove_edge(&1, &1));
+            assert!(!graph.has_edge(&1, &1));
+            assert_eq!(graph.edge_count(), 1);
+            assert_eq!(graph.degree(&1), Some(1));
+
+            graph.add_edge(2, 2, 4.0);
+            graph.add_edge(3, 1, 5.0);
+            assert!(graph.remove_vertex(&2));
+            assert_eq!(graph.edge_count(), 1);
+            assert!(graph.remove_vertex(&1));
+            assert_eq!(graph.edge_count(), 0);
+            assert_eq!(graph.degree(&3), Some(0));
+        }
+    }
+
     #[test]
     fn add_vertices_and_edges() {
         let mut graph = WeightedGraph::directed();
@@ -285,6 +1003,165 @@ mod tests {
         assert_eq!(graph.edge_count(), 0);
     }
 
+    #[test]
+    fn with_vertex_capacity() {
+        let n = 10_000;
+        let mut hinted = WeightedGraph::with_vertex_capacity(n, GraphType::Undirected);
+        let mut plain = WeightedGraph::undirected();
+        let reserved = hinted.adjacency_list.capacity();
+        assert!(reserved >= n);
+
+        for i in 0..n {
+            let weight = i % 7;
+            assert_eq!(
+                hinted.add_edge(i, (i + 1) % n, weight),
+                plain.add_edge(i, (i + 1) % n, weight)
+            );
+        }
+
+        assert_eq!(hinted.adjacency_list.capacity(), reserved);
+        assert_eq!(hinted.vertex_count(), plain.vertex_count());
+        assert_eq!(hinted.edge_count(), plain.edge_count());
+        for i in 0..n {
+            let next = (i + 1) % n;
+            assert_eq!(
+                hinted.get_edge_weight(&next, &i),
+                plain.get_edge_weight(&next, &i)
+            );
+        }
+    }
+
+    #[test]
+    fn indexed_round_trip() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("b", "c", 2);
+        graph.add_edge("a", "c", 5);
+        graph.add_vertex("d");
+
+        let (indexed, index, vertices) = graph.to_indexed();
+        assert_eq!(indexed.vertex_count(), 4);
+        assert_eq!(indexed.edge_count(), 3);
+        assert_eq!(index.len(), vertices.len());
+        assert!(graph.vertices().all(|v| vertices[index[v]] == *v));
+
+        let restored = WeightedGraph::from_indexed(&indexed, &vertices).unwrap();
+        assert_eq!(restored.vertex_count(), 4);
+        assert_eq!(restored.edge_count(), 3);
+        assert_eq!(restored.get_edge_weight(&"a", &"b"), Some(&1));
+        assert_eq!(restored.get_edge_weight(&"b", &"c"), Some(&2));
+        assert_eq!(restored.get_edge_weight(&"a", &"c"), Some(&5));
+        assert!(restored.has_vertex(&"d"));
+    }
+
+    #[test]
+    fn to_unweighted_runs_unweighted_algorithms() {
+        use crate::graph::algorithms::{bfs, connected_components, is_cyclic};
+
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge(1, 2, 7);
+        graph.add_edge(2, 3, 1);
+        graph.add_edge(4, 5, 2);
+        graph.add_vertex(6);
+
+        let unweighted = graph.to_unweighted();
+        assert_eq!(unweighted.vertex_count(), 6);
+        assert_eq!(unweighted.edge_count(), 3);
+        assert!(unweighted.has_edge(&3, &2));
+        assert_eq!(bfs(&unweighted, &1), vec![1, 2, 3]);
+        assert_eq!(connected_components(&unweighted).len(), 3);
+        assert!(!is_cyclic(&unweighted));
+
+        let mut directed = WeightedGraph::directed();
+        directed.add_edge('a', 'b', 1.5);
+        directed.add_edge('b', 'a', 2.5);
+        let unweighted = directed.to_unweighted();
+        assert_eq!(
+            unweighted.graph_type(),
+            &adjacency_list::GraphType::Directed
+        );
+        assert!(is_cyclic(&unweighted));
+    }
+
+    #[test]
+    fn contract_edge_combines_parallel_weights() {
+        let mut triangle = WeightedGraph::undirected();
+        triangle.add_edge(1, 2, 1);
+        triangle.add_edge(2, 3, 2);
+        triangle.add_edge(1, 3, 4);
+
+        assert!(!triangle.contract_edge(&1, &5, 15, |a, b| a + b));
+        assert!(triangle.contract_edge(&1, &2, 12, |a, b| a + b));
+        assert_eq!(triangle.vertex_count(), 2);
+        assert_eq!(triangle.edge_count(), 1);
+        assert_eq!(triangle.get_edge_weight(&12, &3), Some(&6));
+        assert_eq!(triangle.get_edge_weight(&3, &12), Some(&6));
+        assert_eq!(triangle.degree(&12), Some(1));
+
+        let mut directed = WeightedGraph::directed();
+        directed.add_edge('a', 'c', 5);
+        directed.add_edge('b', 'c', 3);
+        directed.add_edge('c', 'a', 1);
+        directed.add_edge('a', 'b', 9);
+
+        assert!(directed.merge_vertices(&['a', 'b'], 'm', |a, b| *a.min(b)));
+        assert_eq!(directed.edge_count(), 2);
+        assert_eq!(directed.get_edge_weight(&'m', &'c'), Some(&3));
+        assert_eq!(directed.get_edge_weight(&'c', &'m'), Some(&1));
+    }
+
+    #[test]
+    fn is_connected_ignores_weights_and_direction() {
+        let mut graph = WeightedGraph::undirected();
+        assert!(graph.is_connected());
+
+        graph.add_edge(1, 2, 5.0);
+        graph.add_edge(2, 3, 0.5);
+        assert!(graph.is_connected());
+
+        graph.add_edge(4, 5, 1.0);
+        assert!(!graph.is_connected());
+
+        let mut directed = WeightedGraph::directed();
+        directed.add_edge('a', 'b', 1);
+        directed.add_edge('c', 'b', 2);
+        assert!(directed.is_connected());
+        directed.add_vertex('d');
+        assert!(!directed.is_connected());
+    }
+
+    #[test]
+    fn map_weights() {
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge(1, 2, 3);
+        graph.add_edge(2, 3, 4);
+
+        let doubled = graph.map_weights(|w| f64::from(*w) * 2.0);
+        assert_eq!(doubled.edge_count(), 2);
+        assert_eq!(doubled.get_edge_weight(&1, &2), Some(&6.0));
+        assert_eq!(doubled.get_edge_weight(&3, &2), Some(&8.0));
+    }
+
+    #[test]
+    fn clear_edges_keeps_vertices() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_vertex(4);
+        graph.add_edge(1, 2, 1.0);
+        graph.add_edge(2, 3, 2.0);
+        graph.add_edge(3, 3, 3.0);
+
+        graph.clear_edges();
+
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 0);
+        for from in 1..=4 {
+            assert!(graph.has_vertex(&from));
+            for to in 1..=4 {
+                assert!(!graph.has_edge(&from, &to));
+            }
+        }
+    }
+
     #[test]
     fn clear_graph() {
         let mut graph = WeightedGraph::directed();
@@ -298,4 +1175,276 @@ mod tests {
         assert_eq!(graph.vertex_count(), 0);
         assert_eq!(graph.edge_count(), 0);
     }
+
+    #[test]
+    fn merge_weight_conflicts() {
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("b", "c", 2);
+
+        let mut other = WeightedGraph::undirected();
+        other.add_edge("b", "a", 10);
+        other.add_edge("c", "d", 3);
+
+        let mut kept = WeightedGraph::undirected();
+        kept.union_with(&graph);
+        kept.union_with(&other);
+        assert_eq!(kept.edge_count(), 3);
+        assert_eq!(kept.get_edge_weight(&"a", &"b"), Some(&1));
+
+        graph.merge_with(&other, |existing, incoming| existing + incoming);
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.get_edge_weight(&"a", &"b"), Some(&11));
+        assert_eq!(graph.get_edge_weight(&"b", &"a"), Some(&11));
+        assert_eq!(graph.get_edge_weight(&"d", &"c"), Some(&3));
+    }
+
+    #[test]
+    fn union_with_disjoint_triangles() {
+        let mut graph = WeightedGraph::directed();
+        let mut other = WeightedGraph::directed();
+        for (from, to) in [(0, 1), (1, 2), (2, 0)] {
+            graph.add_edge(from, to, from * 10 + to);
+            other.add_edge(from + 3, to + 3, from * 10 + to);
+        }
+
+        graph.union_with(&other);
+        assert_eq!(graph.vertex_count(), 6);
+        assert_eq!(graph.edge_count(), 6);
+        assert_eq!(graph.get_edge_weight(&5, &3), Some(&20));
+        assert!(!graph.has_edge(&3, &5));
+
+        let mut reweighted = WeightedGraph::directed();
+        reweighted.add_edge(3, 4, -1);
+        reweighted.add_edge(4, 6, -1);
+        graph.union_with(&reweighted);
+        assert_eq!(graph.vertex_count(), 7);
+        assert_eq!(graph.edge_count(), 7);
+        assert_eq!(graph.get_edge_weight(&3, &4), Some(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot merge graphs of different types")]
+    fn union_with_mismatched_types() {
+        let mut graph: WeightedGraph<i32, i32> = WeightedGraph::directed();
+        graph.union_with(&WeightedGraph::undirected());
+    }
+
+    #[test]
+    fn disjoint_union() {
+        let mut a = WeightedGraph::directed();
+        a.add_edge(1, 2, 5);
+
+        let union = WeightedGraph::disjoint_union(&a, &a, |side, &v| v + side * 10);
+        assert_eq!(union.vertex_count(), 4);
+        assert_eq!(union.edge_count(), 2);
+        assert_eq!(union.get_edge_weight(&11, &12), Some(&5));
+    }
+
+    #[test]
+    fn density() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge(1, 2, 1.5);
+        graph.add_vertex(3);
+        assert_eq!(graph.density(), 1.0 / 6.0);
+
+        let empty: WeightedGraph<i32, f64> = WeightedGraph::undirected();
+        assert_eq!(empty.density(), 0.0);
+    }
+
+    #[test]
+    fn neighbors_cloned_allows_mutation() {
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("a", "c", 5);
+        graph.add_edge("a", "d", 9);
+
+        for edge in graph.neighbors_cloned(&"a") {
+            if edge.weight > 3 {
+                graph.remove_edge(&"a", &edge.to);
+            }
+        }
+
+        assert_eq!(graph.edge_count(), 1);
+        assert!(!graph.has_edge(&"d", &"a"));
+        assert!(graph.neighbors_cloned(&"z").is_empty());
+    }
+
+    #[test]
+    fn weight_statistics() {
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge("a", "b", 4);
+        graph.add_edge("b", "c", -2);
+        graph.add_edge("c", "a", 7);
+
+        assert_eq!(graph.weight_sum(), 9);
+        assert_eq!(graph.min_weight_edge().map(|(_, _, &w)| w), Some(-2));
+        assert_eq!(graph.max_weight_edge().map(|(_, _, &w)| w), Some(7));
+
+        let empty: WeightedGraph<i32, i32> = WeightedGraph::directed();
+        assert_eq!(empty.weight_sum(), 0);
+        assert!(empty.max_weight_edge().is_none());
+    }
+
+    #[test]
+    fn normalize_weights() {
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge(1, 2, 10.0);
+        graph.add_edge(2, 3, 20.0);
+        graph.add_edge(3, 4, 50.0);
+
+        graph.normalize_weights();
+        assert_eq!(graph.get_edge_weight(&1, &2), Some(&0.0));
+        assert_eq!(graph.get_edge_weight(&3, &2), Some(&0.25));
+        assert_eq!(graph.get_edge_weight(&4, &3), Some(&1.0));
+
+        graph.normalize_weights();
+        assert_eq!(graph.get_edge_weight(&2, &3), Some(&0.25));
+        assert_eq!(graph.weight_sum(), 1.25);
+    }
+
+    #[test]
+    fn dijkstra_over_inverted_weights() {
+        use crate::graph::algorithms::dijkstra_shortest_path;
+
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge("a", "b", 9);
+        graph.add_edge("b", "d", 8);
+        graph.add_edge("a", "c", 1);
+        graph.add_edge("c", "d", 2);
+
+        assert_eq!(
+            dijkstra_shortest_path(&graph, &"a", &"d").1,
+            Some(vec!["a", "c", "d"])
+        );
+
+        graph.invert_weights();
+        assert_eq!(graph.get_edge_weight(&"a", &"b"), Some(&0));
+        assert_eq!(
+            dijkstra_shortest_path(&graph, &"a", &"d").1,
+            Some(vec!["a", "b", "d"])
+        );
+    }
+
+    #[test]
+    fn keep_min_parallel_edges() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge("a", "b", 5);
+        graph.add_edge("a", "c", 1);
+        graph.neighbors_mut(&"a").unwrap().push(Edge::new("b", 2));
+        graph.neighbors_mut(&"a").unwrap().push(Edge::new("b", 7));
+
+        graph.keep_min_parallel_edges();
+        assert_eq!(graph.neighbors(&"a").unwrap().len(), 2);
+        assert_eq!(graph.get_edge_weight(&"a", &"b"), Some(&2));
+        assert_eq!(graph.edge_count(), 2);
+
+        let mut graph = WeightedGraph::undirected();
+        graph.add_edge(1, 2, 9);
+        graph.add_edge(2, 2, 4);
+        graph.neighbors_mut(&2).unwrap().push(Edge::new(1, 3));
+
+        graph.keep_min_parallel_edges();
+        assert_eq!(graph.get_edge_weight(&1, &2), Some(&3));
+        assert_eq!(graph.get_edge_weight(&2, &1), Some(&3));
+        assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[test]
+    fn add_vertices_skips_existing() {
+        let mut graph: WeightedGraph<i32, u32> = WeightedGraph::undirected();
+        assert_eq!(graph.add_vertices([1, 2, 2, 3]), 3);
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.add_vertices(vec![3, 4]), 1);
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn adjacency_map_round_trip() {
+        for graph_type in [GraphType::Directed, GraphType::Undirected] {
+            let mut graph = WeightedGraph::new(graph_type.clone());
+            graph.add_edge("a", "b", 4);
+            graph.add_edge("b", "c", 7);
+            graph.add_edge("c", "c", 1);
+            graph.add_vertex("d");
+
+            let map = graph.to_adjacency_map();
+            assert!(map[&"d"].is_empty());
+
+            let restored = WeightedGraph::from_adjacency_map(map, graph_type);
+            assert_eq!(restored.vertex_count(), 4);
+            assert_eq!(restored.edge_count(), graph.edge_count());
+            for (from, to, weight) in [("a", "b", 4), ("b", "c", 7), ("c", "c", 1)] {
+                assert_eq!(restored.get_edge_weight(&from, &to), Some(&weight));
+            }
+        }
+    }
+
+    #[test]
+    fn adjacency_map_repeated_edges_keep_smallest_weight() {
+        let mut map = HashMap::new();
+        map.insert("a", vec![("b", 5)]);
+        map.insert("b", vec![("a", 2)]);
+        let undirected = WeightedGraph::from_adjacency_map(map, GraphType::Undirected);
+        assert_eq!(undirected.edge_count(), 1);
+        assert_eq!(undirected.get_edge_weight(&"a", &"b"), Some(&2));
+        assert_eq!(undirected.get_edge_weight(&"b", &"a"), Some(&2));
+
+        let mut map = HashMap::new();
+        map.insert("a", vec![("b", 5), ("b", 3), ("b", 4)]);
+        let directed = WeightedGraph::from_adjacency_map(map, GraphType::Directed);
+        assert_eq!(directed.edge_count(), 1);
+        assert_eq!(directed.get_edge_weight(&"a", &"b"), Some(&3));
+    }
+
+    #[test]
+    fn reverse_edge_keeps_weight() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge("a", "b", 4);
+        graph.add_edge("b", "c", 1);
+
+        assert!(graph.reverse_edge(&"a", &"b"));
+        assert_eq!(graph.get_edge_weight(&"b", &"a"), Some(&4));
+        assert!(!graph.has_edge(&"a", &"b"));
+        assert_eq!(graph.edge_count(), 2);
+        assert!(!graph.reverse_edge(&"c", &"b"));
+    }
+
+    #[test]
+    fn display_with_weights() {
+        let mut graph = WeightedGraph::directed();
+        graph.add_edge("b", "c", 2);
+        graph.add_edge("a", "b", 7);
+        assert_eq!(graph.to_string(), "V={a, b, c} E={a->b: 7, b->c: 2}");
+
+        let mut undirected = WeightedGraph::undirected();
+        undirected.add_edge(2, 1, 0.5);
+        assert_eq!(undirected.to_string(), "V={1, 2} E={1--2: 0.5}");
+    }
+
+    #[test]
+    fn degrees_match_unweighted_graph() {
+        let mut weighted = WeightedGraph::directed();
+        let mut plain = crate::Graph::directed();
+        for (from, to) in [(1, 2), (1, 3), (1, 4), (2, 1), (4, 4)] {
+            weighted.add_edge(from, to, 1.5);
+            plain.add_edge(from, to);
+        }
+
+        assert_eq!(weighted.out_degree(&1), Some(3));
+        assert_eq!(weighted.degree(&1), Some(3));
+        assert_eq!(weighted.degree(&9), None);
+        for vertex in 1..=4 {
+            assert_eq!(weighted.in_degree(&vertex), plain.in_degree(&vertex));
+            assert_eq!(weighted.out_degree(&vertex), plain.out_degree(&vertex));
+        }
+
+        let mut undirected = WeightedGraph::undirected();
+        undirected.add_edge("a", "b", 1);
+        undirected.add_edge("a", "a", 1);
+        assert_eq!(undirected.degree(&"a"), Some(3));
+        assert_eq!(undirected.in_degree(&"b"), Some(1));
+    }
 }
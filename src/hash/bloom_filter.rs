@@ -31,24 +31,72 @@ impl<T: Hash> BloomFilter<T> {
         }
     }
 
-    pub fn insert(&mut self, item: &T) {
-        for i in 0..self.hash_count {
-            let hash = self.hash(item, i);
-            let index = hash % self.bit_array.len();
-            self.bit_array[index] = true;
+    /// Inserts an item, returning `true` if it was definitely not present before.
+    ///
+    /// The element count only grows when at least one bit flips from 0 to 1, so
+    /// repeated inserts of the same item are counted once. An item whose bits were
+    /// all set by earlier inserts is not counted, so `len()` may slightly undercount.
+    pub fn insert(&mut self, item: &T) -> bool {
+        let newly_set = self.set_bits(item);
+        if newly_set {
+            self.element_count += 1;
         }
+        newly_set
+    }
+
+    /// Inserts an item and always increments the element count, even for duplicates.
+    pub fn insert_counted(&mut self, item: &T) {
+        self.set_bits(item);
         self.element_count += 1;
     }
 
-    pub fn contains(&self, item: &T) -> bool {
-        for i in 0..self.hash_count {
-            let hash = self.hash(item, i);
-            let index = hash % self.bit_array.len();
-            if !self.bit_array[index] {
-                return false;
+    /// Inserts every item, counting them the same way as repeated `insert` calls
+    pub fn insert_batch(&mut self, items: &[T]) {
+        for item in items {
+            if self.set_indices(Self::hash_pair(item)) {
+                self.element_count += 1;
             }
         }
-        true
+    }
+
+    fn set_bits(&mut self, item: &T) -> bool {
+        self.set_indices(Self::hash_pair(item))
+    }
+
+    fn set_indices(&mut self, pair: (u64, u64)) -> bool {
+        let mut newly_set = false;
+        for index in self.indices(pair) {
+            newly_set |= !self.bit_array[index];
+            self.bit_array[index] = true;
+        }
+        newly_set
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.contains_indices(Self::hash_pair(item))
+    }
+
+    /// Checks every item, giving the same answers as calling `contains` on each
+    pub fn contains_batch(&self, items: &[T]) -> Vec<bool> {
+        items
+            .iter()
+            .map(|item| self.contains_indices(Self::hash_pair(item)))
+            .collect()
+    }
+
+    /// Yields the items that are definitely not in the filter
+    pub fn filter_unseen<'a, I>(&'a self, items: I) -> impl Iterator<Item = &'a T> + 'a
+    where
+        I: IntoIterator<Item = &'a T>,
+        I::IntoIter: 'a,
+    {
+        items
+            .into_iter()
+            .filter(move |item| !self.contains_indices(Self::hash_pair(item)))
+    }
+
+    fn contains_indices(&self, pair: (u64, u64)) -> bool {
+        self.indices(pair).all(|index| self.bit_array[index])
     }
 
     pub fn false_positive_rate(&self) -> f64 {
@@ -75,11 +123,31 @@ impl<T: Hash> BloomFilter<T> {
         self.hash_count
     }
 
-    fn hash(&self, item: &T, seed: usize) -> usize {
+    /// Hashes the item once into the two values used for double hashing
+    fn hash_pair(item: &T) -> (u64, u64) {
         let mut hasher = DefaultHasher::new();
         item.hash(&mut hasher);
-        seed.hash(&mut hasher);
-        hasher.finish() as usize
+        let first = hasher.finish();
+        hasher.write_u64(0x9e37_79b9_7f4a_7c15);
+        (first, hasher.finish())
+    }
+
+    /// Derives the `hash_count` bit positions as `h1 + i * h2` (Kirsch–Mitzenmacher)
+    ///
+    /// The step is nudged until it is coprime with the filter size, so the first
+    /// `size` probes all land on different bits whatever the size is.
+    fn indices(&self, (first, step): (u64, u64)) -> impl Iterator<Item = usize> {
+        let size = self.bit_array.len() as u64;
+        let mut step = (step % size).max(1);
+        while gcd(step, size) > 1 {
+            step = step % (size - 1) + 1;
+        }
+
+        std::iter::successors(Some(first % size), move |&index| {
+            Some((index + step) % size)
+        })
+        .take(self.hash_count)
+        .map(|index| index as usize)
     }
 
     fn optimal_size(expected_elements: usize, false_positive_rate: f64) -> usize {
@@ -143,6 +211,13 @@ impl<T: Hash> FromIterator<T> for BloomFilter<T> {
     }
 }
 
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,6 +231,18 @@ mod tests {
         assert_eq!(filter.false_positive_rate(), 0.0);
     }
 
+    #[test]
+    fn probes_hit_distinct_bits_for_any_size() {
+        for size in [1, 7, 12, 64, 90] {
+            let hash_count = size.min(6);
+            for item in 0..200 {
+                let mut filter = BloomFilter::with_params(size, hash_count);
+                filter.insert(&item);
+                assert_eq!(filter.bit_count(), hash_count, "size {size}, item {item}");
+            }
+        }
+    }
+
     #[test]
     fn insert_and_contains() {
         let mut filter = BloomFilter::new(100, 0.01);
@@ -170,6 +257,38 @@ mod tests {
         assert_eq!(filter.len(), 3);
     }
 
+    #[test]
+    fn insert_reports_new_items() {
+        let mut filter = BloomFilter::new(100, 0.01);
+
+        assert!(filter.insert(&42));
+        for _ in 0..1000 {
+            assert!(!filter.insert(&42));
+        }
+        assert_eq!(filter.len(), 1);
+
+        filter.insert_counted(&42);
+        assert_eq!(filter.len(), 2);
+    }
+
+    #[test]
+    fn duplicate_heavy_false_positive_rate() {
+        let mut deduped = BloomFilter::new(100, 0.01);
+        let mut counted = BloomFilter::new(100, 0.01);
+
+        for _ in 0..100 {
+            for i in 0..10 {
+                deduped.insert(&i);
+                counted.insert_counted(&i);
+            }
+        }
+
+        assert!(deduped.len() <= 10);
+        assert_eq!(counted.len(), 1000);
+        assert!(deduped.false_positive_rate() < 0.01);
+        assert!(counted.false_positive_rate() > 0.5);
+    }
+
     #[test]
     fn definite_negatives() {
         let mut filter = BloomFilter::new(100, 0.01);
@@ -308,4 +427,37 @@ mod tests {
             "Actual false positive rate {actual_rate} exceeds theoretical bound {theoretical_rate}"
         );
     }
+
+    #[test]
+    fn batch_operations_match_scalar_path() {
+        let items: Vec<u32> = (0..2_000).collect();
+        let probes: Vec<u32> = (1_000..4_000).collect();
+
+        let mut scalar = BloomFilter::new(2_000, 0.01);
+        for item in &items {
+            scalar.insert(item);
+        }
+        let mut batched = BloomFilter::new(2_000, 0.01);
+        batched.insert_batch(&items);
+
+        assert_eq!(batched.len(), scalar.len());
+        assert_eq!(batched.bit_count(), scalar.bit_count());
+
+        let expected: Vec<bool> = probes.iter().map(|probe| scalar.contains(probe)).collect();
+        assert_eq!(batched.contains_batch(&probes), expected);
+        assert!(batched
+            .contains_batch(&items)
+            .into_iter()
+            .all(|found| found));
+
+        let unseen: Vec<_> = batched.filter_unseen(&probes).copied().collect();
+        let expected_unseen: Vec<_> = probes
+            .iter()
+            .zip(&expected)
+            .filter(|(_, &found)| !found)
+            .map(|(&probe, _)| probe)
+            .collect();
+        assert_eq!(unseen, expected_unseen);
+        assert!(unseen.iter().all(|&probe| probe >= 2_000));
+    }
 }
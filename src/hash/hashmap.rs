@@ -1,7 +1,9 @@
-use crate::utils::{Clear, Size};
+use crate::utils::display::{self, DisplayLimited, DISPLAY_LIMIT};
+use crate::utils::{Clear, Drain, Size};
 use std::collections::hash_map::DefaultHasher;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::Index;
 
 const DEFAULT_CAPACITY: usize = 16;
 
@@ -24,10 +26,41 @@ impl<K, V> Entry<K, V> {
     }
 }
 
+/// FNV-1a hasher with the offset basis mixed with a caller-provided seed
+struct SeededHasher {
+    state: u64,
+}
+
+impl SeededHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new(seed: u64) -> Self {
+        Self {
+            state: Self::OFFSET_BASIS ^ seed,
+        }
+    }
+}
+
+impl Hasher for SeededHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= u64::from(byte);
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
 pub struct HashMap<K, V> {
     buckets: Vec<Option<Box<Entry<K, V>>>>,
     size: usize,
     capacity: usize,
+    seed: Option<u64>,
+    resizes: usize,
 }
 
 impl<K, V> HashMap<K, V>
@@ -44,6 +77,22 @@ where
             buckets: (0..capacity).map(|_| None).collect(),
             size: 0,
             capacity,
+            seed: None,
+            resizes: 0,
+        }
+    }
+
+    /// Creates a map with enough buckets to hold `entries` entries without resizing
+    pub(crate) fn with_room_for(entries: usize) -> Self {
+        Self::with_capacity(Self::buckets_for(entries).max(DEFAULT_CAPACITY))
+    }
+
+    /// Creates a map whose bucket placement depends only on `seed` and the
+    /// sequence of operations, so iteration order is reproducible across runs
+    pub fn with_seeded_hasher(seed: u64) -> Self {
+        Self {
+            seed: Some(seed),
+            ..Self::new()
         }
     }
 
@@ -99,6 +148,60 @@ where
         None
     }
 
+    /// Returns mutable references to the values of `N` distinct keys at once
+    ///
+    /// Returns `None` if any key is missing or two keys are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_ds_lib_bee::hash::HashMap;
+    ///
+    /// let mut balances = HashMap::new();
+    /// balances.insert("alice", 100);
+    /// balances.insert("bob", 20);
+    ///
+    /// let [from, to] = balances.get_many_mut([&"alice", &"bob"]).unwrap();
+    /// *from -= 30;
+    /// *to += 30;
+    /// assert_eq!(balances.get(&"bob"), Some(&50));
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, keys: [&K; N]) -> Option<[&mut V; N]> {
+        for i in 0..N {
+            if keys[..i].contains(&keys[i]) {
+                return None;
+            }
+        }
+
+        let mut indices: Vec<usize> = keys.iter().map(|key| self.hash(key)).collect();
+        indices.sort_unstable();
+        indices.dedup();
+
+        // Split the bucket slice at each wanted index so every chain is
+        // borrowed independently
+        let mut slots: [Option<&mut V>; N] = std::array::from_fn(|_| None);
+        let mut rest = self.buckets.as_mut_slice();
+        let mut offset = 0;
+        for index in indices {
+            let (bucket, tail) = rest.split_at_mut(index - offset + 1);
+            rest = tail;
+            offset = index + 1;
+
+            let mut current = bucket.last_mut()?.as_deref_mut();
+            while let Some(Entry { key, value, next }) = current {
+                if let Some(i) = keys.iter().position(|&wanted| wanted == key) {
+                    slots[i] = Some(value);
+                }
+                current = next.as_deref_mut();
+            }
+        }
+
+        if slots.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(slots.map(Option::unwrap))
+    }
+
     pub fn remove(&mut self, key: &K) -> Option<V> {
         let index = self.hash(key);
         let bucket = &mut self.buckets[index];
@@ -127,25 +230,52 @@ where
         None
     }
 
+    /// Removes the head entry of the first occupied bucket without hashing
+    pub(crate) fn take_any(&mut self) -> Option<(K, V)> {
+        let bucket = self.buckets.iter_mut().find(|bucket| bucket.is_some())?;
+        let removed = bucket.take()?;
+        *bucket = removed.next;
+        self.size -= 1;
+        Some((removed.key, removed.value))
+    }
+
     pub fn contains_key(&self, key: &K) -> bool {
         self.get(key).is_some()
     }
 
-    pub fn iter(&self) -> Iter<K, V> {
+    /// Returns an iterator over the entries in bucket order
+    ///
+    /// The order is unspecified and changes when the map resizes. Use
+    /// [`HashMap::with_seeded_hasher`] when tests need a reproducible order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
         Iter {
             bucket_iter: self.buckets.iter(),
             current_chain: None,
         }
     }
 
-    pub fn keys(&self) -> Keys<K, V> {
+    pub fn keys(&self) -> Keys<'_, K, V> {
         Keys { iter: self.iter() }
     }
 
-    pub fn values(&self) -> Values<K, V> {
+    pub fn values(&self) -> Values<'_, K, V> {
         Values { iter: self.iter() }
     }
 
+    /// Consumes the map, yielding its keys in unspecified order
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys {
+            iter: self.into_iter(),
+        }
+    }
+
+    /// Consumes the map, yielding its values in unspecified order
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues {
+            iter: self.into_iter(),
+        }
+    }
+
     pub fn load_factor(&self) -> f64 {
         self.size as f64 / self.capacity as f64
     }
@@ -154,10 +284,113 @@ where
         self.capacity
     }
 
+    /// Returns the number of buckets in the table; the same as
+    /// [`HashMap::capacity`], named for collision diagnostics
+    pub fn bucket_count(&self) -> usize {
+        self.capacity
+    }
+
+    /// Estimates the bytes held by the map: the bucket table plus one boxed
+    /// entry per element, not counting memory owned by keys or values
+    pub fn memory_usage_bytes(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.buckets.capacity() * std::mem::size_of::<Option<Box<Entry<K, V>>>>()
+            + self.size * std::mem::size_of::<Entry<K, V>>()
+    }
+
+    /// Removes all entries and shrinks the bucket table back to the default size
+    ///
+    /// Unlike [`Clear::clear`], this frees the memory held by a large table.
+    pub fn clear_and_shrink(&mut self) {
+        self.buckets = (0..DEFAULT_CAPACITY).map(|_| None).collect();
+        self.capacity = DEFAULT_CAPACITY;
+        self.size = 0;
+    }
+
+    /// Shrinks the bucket table to the smallest size that keeps the current
+    /// entries under the load factor, but not below the default size
+    ///
+    /// The table never shrinks on its own, so after many removals iteration
+    /// still walks every empty bucket until this is called.
+    pub fn shrink_to_fit(&mut self) {
+        let needed = Self::buckets_for(self.size).max(DEFAULT_CAPACITY);
+        if needed < self.capacity {
+            self.rehash(needed);
+        }
+    }
+
+    /// Grows the bucket table so that `additional` more entries fit without
+    /// exceeding the load factor, rehashing at most once
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = Self::buckets_for(self.size.saturating_add(additional));
+        if needed > self.capacity {
+            self.rehash(needed);
+        }
+    }
+
+    /// Returns how many times the bucket table has been rebuilt
+    pub fn resize_count(&self) -> usize {
+        self.resizes
+    }
+
+    /// Returns how many buckets hold 0, 1, 2, ... entries, indexed by chain length
+    pub fn bucket_histogram(&self) -> Vec<usize> {
+        let mut histogram = vec![0; self.max_chain_length() + 1];
+        for length in self.chain_lengths() {
+            histogram[length] += 1;
+        }
+        histogram
+    }
+
+    pub fn max_chain_length(&self) -> usize {
+        self.chain_lengths().max().unwrap_or(0)
+    }
+
+    /// Returns the length of the longest bucket chain, see
+    /// [`HashMap::max_chain_length`]
+    pub fn longest_chain(&self) -> usize {
+        self.max_chain_length()
+    }
+
+    /// Returns the mean length of the non-empty chains
+    pub fn average_chain_length(&self) -> f64 {
+        let used = self.chain_lengths().filter(|&length| length > 0).count();
+        if used == 0 {
+            return 0.0;
+        }
+        self.size as f64 / used as f64
+    }
+
+    fn chain_lengths(&self) -> impl Iterator<Item = usize> + '_ {
+        self.buckets.iter().map(|bucket| {
+            let mut length = 0;
+            let mut current = bucket.as_deref();
+            while let Some(entry) = current {
+                length += 1;
+                current = entry.next.as_deref();
+            }
+            length
+        })
+    }
+
     fn hash(&self, key: &K) -> usize {
-        let mut hasher = DefaultHasher::new();
-        key.hash(&mut hasher);
-        (hasher.finish() as usize) % self.capacity
+        let hash = match self.seed {
+            Some(seed) => {
+                let mut hasher = SeededHasher::new(seed);
+                key.hash(&mut hasher);
+                hasher.finish()
+            }
+            None => {
+                let mut hasher = DefaultHasher::new();
+                key.hash(&mut hasher);
+                hasher.finish()
+            }
+        };
+        (hash as usize) % self.capacity
+    }
+
+    fn buckets_for(entries: usize) -> usize {
+        (entries as f64 / LOAD_FACTOR_THRESHOLD).ceil() as usize
     }
 
     fn should_resize(&self) -> bool {
@@ -165,13 +398,15 @@ where
     }
 
     fn resize(&mut self) {
-        let old_buckets = std::mem::replace(
-            &mut self.buckets,
-            (0..self.capacity * 2).map(|_| None).collect(),
-        );
-        let _old_capacity = self.capacity;
-        self.capacity *= 2;
+        self.rehash(self.capacity * 2);
+    }
+
+    fn rehash(&mut self, capacity: usize) {
+        let old_buckets =
+            std::mem::replace(&mut self.buckets, (0..capacity).map(|_| None).collect());
+        self.capacity = capacity;
         self.size = 0;
+        self.resizes += 1;
 
         for bucket in old_buckets {
             let mut current = bucket;
@@ -199,6 +434,20 @@ impl<K, V> Clear for HashMap<K, V> {
     }
 }
 
+impl<K, V> Drain for HashMap<K, V> {
+    type Item = (K, V);
+
+    fn drain(&mut self) -> Box<dyn Iterator<Item = (K, V)> + '_> {
+        let buckets = std::mem::replace(
+            &mut self.buckets,
+            (0..self.capacity).map(|_| None).collect(),
+        );
+        self.size = 0;
+
+        Box::new(IntoIter::new(buckets))
+    }
+}
+
 impl<K, V> Size for HashMap<K, V> {
     fn len(&self) -> usize {
         self.size
@@ -211,6 +460,56 @@ impl<K: fmt::Debug + Hash + Eq, V: fmt::Debug> fmt::Debug for HashMap<K, V> {
     }
 }
 
+impl<K, V> DisplayLimited for HashMap<K, V>
+where
+    K: Hash + Eq + Ord + fmt::Display,
+    V: fmt::Display,
+{
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        let entries =
+            display::smallest_sorted(self.iter().collect(), limit.saturating_add(1), |a, b| {
+                a.0.cmp(b.0)
+            });
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| display::display_fn(move |f| write!(f, "{key}: {value}")));
+        display::write_list(f, "{", "}", entries, limit)
+    }
+}
+
+/// Shows `{k: v, ...}` sorted by key, eliding past [`DISPLAY_LIMIT`]
+impl<K, V> fmt::Display for HashMap<K, V>
+where
+    K: Hash + Eq + Ord + fmt::Display,
+    V: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_limit(f, DISPLAY_LIMIT)
+    }
+}
+
+/// Maps are equal when they hold the same keys with equal values,
+/// regardless of insertion order or bucket layout
+impl<K: Hash + Eq, V: PartialEq> PartialEq for HashMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|v| v == value))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq> Eq for HashMap<K, V> {}
+
+impl<K: fmt::Debug + Hash + Eq, V> Index<&K> for HashMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: &K) -> &V {
+        self.get(key)
+            .unwrap_or_else(|| panic!("key {key:?} not found in HashMap"))
+    }
+}
+
 pub struct Iter<'a, K, V> {
     bucket_iter: std::slice::Iter<'a, Option<Box<Entry<K, V>>>>,
     current_chain: Option<&'a Entry<K, V>>,
@@ -262,9 +561,74 @@ impl<'a, K, V> Iterator for Values<'a, K, V> {
     }
 }
 
+pub struct IntoIter<K, V> {
+    bucket_iter: std::vec::IntoIter<Option<Box<Entry<K, V>>>>,
+    current_chain: Option<Box<Entry<K, V>>>,
+}
+
+impl<K, V> IntoIter<K, V> {
+    fn new(buckets: Vec<Option<Box<Entry<K, V>>>>) -> Self {
+        Self {
+            bucket_iter: buckets.into_iter(),
+            current_chain: None,
+        }
+    }
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.current_chain.take() {
+                let Entry { key, value, next } = *entry;
+                self.current_chain = next;
+                return Some((key, value));
+            }
+
+            self.current_chain = self.bucket_iter.next()?;
+        }
+    }
+}
+
+impl<K, V> IntoIterator for HashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.buckets)
+    }
+}
+
+pub struct IntoKeys<K, V> {
+    iter: IntoIter<K, V>,
+}
+
+impl<K, V> Iterator for IntoKeys<K, V> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(k, _)| k)
+    }
+}
+
+pub struct IntoValues<K, V> {
+    iter: IntoIter<K, V>,
+}
+
+impl<K, V> Iterator for IntoValues<K, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+}
+
+/// Sizes the bucket table from the iterator's `size_hint` up front
 impl<K: Hash + Eq, V> FromIterator<(K, V)> for HashMap<K, V> {
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut map = HashMap::new();
+        let iter = iter.into_iter();
+        let mut map = HashMap::with_room_for(iter.size_hint().0);
         for (key, value) in iter {
             map.insert(key, value);
         }
@@ -272,8 +636,19 @@ impl<K: Hash + Eq, V> FromIterator<(K, V)> for HashMap<K, V> {
     }
 }
 
+/// Reserves room from the iterator's `size_hint` before inserting
+///
+/// When the map already holds entries only half the hint is reserved, since
+/// some incoming keys are likely to be duplicates.
 impl<K: Hash + Eq, V> Extend<(K, V)> for HashMap<K, V> {
     fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let hint = iter.size_hint().0;
+        self.reserve(if self.is_empty() {
+            hint
+        } else {
+            (hint + 1) / 2
+        });
         for (key, value) in iter {
             self.insert(key, value);
         }
@@ -283,6 +658,7 @@ impl<K: Hash + Eq, V> Extend<(K, V)> for HashMap<K, V> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::rng::Lcg;
 
     #[test]
     fn new_map_is_empty() {
@@ -306,6 +682,23 @@ mod tests {
         assert_eq!(map.get(&"key3"), None);
     }
 
+    #[test]
+    fn index_operator() {
+        let mut map = HashMap::new();
+        map.insert("key1", 10);
+        map.insert("key2", 20);
+
+        assert_eq!(map[&"key1"], 10);
+        assert_eq!(map[&"key2"], 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "key \"missing\" not found")]
+    fn index_operator_missing_key() {
+        let map: HashMap<&str, i32> = HashMap::new();
+        let _ = map[&"missing"];
+    }
+
     #[test]
     fn contains_key() {
         let mut map = HashMap::new();
@@ -396,6 +789,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reserve_avoids_resizes() {
+        let mut map = HashMap::new();
+        map.reserve(10_000);
+        let resizes = map.resize_count();
+        assert_eq!(resizes, 1);
+
+        for i in 0..10_000 {
+            map.insert(i, i);
+        }
+        assert_eq!(map.resize_count(), resizes);
+        assert!(map.load_factor() <= LOAD_FACTOR_THRESHOLD);
+
+        let capacity = map.capacity();
+        map.reserve(0);
+        assert_eq!(map.capacity(), capacity);
+        assert_eq!(map.get(&9_999), Some(&9_999));
+    }
+
+    #[test]
+    fn clear_and_shrink_frees_buckets() {
+        let mut map: HashMap<_, _> = (0..5_000).map(|i| (i, i)).collect();
+        let grown = map.capacity();
+
+        map.clear();
+        assert_eq!(map.capacity(), grown);
+
+        map.extend((0..5_000).map(|i| (i, i)));
+        map.clear_and_shrink();
+        assert_eq!(map.capacity(), DEFAULT_CAPACITY);
+        assert_eq!(map.len(), 0);
+        assert_eq!(map.get(&1), None);
+
+        map.insert(7, 7);
+        assert_eq!(map.get(&7), Some(&7));
+    }
+
+    #[test]
+    fn extend_reserves_from_size_hint() {
+        let mut map = HashMap::new();
+        map.extend((0..10_000).map(|i| (i, i)));
+        assert_eq!(map.resize_count(), 1);
+        assert_eq!(map.len(), 10_000);
+
+        let collected: HashMap<_, _> = (0..10_000).map(|i| (i, i)).collect();
+        assert_eq!(collected.resize_count(), 0);
+        assert_eq!(collected, map);
+
+        // Half the hint is reserved up front once the map is non-empty
+        let resizes = map.resize_count();
+        map.extend((0..10_000).map(|i| (i, i + 1)));
+        assert_eq!(map.resize_count(), resizes + 1);
+        assert_eq!(map.len(), 10_000);
+        assert_eq!(map.get(&5), Some(&6));
+    }
+
+    #[test]
+    fn into_keys_and_values_take_ownership() {
+        use crate::hash::HashSet;
+
+        struct Opaque(u32);
+        #[derive(PartialEq, Eq, Hash)]
+        struct Id(u32);
+
+        let map: HashMap<_, _> = (0..10_000).map(|i| (i, Opaque(i))).collect();
+        let keys: HashSet<u32> = map.into_keys().collect();
+        assert_eq!(keys.len(), 10_000);
+        assert!(keys.contains(&9_999));
+
+        let map: HashMap<_, _> = (0..100).map(|i| (Id(i), i * 2)).collect();
+        let mut values: Vec<_> = map.into_values().collect();
+        values.sort();
+        assert_eq!(values, (0..100).map(|i| i * 2).collect::<Vec<_>>());
+
+        let map: HashMap<_, _> = (0..50).map(|i| (i, Opaque(i))).collect();
+        assert!(map.into_iter().all(|(key, value)| key == value.0));
+    }
+
     #[test]
     fn from_iterator() {
         let pairs = vec![("a", 1), ("b", 2), ("c", 3)];
@@ -433,4 +904,203 @@ mod tests {
         }
         assert_eq!(map.len(), 20);
     }
+
+    #[test]
+    fn get_many_mut_disjoint_keys() {
+        let mut map = HashMap::new();
+        for i in 0..10 {
+            map.insert(i, i * 10);
+        }
+
+        let (a, b) = (0..10)
+            .flat_map(|a| (0..a).map(move |b| (a, b)))
+            .find(|(a, b)| map.hash(a) == map.hash(b))
+            .expect("no two keys share a bucket");
+        let [x, y] = map.get_many_mut([&a, &b]).unwrap();
+        std::mem::swap(x, y);
+        assert_eq!(map.get(&a), Some(&(b * 10)));
+        assert_eq!(map.get(&b), Some(&(a * 10)));
+
+        let (c, d) = (0..10)
+            .flat_map(|c| (0..c).map(move |d| (c, d)))
+            .find(|(c, d)| map.hash(c) != map.hash(d))
+            .unwrap();
+        let [x, y] = map.get_many_mut([&c, &d]).unwrap();
+        *x += 1;
+        *y += 2;
+        assert_eq!(map[&c] % 10, 1);
+        assert_eq!(map[&d] % 10, 2);
+
+        assert!(map.get_many_mut([&1, &2, &1]).is_none());
+        assert!(map.get_many_mut([&1, &42]).is_none());
+        assert_eq!(map.get_many_mut::<0>([]), Some([]));
+    }
+
+    #[test]
+    fn seeded_hasher_iteration_order() {
+        let build = |seed| {
+            let mut map = HashMap::with_seeded_hasher(seed);
+            for i in 0..100 {
+                map.insert(format!("key{i}"), i);
+            }
+            for i in (0..100).step_by(3) {
+                map.remove(&format!("key{i}"));
+            }
+            map
+        };
+
+        let first = build(42);
+        let second = build(42);
+        assert_eq!(
+            first.keys().collect::<Vec<_>>(),
+            second.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(first.len(), 66);
+        assert_eq!(first.get(&"key1".to_string()), Some(&1));
+    }
+
+    #[test]
+    fn bucket_statistics() {
+        #[derive(PartialEq, Eq)]
+        struct Colliding(i32);
+
+        impl Hash for Colliding {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                0.hash(state);
+            }
+        }
+
+        let mut colliding = HashMap::with_capacity(1);
+        for i in 0..20 {
+            colliding.insert(Colliding(i), i);
+        }
+        let histogram = colliding.bucket_histogram();
+        assert_eq!(colliding.max_chain_length(), 20);
+        assert_eq!(colliding.average_chain_length(), 20.0);
+        assert_eq!(histogram.len(), 21);
+        assert_eq!(histogram[20], 1);
+        assert_eq!(histogram[0], colliding.capacity() - 1);
+
+        let mut map = HashMap::new();
+        for i in 0..10_000 {
+            map.insert(i, i);
+        }
+        let histogram = map.bucket_histogram();
+        assert_eq!(histogram.iter().sum::<usize>(), map.capacity());
+        assert_eq!(
+            histogram
+                .iter()
+                .enumerate()
+                .map(|(length, count)| length * count)
+                .sum::<usize>(),
+            map.len()
+        );
+        assert!(map.max_chain_length() <= 8);
+        assert!(map.average_chain_length() < 2.0);
+
+        let empty: HashMap<i32, i32> = HashMap::new();
+        assert_eq!(empty.bucket_histogram(), vec![DEFAULT_CAPACITY]);
+        assert_eq!(empty.average_chain_length(), 0.0);
+    }
+
+    #[test]
+    fn longest_chain_shrinks_after_resize() {
+        let mut map = HashMap::with_capacity(4);
+        for key in 0..4 {
+            map.insert(key, key);
+        }
+        assert_eq!(map.bucket_count(), 4);
+        assert_eq!(map.bucket_count(), map.capacity());
+        let crowded = map.longest_chain();
+        assert!(crowded > 1);
+
+        map.reserve(1_000);
+        assert!(map.bucket_count() > 4);
+        assert!(map.longest_chain() < crowded);
+        assert_eq!(map.longest_chain(), map.max_chain_length());
+    }
+
+    #[test]
+    fn equality_ignores_insertion_order() {
+        let forward: HashMap<_, _> = (0..40).map(|i| (i, i * 2)).collect();
+        let mut backward = HashMap::with_capacity(4);
+        for i in (0..40).rev() {
+            backward.insert(i, i * 2);
+        }
+        assert_eq!(forward, backward);
+
+        backward.insert(7, 0);
+        assert_ne!(forward, backward);
+
+        backward.insert(7, 14);
+        backward.insert(40, 80);
+        assert_ne!(forward, backward);
+    }
+
+    #[test]
+    fn display_sorted_by_key() {
+        let map: HashMap<_, _> = [(3, "c"), (1, "a"), (2, "b")].into_iter().collect();
+        assert_eq!(map.to_string(), "{1: a, 2: b, 3: c}");
+
+        let large: HashMap<u32, u32> = (0..1000).map(|i| (i, i * i)).collect();
+        assert_eq!(large.fmt_limited(3).to_string(), "{0: 0, 1: 1, 2: 4, ...}");
+        assert_eq!(HashMap::<u8, u8>::new().to_string(), "{}");
+    }
+
+    #[test]
+    fn stress_against_std_with_shrinking() {
+        use std::collections::HashMap as StdHashMap;
+
+        let mut map = HashMap::new();
+        let mut oracle = StdHashMap::new();
+        let mut rng = Lcg::new(0x5eed);
+
+        for step in 0..100_000u32 {
+            let key = rng.below(4096) as u32;
+            // Grow for the first half, then mostly remove so the table empties out
+            let insert_percent = if step < 50_000 { 75 } else { 20 };
+
+            if rng.below(100) < insert_percent {
+                assert_eq!(map.insert(key, step), oracle.insert(key, step));
+            } else {
+                assert_eq!(map.remove(&key), oracle.remove(&key));
+            }
+            assert_eq!(map.len(), oracle.len(), "size drifted at step {step}");
+
+            if step % 10_000 == 9_999 {
+                map.shrink_to_fit();
+                assert!(map.load_factor() <= LOAD_FACTOR_THRESHOLD);
+            }
+            if step % 1_000 == 0 || step % 10_000 == 9_999 {
+                let mut entries: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+                let mut expected: Vec<_> = oracle.iter().map(|(&k, &v)| (k, v)).collect();
+                entries.sort_unstable();
+                expected.sort_unstable();
+                assert_eq!(entries, expected, "contents differ at step {step}");
+            }
+        }
+
+        let peak = map.capacity();
+        for key in 0..4096 {
+            assert_eq!(map.remove(&key), oracle.remove(&key));
+        }
+        map.shrink_to_fit();
+        assert!(map.is_empty());
+        assert!(map.capacity() <= peak);
+        assert_eq!(map.capacity(), DEFAULT_CAPACITY);
+        assert_eq!(map.iter().count(), 0);
+    }
+
+    #[test]
+    fn memory_usage_tracks_table_and_entries() {
+        let mut map: HashMap<u64, u64> = HashMap::new();
+        let empty = map.memory_usage_bytes();
+
+        map.extend((0..1_000).map(|i| (i, i)));
+        let full = map.memory_usage_bytes();
+        assert!(full >= empty + 1_000 * std::mem::size_of::<(u64, u64)>());
+
+        map.clear_and_shrink();
+        assert_eq!(map.memory_usage_bytes(), empty);
+    }
 }
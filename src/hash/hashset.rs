@@ -1,7 +1,9 @@
 //! HashSet implementation built on top of HashMap
 
 use crate::hash::HashMap;
-use crate::utils::{Clear, Size};
+use crate::utils::display::{self, DisplayLimited, DISPLAY_LIMIT};
+use crate::utils::rng::Lcg;
+use crate::utils::{Clear, Drain, Size};
 use std::fmt;
 use std::hash::Hash;
 
@@ -41,6 +43,14 @@ where
         }
     }
 
+    /// Creates a set with reproducible iteration order, see
+    /// [`HashMap::with_seeded_hasher`]
+    pub fn with_seeded_hasher(seed: u64) -> Self {
+        Self {
+            map: HashMap::with_seeded_hasher(seed),
+        }
+    }
+
     pub fn insert(&mut self, value: T) -> bool {
         self.map.insert(value, ()).is_none()
     }
@@ -53,7 +63,43 @@ where
         self.map.contains_key(value)
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    /// Returns an arbitrary element, the first one in bucket order
+    pub fn any(&self) -> Option<&T> {
+        self.map.keys().next()
+    }
+
+    /// Removes and returns an arbitrary element without hashing or cloning it
+    ///
+    /// Each call scans for the first occupied bucket, so draining a sparse
+    /// table this way costs more than [`Drain::drain`].
+    pub fn take_any(&mut self) -> Option<T> {
+        self.map.take_any().map(|(value, ())| value)
+    }
+
+    /// Picks up to `k` elements by reservoir sampling, driven by `rng_seed`
+    ///
+    /// The same seed on a set with the same contents and insertion history
+    /// yields the same sample. Returns every element if `k >= len`.
+    pub fn sample(&self, rng_seed: u64, k: usize) -> Vec<&T> {
+        let mut rng = Lcg::new(rng_seed);
+        let mut reservoir = Vec::with_capacity(k.min(self.len()));
+
+        for (seen, value) in self.iter().enumerate() {
+            if seen < k {
+                reservoir.push(value);
+                continue;
+            }
+            let slot = rng.below(seen as u64 + 1) as usize;
+            if slot < k {
+                reservoir[slot] = value;
+            }
+        }
+
+        reservoir
+    }
+
+    /// Returns an iterator over the values in unspecified order
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             map_iter: self.map.keys(),
         }
@@ -63,18 +109,56 @@ where
         self.map.capacity()
     }
 
+    pub fn bucket_count(&self) -> usize {
+        self.map.bucket_count()
+    }
+
+    /// Removes all values and shrinks the bucket table back to the default size
+    pub fn clear_and_shrink(&mut self) {
+        self.map.clear_and_shrink();
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    /// Shrinks the bucket table to fit the current values, see
+    /// [`HashMap::shrink_to_fit`]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit();
+    }
+
+    pub fn resize_count(&self) -> usize {
+        self.map.resize_count()
+    }
+
     pub fn load_factor(&self) -> f64 {
         self.map.load_factor()
     }
 
+    pub fn bucket_histogram(&self) -> Vec<usize> {
+        self.map.bucket_histogram()
+    }
+
+    pub fn max_chain_length(&self) -> usize {
+        self.map.max_chain_length()
+    }
+
+    pub fn longest_chain(&self) -> usize {
+        self.map.longest_chain()
+    }
+
+    pub fn average_chain_length(&self) -> f64 {
+        self.map.average_chain_length()
+    }
+
     pub fn union(&self, other: &HashSet<T>) -> HashSet<T>
     where
         T: Clone,
     {
-        let mut result = self.clone();
-        for item in other.iter() {
-            result.insert(item.clone());
-        }
+        let mut result = HashSet::with_room_for(self.len() + other.len());
+        result.extend(self.iter().cloned());
+        result.extend(other.iter().cloned());
         result
     }
 
@@ -82,9 +166,15 @@ where
     where
         T: Clone,
     {
-        let mut result = HashSet::new();
-        for item in self.iter() {
-            if other.contains(item) {
+        let (smaller, larger) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let mut result = HashSet::with_room_for(smaller.len());
+        for item in smaller.iter() {
+            if larger.contains(item) {
                 result.insert(item.clone());
             }
         }
@@ -95,7 +185,7 @@ where
     where
         T: Clone,
     {
-        let mut result = HashSet::new();
+        let mut result = HashSet::with_room_for(self.len());
         for item in self.iter() {
             if !other.contains(item) {
                 result.insert(item.clone());
@@ -104,10 +194,38 @@ where
         result
     }
 
+    /// Returns the values that are in exactly one of the two sets
+    pub fn symmetric_difference(&self, other: &HashSet<T>) -> HashSet<T>
+    where
+        T: Clone,
+    {
+        let mut result = HashSet::with_room_for(self.len() + other.len());
+        for item in self.iter() {
+            if !other.contains(item) {
+                result.insert(item.clone());
+            }
+        }
+        for item in other.iter() {
+            if !self.contains(item) {
+                result.insert(item.clone());
+            }
+        }
+        result
+    }
+
     pub fn is_subset(&self, other: &HashSet<T>) -> bool {
+        if self.len() > other.len() {
+            return false;
+        }
         self.iter().all(|x| other.contains(x))
     }
 
+    fn with_room_for(len: usize) -> Self {
+        Self {
+            map: HashMap::with_room_for(len),
+        }
+    }
+
     pub fn is_superset(&self, other: &HashSet<T>) -> bool {
         other.is_subset(self)
     }
@@ -139,18 +257,49 @@ impl<T> Clear for HashSet<T> {
     }
 }
 
+impl<T> Drain for HashSet<T> {
+    type Item = T;
+
+    fn drain(&mut self) -> Box<dyn Iterator<Item = T> + '_> {
+        Box::new(self.map.drain().map(|(value, _)| value))
+    }
+}
+
 impl<T> Size for HashSet<T> {
     fn len(&self) -> usize {
         self.map.len()
     }
 }
 
+impl<T: Hash + Eq> PartialEq for HashSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Hash + Eq> Eq for HashSet<T> {}
+
 impl<T: fmt::Debug + Hash + Eq> fmt::Debug for HashSet<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_set().entries(self.iter()).finish()
     }
 }
 
+impl<T: Hash + Eq + Ord + fmt::Display> DisplayLimited for HashSet<T> {
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        let values =
+            display::smallest_sorted(self.iter().collect(), limit.saturating_add(1), Ord::cmp);
+        display::write_list(f, "{", "}", values, limit)
+    }
+}
+
+/// Shows the values sorted, eliding past [`DISPLAY_LIMIT`]
+impl<T: Hash + Eq + Ord + fmt::Display> fmt::Display for HashSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_limit(f, DISPLAY_LIMIT)
+    }
+}
+
 pub struct Iter<'a, T> {
     map_iter: crate::hash::hashmap::Keys<'a, T, ()>,
 }
@@ -163,21 +312,55 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+pub struct IntoIter<T> {
+    keys: crate::hash::hashmap::IntoKeys<T, ()>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys.next()
+    }
+}
+
+impl<T: Hash + Eq> IntoIterator for HashSet<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            keys: self.map.into_keys(),
+        }
+    }
+}
+
 impl<T: Hash + Eq> FromIterator<T> for HashSet<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut set = HashSet::new();
-        for item in iter {
-            set.insert(item);
+        Self {
+            map: iter.into_iter().map(|item| (item, ())).collect(),
         }
-        set
     }
 }
 
+/// Reserves room from the iterator's `size_hint` before inserting, see
+/// [`HashMap`]'s `Extend` impl
 impl<T: Hash + Eq> Extend<T> for HashSet<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for item in iter {
-            self.insert(item);
-        }
+        self.map.extend(iter.into_iter().map(|item| (item, ())));
+    }
+}
+
+/// ```rust
+/// use rust_ds_lib_bee::{HashSet, Size};
+///
+/// let mut set: HashSet<i32> = HashSet::new();
+/// set.extend(&[1, 2, 2, 3]);
+/// assert_eq!(set.len(), 3);
+/// ```
+impl<'a, T: Hash + Eq + Copy + 'a> Extend<&'a T> for HashSet<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
     }
 }
 
@@ -297,4 +480,130 @@ mod tests {
         assert!(set.is_empty());
         assert_eq!(set.len(), 0);
     }
+
+    #[test]
+    fn bucket_statistics() {
+        let set: HashSet<_> = (0..1000).collect();
+        assert_eq!(set.bucket_histogram().iter().sum::<usize>(), set.capacity());
+        assert!(set.max_chain_length() >= 1);
+        assert_eq!(set.longest_chain(), set.max_chain_length());
+        assert_eq!(set.bucket_count(), set.capacity());
+        assert!(set.average_chain_length() >= 1.0);
+    }
+
+    #[test]
+    fn extend_reserves_from_size_hint() {
+        let mut set = HashSet::new();
+        set.extend(0..5_000);
+        assert_eq!(set.resize_count(), 1);
+
+        let collected: HashSet<_> = (0..5_000).collect();
+        assert_eq!(collected.resize_count(), 0);
+        assert_eq!(collected.len(), 5_000);
+    }
+
+    #[test]
+    fn reserve_forwards_to_map() {
+        let mut set = HashSet::with_capacity(4);
+        set.reserve(1_000);
+        let resizes = set.resize_count();
+
+        set.extend(0..1_000);
+        assert_eq!(set.resize_count(), resizes);
+        assert_eq!(set.len(), 1_000);
+
+        set.clear_and_shrink();
+        assert!(set.is_empty());
+        assert!(set.capacity() < 1_000);
+    }
+
+    #[test]
+    fn set_operations_presize_results() {
+        let evens: HashSet<_> = (0..2_000).step_by(2).collect();
+        let small: HashSet<_> = (0..30).collect();
+
+        let union = evens.union(&small);
+        assert_eq!(union.len(), 1_015);
+        assert_eq!(union.resize_count(), 0);
+
+        let intersection = evens.intersection(&small);
+        assert_eq!(intersection.len(), 15);
+        assert_eq!(intersection.resize_count(), 0);
+        assert_eq!(small.intersection(&evens).len(), 15);
+
+        let difference = evens.difference(&small);
+        assert_eq!(difference.len(), 985);
+        assert_eq!(difference.resize_count(), 0);
+
+        let symmetric = evens.symmetric_difference(&small);
+        assert_eq!(symmetric.len(), 1_000);
+        assert!(symmetric.contains(&1) && symmetric.contains(&30) && !symmetric.contains(&2));
+        assert!(symmetric.is_subset(&small.symmetric_difference(&evens)));
+    }
+
+    #[test]
+    fn is_subset_by_size() {
+        let small: HashSet<_> = (0..3).collect();
+        let large: HashSet<_> = (0..10).collect();
+        assert!(small.is_subset(&large));
+        assert!(!large.is_subset(&small));
+        assert!(HashSet::<i32>::new().is_subset(&small));
+    }
+
+    #[test]
+    fn into_iter_moves_values() {
+        let set: HashSet<String> = (0..20).map(|i| i.to_string()).collect();
+        let mut values: Vec<String> = set.into_iter().collect();
+        values.sort_by_key(|value| value.parse::<u32>().unwrap());
+        assert_eq!(values.len(), 20);
+        assert_eq!(values[19], "19");
+    }
+
+    #[test]
+    fn equality_ignores_insertion_order() {
+        let a: HashSet<_> = ["x", "y", "z"].into_iter().collect();
+        let b: HashSet<_> = ["z", "x", "y"].into_iter().collect();
+        assert_eq!(a, b);
+
+        let c: HashSet<_> = ["x", "y"].into_iter().collect();
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn take_any_drains_each_element_once() {
+        let mut set: HashSet<u32> = (0..100).collect();
+        assert!(set.any().is_some_and(|value| *value < 100));
+
+        let mut taken = Vec::new();
+        while let Some(value) = set.take_any() {
+            assert!(!set.contains(&value));
+            taken.push(value);
+        }
+
+        taken.sort_unstable();
+        assert_eq!(taken, (0..100).collect::<Vec<_>>());
+        assert!(set.is_empty());
+        assert_eq!(set.any(), None);
+    }
+
+    #[test]
+    fn sample_is_reproducible() {
+        let set: HashSet<u32> = (0..500).collect();
+
+        let first = set.sample(42, 10);
+        assert_eq!(first.len(), 10);
+        assert_eq!(first, set.sample(42, 10));
+        assert_ne!(first, set.sample(7, 10));
+
+        let distinct: HashSet<_> = first.iter().copied().collect();
+        assert_eq!(distinct.len(), 10);
+        assert_eq!(set.sample(1, 600).len(), 500);
+    }
+
+    #[test]
+    fn display_sorted() {
+        let set: HashSet<_> = [30, 10, 20].into_iter().collect();
+        assert_eq!(set.to_string(), "{10, 20, 30}");
+        assert_eq!(set.fmt_limited(1).to_string(), "{10, ...}");
+    }
 }
@@ -1,7 +1,9 @@
 pub mod bloom_filter;
 pub mod hashmap;
 pub mod hashset;
+pub mod open_hashmap;
 
 pub use bloom_filter::BloomFilter;
 pub use hashmap::HashMap;
 pub use hashset::HashSet;
+pub use open_hashmap::OpenHashMap;
@@ -0,0 +1,451 @@
+//! HashMap variant using open addressing with linear probing
+
+use crate::utils::{Clear, Size};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+const DEFAULT_CAPACITY: usize = 16;
+
+const LOAD_FACTOR_THRESHOLD: f64 = 0.75;
+
+#[derive(Debug, Clone)]
+enum Slot<K, V> {
+    Empty,
+    Tombstone,
+    Occupied(K, V),
+}
+
+/// A hash map storing entries inline in a flat slot table
+///
+/// Unlike [`HashMap`](crate::HashMap), which chains boxed entries per bucket,
+/// `OpenHashMap` resolves collisions by linear probing. Removed entries leave
+/// tombstones so probe sequences stay intact; they are purged on the next resize.
+/// Tombstones count towards the load factor.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::hash::OpenHashMap;
+///
+/// let mut map = OpenHashMap::new();
+/// map.insert("key", 1);
+/// assert_eq!(map.get(&"key"), Some(&1));
+/// assert_eq!(map.remove(&"key"), Some(1));
+/// ```
+pub struct OpenHashMap<K, V> {
+    slots: Vec<Slot<K, V>>,
+    size: usize,
+    tombstones: usize,
+}
+
+impl<K, V> OpenHashMap<K, V>
+where
+    K: Hash + Eq,
+{
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            slots: (0..capacity).map(|_| Slot::Empty).collect(),
+            size: 0,
+            tombstones: 0,
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.should_resize() {
+            self.resize();
+        }
+
+        let mut index = self.hash(&key);
+        let mut first_tombstone = None;
+
+        loop {
+            match &mut self.slots[index] {
+                Slot::Empty => break,
+                Slot::Tombstone => {
+                    first_tombstone.get_or_insert(index);
+                }
+                Slot::Occupied(existing, existing_value) => {
+                    if *existing == key {
+                        return Some(std::mem::replace(existing_value, value));
+                    }
+                }
+            }
+            index = (index + 1) % self.slots.len();
+        }
+
+        if let Some(tombstone) = first_tombstone {
+            index = tombstone;
+            self.tombstones -= 1;
+        }
+
+        self.slots[index] = Slot::Occupied(key, value);
+        self.size += 1;
+        None
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match &self.slots[self.find(key)?] {
+            Slot::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.find(key)?;
+        match &mut self.slots[index] {
+            Slot::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.find(key)?;
+        match std::mem::replace(&mut self.slots[index], Slot::Tombstone) {
+            Slot::Occupied(_, value) => {
+                self.size -= 1;
+                self.tombstones += 1;
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: self.slots.iter(),
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    pub fn load_factor(&self) -> f64 {
+        (self.size + self.tombstones) as f64 / self.slots.len() as f64
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Grows the slot table so that `additional` more entries fit without
+    /// exceeding the load factor, rehashing at most once
+    pub fn reserve(&mut self, additional: usize) {
+        let needed =
+            (self.size.saturating_add(additional) as f64 / LOAD_FACTOR_THRESHOLD).ceil() as usize;
+        if needed > self.slots.len() {
+            self.rehash(needed);
+        }
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        let start = self.hash(key);
+        let mut index = start;
+
+        loop {
+            match &self.slots[index] {
+                Slot::Empty => return None,
+                Slot::Occupied(existing, _) if existing == key => return Some(index),
+                _ => {}
+            }
+            index = (index + 1) % self.slots.len();
+            if index == start {
+                return None;
+            }
+        }
+    }
+
+    fn hash(&self, key: &K) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() as usize) % self.slots.len()
+    }
+
+    fn should_resize(&self) -> bool {
+        (self.size + self.tombstones + 1) as f64 / self.slots.len() as f64 > LOAD_FACTOR_THRESHOLD
+    }
+
+    fn resize(&mut self) {
+        // Only grow when live entries need the room; otherwise just purge tombstones
+        let new_capacity = if (self.size + 1) as f64 > self.slots.len() as f64 / 2.0 {
+            self.slots.len() * 2
+        } else {
+            self.slots.len()
+        };
+        self.rehash(new_capacity);
+    }
+
+    fn rehash(&mut self, new_capacity: usize) {
+        let old_slots = std::mem::replace(
+            &mut self.slots,
+            (0..new_capacity).map(|_| Slot::Empty).collect(),
+        );
+        self.size = 0;
+        self.tombstones = 0;
+
+        for slot in old_slots {
+            if let Slot::Occupied(key, value) = slot {
+                self.insert(key, value);
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for OpenHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Clear for OpenHashMap<K, V> {
+    fn clear(&mut self) {
+        for slot in &mut self.slots {
+            *slot = Slot::Empty;
+        }
+        self.size = 0;
+        self.tombstones = 0;
+    }
+}
+
+impl<K, V> Size for OpenHashMap<K, V> {
+    fn len(&self) -> usize {
+        self.size
+    }
+}
+
+impl<K: fmt::Debug + Hash + Eq, V: fmt::Debug> fmt::Debug for OpenHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    slots: std::slice::Iter<'a, Slot<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.find_map(|slot| match slot {
+            Slot::Occupied(key, value) => Some((key, value)),
+            _ => None,
+        })
+    }
+}
+
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for OpenHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OpenHashMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+/// Reserves room from the iterator's `size_hint` before inserting, halving
+/// the hint when the map already holds entries
+impl<K: Hash + Eq, V> Extend<(K, V)> for OpenHashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let hint = iter.size_hint().0;
+        self.reserve(if self.is_empty() {
+            hint
+        } else {
+            (hint + 1) / 2
+        });
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_map_is_empty() {
+        let map: OpenHashMap<&str, i32> = OpenHashMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.len(), 0);
+        assert_eq!(map.capacity(), DEFAULT_CAPACITY);
+    }
+
+    #[test]
+    fn insert_and_get() {
+        let mut map = OpenHashMap::new();
+
+        assert_eq!(map.insert("key1", "value1"), None);
+        assert_eq!(map.insert("key2", "value2"), None);
+        assert_eq!(map.insert("key1", "new_value"), Some("value1"));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&"key1"), Some(&"new_value"));
+        assert_eq!(map.get(&"key2"), Some(&"value2"));
+        assert_eq!(map.get(&"key3"), None);
+    }
+
+    #[test]
+    fn contains_key() {
+        let mut map = OpenHashMap::new();
+        map.insert("key1", "value1");
+        map.insert("key2", "value2");
+
+        assert!(map.contains_key(&"key1"));
+        assert!(map.contains_key(&"key2"));
+        assert!(!map.contains_key(&"key3"));
+    }
+
+    #[test]
+    fn remove() {
+        let mut map = OpenHashMap::new();
+        map.insert("key1", "value1");
+        map.insert("key2", "value2");
+        map.insert("key3", "value3");
+
+        assert_eq!(map.remove(&"key2"), Some("value2"));
+        assert_eq!(map.len(), 2);
+        assert!(!map.contains_key(&"key2"));
+
+        assert_eq!(map.remove(&"key4"), None);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut map = OpenHashMap::new();
+        map.insert("key1", 10);
+
+        if let Some(value) = map.get_mut(&"key1") {
+            *value += 5;
+        }
+
+        assert_eq!(map.get(&"key1"), Some(&15));
+    }
+
+    #[test]
+    fn iter() {
+        let mut map = OpenHashMap::new();
+        map.insert("key1", "value1");
+        map.insert("key2", "value2");
+        map.insert("key3", "value3");
+
+        let mut pairs: Vec<_> = map.iter().collect();
+        pairs.sort_by_key(|(k, _)| *k);
+
+        assert_eq!(
+            pairs,
+            vec![
+                (&"key1", &"value1"),
+                (&"key2", &"value2"),
+                (&"key3", &"value3"),
+            ]
+        );
+    }
+
+    #[test]
+    fn resize_on_load_factor() {
+        let mut map = OpenHashMap::with_capacity(4);
+
+        for i in 0..10 {
+            map.insert(i, i * 10);
+        }
+
+        assert!(map.capacity() > 4);
+        assert!(map.load_factor() <= LOAD_FACTOR_THRESHOLD);
+        assert_eq!(map.len(), 10);
+
+        for i in 0..10 {
+            assert_eq!(map.get(&i), Some(&(i * 10)));
+        }
+    }
+
+    #[test]
+    fn extend_reserves_from_size_hint() {
+        let map: OpenHashMap<_, _> = (0..3_000).map(|i| (i, i)).collect();
+        // Doubling from the default would have produced a power of two
+        assert_eq!(map.capacity(), 4_000);
+        assert_eq!(map.len(), 3_000);
+        assert_eq!(map.get(&2_999), Some(&2_999));
+    }
+
+    #[test]
+    fn probing_past_tombstones() {
+        let mut map = OpenHashMap::with_capacity(1);
+
+        for i in 0..20 {
+            map.insert(i, i * 100);
+        }
+        for i in (0..20).step_by(2) {
+            assert_eq!(map.remove(&i), Some(i * 100));
+        }
+
+        for i in 0..20 {
+            let expected = i * 100;
+            assert_eq!(map.get(&i), (i % 2 == 1).then_some(&expected));
+        }
+
+        assert_eq!(map.insert(1, 1), Some(100));
+        assert_eq!(map.insert(0, 0), None);
+        assert_eq!(map.len(), 11);
+    }
+
+    #[test]
+    fn churn_does_not_grow_unbounded() {
+        let mut map = OpenHashMap::with_capacity(16);
+
+        for i in 0..1000 {
+            map.insert(i, i);
+            map.remove(&i);
+        }
+
+        assert!(map.is_empty());
+        assert_eq!(map.capacity(), 16);
+    }
+
+    #[test]
+    fn matches_chaining_map() {
+        let mut open = OpenHashMap::new();
+        let mut chained = crate::hash::HashMap::new();
+
+        for i in 0..500 {
+            open.insert(i % 97, i);
+            chained.insert(i % 97, i);
+            if i % 5 == 0 {
+                assert_eq!(open.remove(&(i % 13)), chained.remove(&(i % 13)));
+            }
+        }
+
+        assert_eq!(open.len(), chained.len());
+        for (key, value) in chained.iter() {
+            assert_eq!(open.get(key), Some(value));
+        }
+    }
+
+    #[test]
+    fn clear() {
+        let mut map = OpenHashMap::new();
+        map.insert("key1", "value1");
+        map.insert("key2", "value2");
+        map.remove(&"key2");
+
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.load_factor(), 0.0);
+        assert!(!map.contains_key(&"key1"));
+    }
+}
@@ -1,16 +1,21 @@
-use crate::utils::{Clear, Peek, PeekMut, Size};
+use crate::utils::display::{self, DisplayLimited, DISPLAY_LIMIT};
+use crate::utils::{Clear, Drain, Peek, PeekMut, Size};
 use std::cmp::Ordering;
 use std::fmt;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HeapType {
     Max,
     Min,
 }
 
+#[derive(Clone)]
 pub struct BinaryHeap<T> {
     data: Vec<T>,
     heap_type: HeapType,
+    // Insertion counters parallel to `data`, present only in stable mode
+    sequences: Option<Vec<u64>>,
+    next_sequence: u64,
 }
 
 impl<T: Ord> BinaryHeap<T> {
@@ -22,6 +27,8 @@ impl<T: Ord> BinaryHeap<T> {
         Self {
             data: Vec::new(),
             heap_type: HeapType::Max,
+            sequences: None,
+            next_sequence: 0,
         }
     }
 
@@ -29,6 +36,8 @@ impl<T: Ord> BinaryHeap<T> {
         Self {
             data: Vec::new(),
             heap_type: HeapType::Min,
+            sequences: None,
+            next_sequence: 0,
         }
     }
 
@@ -36,10 +45,31 @@ impl<T: Ord> BinaryHeap<T> {
         Self {
             data: Vec::with_capacity(capacity),
             heap_type: HeapType::Max,
+            sequences: None,
+            next_sequence: 0,
         }
     }
 
+    /// Creates a max-heap that pops equal elements in insertion order
+    ///
+    /// Each pushed element is tagged with an increasing counter used to break
+    /// ties, at the cost of one extra `u64` per element.
+    pub fn new_stable() -> Self {
+        Self {
+            sequences: Some(Vec::new()),
+            ..Self::max_heap()
+        }
+    }
+
+    pub fn is_stable(&self) -> bool {
+        self.sequences.is_some()
+    }
+
     pub fn push(&mut self, item: T) {
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.push(self.next_sequence);
+            self.next_sequence += 1;
+        }
         self.data.push(item);
         self.sift_up(self.data.len() - 1);
     }
@@ -50,7 +80,10 @@ impl<T: Ord> BinaryHeap<T> {
         }
 
         let last_idx = self.data.len() - 1;
-        self.data.swap(0, last_idx);
+        self.swap(0, last_idx);
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.pop();
+        }
         let result = self.data.pop();
 
         if !self.data.is_empty() {
@@ -60,25 +93,174 @@ impl<T: Ord> BinaryHeap<T> {
         result
     }
 
+    /// Consumes both heaps and returns one containing all their elements,
+    /// heapifying the combined data once in O(n + m).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heaps have different [`HeapType`]s.
+    ///
+    /// The result is stable if `self` is, with the elements of `other`
+    /// ordered after those of `self` among equals.
+    pub fn meld(mut self, mut other: BinaryHeap<T>) -> BinaryHeap<T> {
+        assert_eq!(
+            self.heap_type, other.heap_type,
+            "cannot meld heaps of different types"
+        );
+
+        match self.sequences.as_mut() {
+            Some(sequences) => {
+                let offset = self.next_sequence;
+                match other.sequences.take() {
+                    Some(theirs) => sequences.extend(theirs.into_iter().map(|seq| seq + offset)),
+                    None => sequences.extend(offset..offset + other.data.len() as u64),
+                }
+                self.next_sequence = offset + other.next_sequence.max(other.data.len() as u64);
+            }
+            None => {
+                other.sequences = None;
+                if other.data.len() > self.data.len() {
+                    std::mem::swap(&mut self, &mut other);
+                }
+            }
+        }
+        if other.data.is_empty() {
+            return self;
+        }
+
+        self.data.append(&mut other.data);
+        self.heapify();
+        self
+    }
+
+    /// Empties the heap, keeping its allocation, and switches it to `heap_type`
+    pub fn reset(&mut self, heap_type: HeapType) {
+        self.clear();
+        self.heap_type = heap_type;
+    }
+
     pub fn capacity(&self) -> usize {
         self.data.capacity()
     }
 
+    /// Reserves room for at least `additional` more elements
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.reserve(additional);
+        }
+    }
+
+    /// Releases spare capacity, keeping the elements and their order
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.shrink_to_fit();
+        }
+    }
+
+    /// Releases spare capacity down to `min_capacity` or the length,
+    /// whichever is larger
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.data.shrink_to(min_capacity);
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.shrink_to(min_capacity);
+        }
+    }
+
+    /// Estimates the bytes held by the heap, counting allocated capacity
+    /// and stable-mode counters but not memory owned by the elements
+    pub fn memory_usage_bytes(&self) -> usize {
+        let sequences = self.sequences.as_ref().map_or(0, |sequences| {
+            sequences.capacity() * std::mem::size_of::<u64>()
+        });
+        std::mem::size_of::<Self>() + self.data.capacity() * std::mem::size_of::<T>() + sequences
+    }
+
     pub fn heap_type(&self) -> &HeapType {
         &self.heap_type
     }
 
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
         self.data.iter()
     }
 
+    /// Returns the backing array in heap order for bulk in-place updates
+    ///
+    /// Mutating elements in a way that changes their ordering breaks the
+    /// heap invariant; call [`rebuild`](Self::rebuild) before using the heap
+    /// again.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Checks that every parent is ordered before its children for the
+    /// configured [`HeapType`]
+    pub fn is_valid_heap(&self) -> bool {
+        (1..self.data.len()).all(|idx| self.compare(idx, (idx - 1) / 2) != Ordering::Greater)
+    }
+
+    /// Restores the heap invariant in O(n) after external mutation
+    pub fn rebuild(&mut self) {
+        self.heapify();
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, restoring the
+    /// heap in a single O(n) rebuild
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|item| !f(item));
+    }
+
+    /// Removes and returns every element for which `pred` returns `true`,
+    /// in unspecified order, then rebuilds the heap once in O(n)
+    ///
+    /// A stable heap keeps the insertion order of the survivors.
+    pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let len = self.data.len();
+        let data = std::mem::replace(&mut self.data, Vec::with_capacity(len));
+        let sequences = self.sequences.take();
+        let mut kept_sequences = sequences
+            .as_ref()
+            .map(|seqs| Vec::with_capacity(seqs.len()));
+        let mut removed = Vec::new();
+
+        for (idx, item) in data.into_iter().enumerate() {
+            if pred(&item) {
+                removed.push(item);
+                continue;
+            }
+            if let (Some(kept), Some(seqs)) = (kept_sequences.as_mut(), sequences.as_ref()) {
+                kept.push(seqs[idx]);
+            }
+            self.data.push(item);
+        }
+
+        self.sequences = kept_sequences;
+        if !removed.is_empty() {
+            self.heapify();
+        }
+        removed
+    }
+
+    pub(crate) fn sift_down_top(&mut self) {
+        if !self.data.is_empty() {
+            self.sift_down(0);
+        }
+    }
+
+    fn heapify(&mut self) {
+        for idx in (0..self.data.len() / 2).rev() {
+            self.sift_down(idx);
+        }
+    }
+
     fn sift_up(&mut self, mut idx: usize) {
         while idx > 0 {
             let parent_idx = (idx - 1) / 2;
             if self.compare(idx, parent_idx) != Ordering::Greater {
                 break;
             }
-            self.data.swap(idx, parent_idx);
+            self.swap(idx, parent_idx);
             idx = parent_idx;
         }
     }
@@ -105,15 +287,27 @@ impl<T: Ord> BinaryHeap<T> {
                 break;
             }
 
-            self.data.swap(idx, largest);
+            self.swap(idx, largest);
             idx = largest;
         }
     }
 
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.swap(i, j);
+        }
+    }
+
     fn compare(&self, i: usize, j: usize) -> Ordering {
-        match self.heap_type {
+        let ordering = match self.heap_type {
             HeapType::Max => self.data[i].cmp(&self.data[j]),
             HeapType::Min => self.data[j].cmp(&self.data[i]),
+        };
+        // In stable mode the earlier insertion ranks higher among equals
+        match &self.sequences {
+            Some(sequences) => ordering.then_with(|| sequences[j].cmp(&sequences[i])),
+            None => ordering,
         }
     }
 
@@ -124,6 +318,36 @@ impl<T: Ord> BinaryHeap<T> {
         }
         result
     }
+
+    /// Lazily pops the elements in pop order, leaving the heap empty with its
+    /// capacity retained
+    ///
+    /// Dropping the iterator early still removes the remaining elements.
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, T> {
+        DrainSorted { heap: self }
+    }
+
+    /// Returns the elements in pop order without modifying the heap
+    pub fn to_sorted_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.pop_order(self.data.len())
+            .into_iter()
+            .map(|idx| self.data[idx].clone())
+            .collect()
+    }
+
+    /// Returns the indices of the first `count` elements in pop order
+    fn pop_order(&self, count: usize) -> Vec<usize> {
+        display::smallest_sorted((0..self.data.len()).collect(), count, |&a, &b| {
+            self.compare(b, a)
+        })
+    }
+
+    pub fn sorted_iter(self) -> std::vec::IntoIter<T> {
+        self.into_sorted_vec().into_iter()
+    }
 }
 
 impl<T: Ord> Default for BinaryHeap<T> {
@@ -135,6 +359,21 @@ impl<T: Ord> Default for BinaryHeap<T> {
 impl<T> Clear for BinaryHeap<T> {
     fn clear(&mut self) {
         self.data.clear();
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.clear();
+        }
+    }
+}
+
+impl<T> Drain for BinaryHeap<T> {
+    type Item = T;
+
+    /// Yields the elements in arbitrary order, keeping the allocation
+    fn drain(&mut self) -> Box<dyn Iterator<Item = T> + '_> {
+        if let Some(sequences) = self.sequences.as_mut() {
+            sequences.clear();
+        }
+        Box::new(self.data.drain(..))
     }
 }
 
@@ -159,30 +398,105 @@ impl<T> PeekMut<T> for BinaryHeap<T> {
 impl<T: Ord> FromIterator<T> for BinaryHeap<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut heap = BinaryHeap::new();
-        for item in iter {
-            heap.push(item);
-        }
+        heap.extend(iter);
+        heap
+    }
+}
+
+/// Builds a max-heap from the vector in O(n)
+impl<T: Ord> From<Vec<T>> for BinaryHeap<T> {
+    fn from(data: Vec<T>) -> Self {
+        let mut heap = Self {
+            data,
+            heap_type: HeapType::Max,
+            sequences: None,
+            next_sequence: 0,
+        };
+        heap.heapify();
         heap
     }
 }
 
+/// Reserves room from the iterator's `size_hint` before pushing
 impl<T: Ord> Extend<T> for BinaryHeap<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
         for item in iter {
             self.push(item);
         }
     }
 }
 
+/// ```rust
+/// use rust_ds_lib_bee::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// heap.extend(&[3, 1, 2]);
+/// assert_eq!(heap.pop(), Some(3));
+/// ```
+impl<'a, T: Ord + Copy + 'a> Extend<&'a T> for BinaryHeap<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
 impl<T: fmt::Debug> fmt::Debug for BinaryHeap<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("BinaryHeap")
             .field("data", &self.data)
             .field("heap_type", &self.heap_type)
+            .field("stable", &self.sequences.is_some())
             .finish()
     }
 }
 
+impl<T: Ord + fmt::Display> DisplayLimited for BinaryHeap<T> {
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        let order = self.pop_order(limit.saturating_add(1));
+        display::write_list(
+            f,
+            "[",
+            "]",
+            order.into_iter().map(|idx| &self.data[idx]),
+            limit,
+        )
+    }
+}
+
+/// Shows the elements in pop order, eliding past [`DISPLAY_LIMIT`]
+impl<T: Ord + fmt::Display> fmt::Display for BinaryHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_limit(f, DISPLAY_LIMIT)
+    }
+}
+
+/// Iterator returned by [`BinaryHeap::drain_sorted`]
+pub struct DrainSorted<'a, T: Ord> {
+    heap: &'a mut BinaryHeap<T>,
+}
+
+impl<T: Ord> Iterator for DrainSorted<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Ord> ExactSizeIterator for DrainSorted<'_, T> {}
+
+impl<T: Ord> Drop for DrainSorted<'_, T> {
+    fn drop(&mut self) {
+        self.heap.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,6 +509,23 @@ mod tests {
         assert_eq!(heap.peek(), None);
     }
 
+    #[test]
+    fn drain_sorted_empties_on_drop() {
+        let mut heap: BinaryHeap<i32> = (0..10).collect();
+        let capacity = heap.capacity();
+
+        let first: Vec<i32> = heap.drain_sorted().take(3).collect();
+        assert_eq!(first, vec![9, 8, 7]);
+        assert!(heap.is_empty());
+        assert_eq!(heap.capacity(), capacity);
+
+        let mut min_heap = BinaryHeap::min_heap();
+        min_heap.extend([5, 3, 8, 1]);
+        let drain = min_heap.drain_sorted();
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.collect::<Vec<_>>(), vec![1, 3, 5, 8]);
+    }
+
     #[test]
     fn max_heap_ordering() {
         let mut heap = BinaryHeap::max_heap();
@@ -254,6 +585,12 @@ mod tests {
         assert_eq!(sorted, vec![5, 4, 3, 1, 1]);
     }
 
+    #[test]
+    fn sorted_iter() {
+        let heap: BinaryHeap<_> = [3, 1, 4, 1, 5].into_iter().collect();
+        assert!(heap.sorted_iter().eq([5, 4, 3, 1, 1]));
+    }
+
     #[test]
     fn from_iterator() {
         let values = vec![3, 1, 4, 1, 5, 9];
@@ -270,6 +607,18 @@ mod tests {
         assert!(heap.is_empty());
     }
 
+    #[test]
+    fn extend_reserves_from_size_hint() {
+        let heap: BinaryHeap<i32> = (0..1_000).collect();
+        assert_eq!(heap.capacity(), 1_000);
+        assert_eq!(heap.peek(), Some(&999));
+
+        let mut stable = BinaryHeap::new_stable();
+        stable.extend(0..100);
+        assert_eq!(stable.capacity(), 100);
+        assert_eq!(stable.pop(), Some(99));
+    }
+
     #[test]
     fn heap_property_maintained() {
         let mut heap = BinaryHeap::max_heap();
@@ -296,4 +645,216 @@ mod tests {
         assert!(heap.is_empty());
         assert_eq!(heap.len(), 0);
     }
+
+    #[test]
+    fn meld_max_heaps() {
+        let left: BinaryHeap<_> = [3, 9, 1, 7].into_iter().collect();
+        let right: BinaryHeap<_> = [8, 2, 6, 4, 5].into_iter().collect();
+
+        let melded = left.meld(right);
+        assert_eq!(melded.len(), 9);
+        assert_eq!(melded.into_sorted_vec(), vec![9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn meld_with_empty_heap() {
+        let mut heap = BinaryHeap::min_heap();
+        for i in [5, 2, 8] {
+            heap.push(i);
+        }
+
+        let melded = heap.meld(BinaryHeap::min_heap());
+        assert_eq!(melded.heap_type(), &HeapType::Min);
+        assert_eq!(melded.into_sorted_vec(), vec![2, 5, 8]);
+
+        let mut heap = BinaryHeap::min_heap();
+        heap.push(1);
+        let melded = BinaryHeap::min_heap().meld(heap);
+        assert_eq!(melded.into_sorted_vec(), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot meld heaps of different types")]
+    fn meld_mismatched_types() {
+        let _ = BinaryHeap::<i32>::max_heap().meld(BinaryHeap::min_heap());
+    }
+
+    #[test]
+    fn reset_reuses_allocation() {
+        let mut heap = BinaryHeap::max_heap();
+        heap.extend(0..100);
+        let capacity = heap.capacity();
+
+        heap.clear();
+        assert_eq!(heap.capacity(), capacity);
+
+        heap.extend(0..100);
+        heap.reset(HeapType::Min);
+        assert!(heap.is_empty());
+        assert_eq!(heap.heap_type(), &HeapType::Min);
+
+        heap.extend([5, 1, 9, 3]);
+        assert_eq!(heap.capacity(), capacity);
+        assert_eq!(heap.into_sorted_vec(), vec![1, 3, 5, 9]);
+    }
+
+    #[test]
+    fn from_vec_heapifies() {
+        let mut heap = BinaryHeap::from(vec![4, 9, 1, 7, 3, 9]);
+        assert_eq!(heap.len(), 6);
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![9, 9, 7, 4, 3, 1]);
+    }
+
+    #[test]
+    fn to_sorted_vec_leaves_heap_intact() {
+        let values = [5, 1, 8, 3, 8, 2, 9];
+        for mut heap in [BinaryHeap::max_heap(), BinaryHeap::min_heap()] {
+            heap.extend(values);
+
+            let sorted = heap.to_sorted_vec();
+            assert_eq!(sorted, heap.clone().into_sorted_vec());
+            assert_eq!(heap.len(), values.len());
+            assert_eq!(heap.peek(), sorted.first());
+        }
+    }
+
+    #[test]
+    fn rebuild_repairs_external_mutation() {
+        for mut heap in [BinaryHeap::max_heap(), BinaryHeap::min_heap()] {
+            heap.extend([10, 20, 30, 40, 50, 60]);
+            assert!(heap.is_valid_heap());
+
+            for value in heap.as_mut_slice() {
+                *value = 100 - *value;
+            }
+            assert!(!heap.is_valid_heap());
+
+            heap.rebuild();
+            assert!(heap.is_valid_heap());
+            let expected = match heap.heap_type() {
+                HeapType::Max => vec![90, 80, 70, 60, 50, 40],
+                HeapType::Min => vec![40, 50, 60, 70, 80, 90],
+            };
+            assert_eq!(heap.into_sorted_vec(), expected);
+        }
+    }
+
+    #[test]
+    fn stable_heap_pops_equals_in_insertion_order() {
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        struct Job(u8, char);
+
+        impl PartialOrd for Job {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Job {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut heap = BinaryHeap::new_stable();
+        for (priority, tag) in [(5, 'a'), (1, 'x'), (5, 'b'), (9, 'z'), (5, 'c'), (5, 'd')] {
+            heap.push(Job(priority, tag));
+        }
+        assert!(heap.is_stable());
+
+        let expected = "zabcdx";
+        let sorted: String = heap.to_sorted_vec().iter().map(|job| job.1).collect();
+        assert_eq!(sorted, expected);
+
+        let mut popped = String::new();
+        while let Some(job) = heap.pop() {
+            popped.push(job.1);
+        }
+        assert_eq!(popped, expected);
+    }
+
+    #[test]
+    fn display_in_pop_order() {
+        let mut max = BinaryHeap::max_heap();
+        let mut min = BinaryHeap::min_heap();
+        max.extend([3, 9, 1, 4]);
+        min.extend([3, 9, 1, 4]);
+
+        assert_eq!(max.to_string(), "[9, 4, 3, 1]");
+        assert_eq!(min.fmt_limited(2).to_string(), "[1, 3, ...]");
+        assert_eq!(max.len(), 4);
+    }
+
+    #[test]
+    fn shrink_after_burst() {
+        let mut heap = BinaryHeap::min_heap();
+        heap.extend(0..1_000_000u32);
+        while heap.len() > 100 {
+            heap.pop();
+        }
+        let burst_bytes = heap.memory_usage_bytes();
+        assert!(heap.capacity() >= 1_000_000);
+
+        heap.shrink_to(500);
+        assert!(heap.capacity() >= 500 && heap.capacity() < 1_000_000);
+
+        heap.shrink_to_fit();
+        assert!(heap.capacity() < 1_000);
+        assert!(heap.memory_usage_bytes() < burst_bytes);
+        assert!(heap.is_valid_heap());
+        assert_eq!(
+            heap.into_sorted_vec(),
+            (999_900..1_000_000).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn retain_and_extract_if_rebuild_once() {
+        let mut heap = BinaryHeap::min_heap();
+        heap.extend((0..50).rev());
+        heap.retain(|&value| value % 5 != 0);
+        assert_eq!(heap.len(), 40);
+        assert!(heap.is_valid_heap());
+
+        let mut removed = heap.extract_if(|&value| value > 30);
+        removed.sort();
+        assert_eq!(
+            removed,
+            vec![31, 32, 33, 34, 36, 37, 38, 39, 41, 42, 43, 44, 46, 47, 48, 49]
+        );
+        assert_eq!(heap.pop(), Some(1));
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Job(u8, char);
+
+        impl PartialOrd for Job {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Job {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut stable = BinaryHeap::new_stable();
+        for (priority, tag) in [(5, 'a'), (1, 'x'), (5, 'b'), (9, 'z'), (5, 'c'), (5, 'd')] {
+            stable.push(Job(priority, tag));
+        }
+        let removed = stable.extract_if(|job| job.1 == 'b' || job.1 == 'z');
+        assert_eq!(removed.len(), 2);
+        stable.push(Job(5, 'e'));
+
+        let order: String = std::iter::from_fn(|| stable.pop())
+            .map(|job| job.1)
+            .collect();
+        assert_eq!(order, "acdex");
+    }
 }
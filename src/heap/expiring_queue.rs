@@ -0,0 +1,169 @@
+//! Queue releasing items once their deadline has been reached
+
+use crate::heap::BinaryHeap;
+use crate::utils::{Clear, Peek, Size};
+use std::cmp::Ordering;
+use std::fmt;
+
+struct Scheduled<T, I> {
+    item: T,
+    deadline: I,
+    sequence: u64,
+}
+
+impl<T, I: Ord> PartialEq for Scheduled<T, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, I: Ord> Eq for Scheduled<T, I> {}
+
+impl<T, I: Ord> PartialOrd for Scheduled<T, I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, I: Ord> Ord for Scheduled<T, I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deadline
+            .cmp(&other.deadline)
+            .then(self.sequence.cmp(&other.sequence))
+    }
+}
+
+/// A min-heap of items keyed by deadline, for timer-style scheduling
+///
+/// Items with equal deadlines are released in insertion order.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::heap::ExpiringQueue;
+///
+/// let mut timers = ExpiringQueue::new();
+/// timers.push("flush", 10);
+/// timers.push("ping", 5);
+///
+/// assert_eq!(timers.pop_ready(&4), None);
+/// assert_eq!(timers.pop_ready(&7), Some("ping"));
+/// assert_eq!(timers.next_deadline(), Some(&10));
+/// ```
+pub struct ExpiringQueue<T, I> {
+    heap: BinaryHeap<Scheduled<T, I>>,
+    next_sequence: u64,
+}
+
+impl<T, I: Ord> ExpiringQueue<T, I> {
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::min_heap(),
+            next_sequence: 0,
+        }
+    }
+
+    pub fn push(&mut self, item: T, deadline: I) {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.heap.push(Scheduled {
+            item,
+            deadline,
+            sequence,
+        });
+    }
+
+    /// Removes the item with the earliest deadline if that deadline is at or before `now`
+    pub fn pop_ready(&mut self, now: &I) -> Option<T> {
+        if self.next_deadline()? > now {
+            return None;
+        }
+        self.heap.pop().map(|scheduled| scheduled.item)
+    }
+
+    pub fn next_deadline(&self) -> Option<&I> {
+        self.heap.peek().map(|scheduled| &scheduled.deadline)
+    }
+
+    /// Removes every item whose deadline is strictly before `cutoff`, earliest first
+    pub fn expire_before(&mut self, cutoff: &I) -> Vec<T> {
+        let mut expired = Vec::new();
+        while self
+            .next_deadline()
+            .is_some_and(|deadline| deadline < cutoff)
+        {
+            if let Some(scheduled) = self.heap.pop() {
+                expired.push(scheduled.item);
+            }
+        }
+        expired
+    }
+}
+
+impl<T, I: Ord> Default for ExpiringQueue<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, I> Clear for ExpiringQueue<T, I> {
+    fn clear(&mut self) {
+        self.heap.clear();
+    }
+}
+
+impl<T, I> Size for ExpiringQueue<T, I> {
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+impl<T: fmt::Debug, I: fmt::Debug> fmt::Debug for ExpiringQueue<T, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpiringQueue")
+            .field("len", &self.heap.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn items_become_ready_at_their_deadline() {
+        let mut queue = ExpiringQueue::new();
+        let deadlines = [7u64, 3, 12, 3, 9];
+        for (id, &deadline) in deadlines.iter().enumerate() {
+            queue.push(id, deadline);
+        }
+
+        let mut released = Vec::new();
+        for now in 0..=15u64 {
+            while let Some(id) = queue.pop_ready(&now) {
+                assert_eq!(deadlines[id], now, "item {id} released at the wrong tick");
+                released.push(id);
+            }
+            if let Some(&next) = queue.next_deadline() {
+                assert!(next > now);
+            }
+        }
+
+        assert_eq!(released, vec![1, 3, 0, 4, 2]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_ready(&u64::MAX), None);
+    }
+
+    #[test]
+    fn expire_before_removes_overdue_items() {
+        let mut queue = ExpiringQueue::new();
+        for (name, deadline) in [("a", 5), ("b", 1), ("c", 8), ("d", 5)] {
+            queue.push(name, deadline);
+        }
+
+        assert!(queue.expire_before(&1).is_empty());
+        assert_eq!(queue.expire_before(&6), vec!["b", "a", "d"]);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.next_deadline(), Some(&8));
+    }
+}
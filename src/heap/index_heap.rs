@@ -0,0 +1,237 @@
+//! Heap of indices into caller-owned data, ordered by a comparator
+
+use crate::utils::{Clear, Size};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A min-heap of `usize` indices ordered by a comparator over external data
+///
+/// Only the indices are sifted, so large elements never move. Indices pop
+/// in the order `sort_by(cmp)` would produce. After changing the data behind
+/// an index, call [`IndexHeap::update`] to restore the ordering; data the
+/// comparator borrows can be mutated through a `Cell` or `RefCell`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::heap::IndexHeap;
+/// use std::cell::Cell;
+///
+/// let dist = [Cell::new(7), Cell::new(3), Cell::new(5)];
+/// let mut heap = IndexHeap::new(dist.len(), |i, j| dist[i].get().cmp(&dist[j].get()));
+///
+/// dist[0].set(1);
+/// heap.update(0);
+/// assert_eq!(heap.pop(), Some(0));
+/// assert_eq!(heap.pop(), Some(1));
+/// ```
+pub struct IndexHeap<F> {
+    heap: Vec<usize>,
+    // Heap slot of each index, `None` once popped
+    positions: Vec<Option<usize>>,
+    cmp: F,
+}
+
+impl<F> IndexHeap<F>
+where
+    F: FnMut(usize, usize) -> Ordering,
+{
+    /// Creates a heap holding every index in `0..len`, heapified in O(len)
+    pub fn new(len: usize, cmp: F) -> Self {
+        let mut heap = Self {
+            heap: (0..len).collect(),
+            positions: (0..len).map(Some).collect(),
+            cmp,
+        };
+        for slot in (0..len / 2).rev() {
+            heap.sift_down(slot);
+        }
+        heap
+    }
+
+    /// Creates an empty heap accepting indices in `0..len`
+    pub fn empty(len: usize, cmp: F) -> Self {
+        Self {
+            heap: Vec::new(),
+            positions: vec![None; len],
+            cmp,
+        }
+    }
+
+    /// Inserts `index`, returning false if it is already in the heap
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is outside the range the heap was created for.
+    pub fn push(&mut self, index: usize) -> bool {
+        if self.positions[index].is_some() {
+            return false;
+        }
+
+        self.heap.push(index);
+        self.positions[index] = Some(self.heap.len() - 1);
+        self.sift_up(self.heap.len() - 1);
+        true
+    }
+
+    /// Removes and returns the index whose element compares least
+    pub fn pop(&mut self) -> Option<usize> {
+        let top = *self.heap.first()?;
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        self.heap.pop();
+        self.positions[top] = None;
+
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some(top)
+    }
+
+    /// Returns the index whose element compares least without removing it
+    pub fn peek(&self) -> Option<usize> {
+        self.heap.first().copied()
+    }
+
+    /// Returns true if `index` is currently in the heap
+    pub fn contains(&self, index: usize) -> bool {
+        self.positions.get(index).is_some_and(Option::is_some)
+    }
+
+    /// Restores the heap order after the element behind `index` changed
+    ///
+    /// Returns false, doing nothing, if `index` is not in the heap.
+    pub fn update(&mut self, index: usize) -> bool {
+        let Some(slot) = self.positions.get(index).copied().flatten() else {
+            return false;
+        };
+
+        self.sift_up(slot);
+        // If the element did not move up it may need to move down instead
+        if self.heap[slot] == index {
+            self.sift_down(slot);
+        }
+        true
+    }
+
+    fn less(&mut self, a: usize, b: usize) -> bool {
+        (self.cmp)(self.heap[a], self.heap[b]) == Ordering::Less
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a]] = Some(a);
+        self.positions[self.heap[b]] = Some(b);
+    }
+
+    fn sift_up(&mut self, mut slot: usize) {
+        while slot > 0 {
+            let parent = (slot - 1) / 2;
+            if !self.less(slot, parent) {
+                break;
+            }
+            self.swap(slot, parent);
+            slot = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut slot: usize) {
+        loop {
+            let left = 2 * slot + 1;
+            let right = left + 1;
+            let mut smallest = slot;
+
+            if left < self.heap.len() && self.less(left, smallest) {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.less(right, smallest) {
+                smallest = right;
+            }
+            if smallest == slot {
+                break;
+            }
+
+            self.swap(slot, smallest);
+            slot = smallest;
+        }
+    }
+}
+
+impl<F> Clear for IndexHeap<F> {
+    fn clear(&mut self) {
+        for &index in &self.heap {
+            self.positions[index] = None;
+        }
+        self.heap.clear();
+    }
+}
+
+impl<F> Size for IndexHeap<F> {
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+impl<F> fmt::Debug for IndexHeap<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexHeap")
+            .field("heap", &self.heap)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn pop_order_matches_sorted_data() {
+        let data = vec![42, 7, 19, 3, 88, 7, 0, 56, 23];
+        let mut heap = IndexHeap::new(data.len(), |i, j| data[i].cmp(&data[j]));
+        assert_eq!(heap.len(), data.len());
+        assert_eq!(heap.peek().map(|i| data[i]), Some(0));
+
+        let popped: Vec<i32> = std::iter::from_fn(|| heap.pop()).map(|i| data[i]).collect();
+        let mut sorted = data.clone();
+        sorted.sort();
+        assert_eq!(popped, sorted);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn update_after_external_mutation() {
+        let data: Vec<Cell<i32>> = [10, 20, 30, 40, 50].into_iter().map(Cell::new).collect();
+        let mut heap = IndexHeap::new(data.len(), |i, j| data[i].get().cmp(&data[j].get()));
+
+        data[4].set(5);
+        assert!(heap.update(4));
+        data[0].set(45);
+        assert!(heap.update(0));
+
+        assert_eq!(heap.pop(), Some(4));
+        assert!(!heap.contains(4));
+        assert!(!heap.update(4));
+
+        let rest: Vec<usize> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(rest, vec![1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn push_into_empty_heap() {
+        let data = [3, 1, 2];
+        let mut heap = IndexHeap::empty(data.len(), |i, j| data[i].cmp(&data[j]));
+        assert_eq!(heap.pop(), None);
+
+        assert!(heap.push(0));
+        assert!(heap.push(1));
+        assert!(!heap.push(1));
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(0));
+
+        heap.push(2);
+        heap.clear();
+        assert!(heap.is_empty());
+        assert!(heap.push(2));
+    }
+}
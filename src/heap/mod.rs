@@ -1,5 +1,13 @@
 pub mod binary_heap;
+pub mod expiring_queue;
+pub mod index_heap;
 pub mod priority_queue;
+pub mod sort;
+pub mod top_k;
 
 pub use binary_heap::BinaryHeap;
+pub use expiring_queue::ExpiringQueue;
+pub use index_heap::IndexHeap;
 pub use priority_queue::PriorityQueue;
+pub use sort::{heapsort, select_nth_unstable_by_heap};
+pub use top_k::TopK;
@@ -1,12 +1,14 @@
 use crate::heap::BinaryHeap;
-use crate::utils::{Clear, Peek, Size};
+use crate::utils::display::{DisplayLimited, DISPLAY_LIMIT};
+use crate::utils::{Clear, Peek, PeekMut, Size};
 use std::cmp::Ordering;
 use std::fmt;
+use std::ops::{Deref, DerefMut};
 
 #[derive(Debug, Clone)]
-struct PriorityItem<T, P> {
-    item: T,
-    priority: P,
+pub(crate) struct PriorityItem<T, P> {
+    pub(crate) item: T,
+    pub(crate) priority: P,
 }
 
 impl<T, P: Ord> PartialEq for PriorityItem<T, P> {
@@ -29,6 +31,12 @@ impl<T, P: Ord> Ord for PriorityItem<T, P> {
     }
 }
 
+impl<T: fmt::Display, P: fmt::Display> fmt::Display for PriorityItem<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.item, self.priority)
+    }
+}
+
 pub struct PriorityQueue<T, P> {
     heap: BinaryHeap<PriorityItem<T, P>>,
 }
@@ -64,14 +72,68 @@ impl<T, P: Ord> PriorityQueue<T, P> {
             .map(|priority_item| &priority_item.priority)
     }
 
+    /// Returns a guard giving mutable access to the highest-priority item
+    ///
+    /// Changing the priority through [`PeekMutGuard::set_priority`] restores
+    /// the queue order when the guard is dropped.
+    pub fn peek_mut(&mut self) -> Option<PeekMutGuard<'_, T, P>> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        Some(PeekMutGuard {
+            heap: &mut self.heap,
+            reprioritized: false,
+        })
+    }
+
+    /// Replaces every priority with `f(item, priority)` and restores the
+    /// queue order in a single O(n) rebuild
+    pub fn recompute_priorities<F: FnMut(&T, &P) -> P>(&mut self, mut f: F) {
+        for entry in self.heap.as_mut_slice() {
+            entry.priority = f(&entry.item, &entry.priority);
+        }
+        self.heap.rebuild();
+    }
+
+    /// Keeps only the entries for which `f` returns `true`, returning how
+    /// many were removed
+    ///
+    /// The queue is rebuilt once in O(n) instead of popping every entry.
+    pub fn retain<F: FnMut(&T, &P) -> bool>(&mut self, mut f: F) -> usize {
+        self.extract_if(|item, priority| !f(item, priority)).len()
+    }
+
+    /// Removes and returns every `(item, priority)` for which `pred` returns
+    /// `true`, in unspecified order, rebuilding the queue once in O(n)
+    pub fn extract_if<F: FnMut(&T, &P) -> bool>(&mut self, mut pred: F) -> Vec<(T, P)> {
+        self.heap
+            .extract_if(|entry| pred(&entry.item, &entry.priority))
+            .into_iter()
+            .map(|entry| (entry.item, entry.priority))
+            .collect()
+    }
+
     pub fn capacity(&self) -> usize {
         self.heap.capacity()
     }
 
+    pub fn reserve(&mut self, additional: usize) {
+        self.heap.reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.heap.shrink_to_fit();
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.heap.shrink_to(min_capacity);
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&T, &P)> {
         self.heap.iter().map(|item| (&item.item, &item.priority))
     }
 
+    /// Returns the items from highest to lowest priority
     pub fn into_sorted_vec(self) -> Vec<T> {
         self.heap
             .into_sorted_vec()
@@ -81,6 +143,44 @@ impl<T, P: Ord> PriorityQueue<T, P> {
     }
 }
 
+pub struct PeekMutGuard<'a, T, P: Ord> {
+    heap: &'a mut BinaryHeap<PriorityItem<T, P>>,
+    reprioritized: bool,
+}
+
+impl<T, P: Ord> PeekMutGuard<'_, T, P> {
+    pub fn priority(&self) -> &P {
+        &self.heap.peek().unwrap().priority
+    }
+
+    pub fn set_priority(&mut self, priority: P) {
+        self.heap.peek_mut().unwrap().priority = priority;
+        self.reprioritized = true;
+    }
+}
+
+impl<T, P: Ord> Deref for PeekMutGuard<'_, T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.heap.peek().unwrap().item
+    }
+}
+
+impl<T, P: Ord> DerefMut for PeekMutGuard<'_, T, P> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap.peek_mut().unwrap().item
+    }
+}
+
+impl<T, P: Ord> Drop for PeekMutGuard<'_, T, P> {
+    fn drop(&mut self) {
+        if self.reprioritized {
+            self.heap.sift_down_top();
+        }
+    }
+}
+
 impl<T, P: Ord> Default for PriorityQueue<T, P> {
     fn default() -> Self {
         Self::new()
@@ -113,18 +213,32 @@ impl<T: fmt::Debug, P: fmt::Debug + Ord> fmt::Debug for PriorityQueue<T, P> {
     }
 }
 
+impl<T: fmt::Display, P: Ord + fmt::Display> DisplayLimited for PriorityQueue<T, P> {
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        self.heap.fmt_with_limit(f, limit)
+    }
+}
+
+/// Shows `item: priority` pairs in pop order, eliding past [`DISPLAY_LIMIT`]
+impl<T: fmt::Display, P: Ord + fmt::Display> fmt::Display for PriorityQueue<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_limit(f, DISPLAY_LIMIT)
+    }
+}
+
 impl<T, P: Ord> FromIterator<(T, P)> for PriorityQueue<T, P> {
     fn from_iter<I: IntoIterator<Item = (T, P)>>(iter: I) -> Self {
         let mut queue = PriorityQueue::new();
-        for (item, priority) in iter {
-            queue.push(item, priority);
-        }
+        queue.extend(iter);
         queue
     }
 }
 
+/// Reserves room from the iterator's `size_hint` before pushing
 impl<T, P: Ord> Extend<(T, P)> for PriorityQueue<T, P> {
     fn extend<I: IntoIterator<Item = (T, P)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
         for (item, priority) in iter {
             self.push(item, priority);
         }
@@ -173,6 +287,9 @@ mod tests {
         let queue: PriorityQueue<i32, i32> = PriorityQueue::with_capacity(10);
         assert_eq!(queue.capacity(), 10);
         assert!(queue.is_empty());
+
+        let collected: PriorityQueue<_, _> = (0..500).map(|i| (i, i % 7)).collect();
+        assert_eq!(collected.capacity(), 500);
     }
 
     #[test]
@@ -235,4 +352,126 @@ mod tests {
         assert!(["first", "second", "third"].contains(&second));
         assert!(["first", "second", "third"].contains(&third));
     }
+
+    #[test]
+    fn clear_keeps_capacity() {
+        let mut queue: PriorityQueue<i32, i32> = (0..50).map(|i| (i, i)).collect();
+        let capacity = queue.capacity();
+
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), capacity);
+    }
+
+    #[test]
+    fn peek_mut_demotes_top() {
+        let mut queue = PriorityQueue::new();
+        queue.push("deploy", 10);
+        queue.push("review", 7);
+        queue.push("lint", 3);
+
+        if let Some(mut top) = queue.peek_mut() {
+            assert_eq!(*top, "deploy");
+            assert_eq!(top.priority(), &10);
+            top.set_priority(5);
+        }
+
+        assert_eq!(queue.peek(), Some(&"review"));
+        assert_eq!(queue.into_sorted_vec(), vec!["review", "deploy", "lint"]);
+    }
+
+    #[test]
+    fn peek_mut_payload_only() {
+        let mut queue = PriorityQueue::new();
+        queue.push(String::from("a"), 2);
+        queue.push(String::from("b"), 1);
+
+        queue.peek_mut().unwrap().push('!');
+        drop(queue.peek_mut());
+
+        assert_eq!(queue.peek_priority(), Some(&2));
+        assert_eq!(queue.into_sorted_vec(), vec!["a!", "b"]);
+
+        let mut empty: PriorityQueue<i32, i32> = PriorityQueue::new();
+        assert!(empty.peek_mut().is_none());
+    }
+
+    #[test]
+    fn display_shows_priorities() {
+        let queue: PriorityQueue<_, _> = [("lint", 1), ("deploy", 9), ("review", 5)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(queue.to_string(), "[deploy: 9, review: 5, lint: 1]");
+        assert_eq!(queue.fmt_limited(1).to_string(), "[deploy: 9, ...]");
+    }
+
+    #[test]
+    fn shrink_releases_capacity() {
+        let mut queue: PriorityQueue<u32, u32> = (0..10_000).map(|i| (i, i)).collect();
+        while queue.len() > 10 {
+            queue.pop();
+        }
+
+        queue.shrink_to(100);
+        assert!(queue.capacity() >= 100 && queue.capacity() < 10_000);
+        queue.shrink_to_fit();
+        assert!(queue.capacity() < 100);
+        assert_eq!(queue.into_sorted_vec(), (0..10).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn recompute_priorities_rebuilds_order() {
+        let tasks = [
+            ("a", 40),
+            ("b", 10),
+            ("c", 70),
+            ("d", 20),
+            ("e", 90),
+            ("f", 30),
+        ];
+        let mut halved: PriorityQueue<&str, i32> = tasks.into_iter().collect();
+        halved.recompute_priorities(|_, priority| priority / 2);
+        assert_eq!(halved.peek_priority(), Some(&45));
+        assert_eq!(halved.into_sorted_vec(), vec!["e", "c", "a", "f", "d", "b"]);
+
+        let mut inverted: PriorityQueue<&str, i32> = tasks.into_iter().collect();
+        inverted.recompute_priorities(|_, priority| -priority);
+        let popped: Vec<&str> = std::iter::from_fn(|| inverted.pop()).collect();
+        assert_eq!(popped, vec!["b", "d", "f", "a", "c", "e"]);
+    }
+
+    #[test]
+    fn cancel_jobs_by_predicate() {
+        let mut queue: PriorityQueue<(u32, &str), u32> = (0..20)
+            .map(|id| ((id, if id % 2 == 0 { "alice" } else { "bob" }), id * 7 % 20))
+            .collect();
+
+        assert_eq!(queue.retain(|&(_, client), _| client != "bob"), 10);
+        assert_eq!(queue.len(), 10);
+        assert!(queue.iter().all(|(&(_, client), _)| client == "alice"));
+
+        let mut cancelled = queue.extract_if(|_, &priority| priority < 5);
+        cancelled.sort();
+        assert_eq!(
+            cancelled,
+            vec![((0, "alice"), 0), ((6, "alice"), 2), ((12, "alice"), 4)]
+        );
+        assert!(queue.iter().all(|(_, &priority)| priority >= 5));
+
+        let mut expected: Vec<((u32, &str), u32)> = queue
+            .iter()
+            .map(|(&item, &priority)| (item, priority))
+            .collect();
+        expected.sort_by_key(|&(_, priority)| std::cmp::Reverse(priority));
+        let popped: Vec<(u32, &str)> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(
+            popped,
+            expected
+                .into_iter()
+                .map(|(item, _)| item)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(queue.retain(|_, _| true), 0);
+    }
 }
@@ -0,0 +1,103 @@
+//! In-place heap algorithms over slices
+
+/// Sorts the slice in ascending order using an in-place max-heap
+pub fn heapsort<T: Ord>(slice: &mut [T]) {
+    for idx in (0..slice.len() / 2).rev() {
+        sift_down(slice, idx, slice.len());
+    }
+
+    for end in (1..slice.len()).rev() {
+        slice.swap(0, end);
+        sift_down(slice, 0, end);
+    }
+}
+
+/// Returns the `n`th smallest element (zero-based), reordering the slice
+///
+/// Keeps a max-heap of the `n + 1` smallest elements seen so far in the front
+/// of the slice, so it runs in O(len * log n).
+///
+/// # Panics
+///
+/// Panics if `n >= slice.len()`.
+pub fn select_nth_unstable_by_heap<T: Ord>(slice: &mut [T], n: usize) -> &T {
+    assert!(
+        n < slice.len(),
+        "index {n} out of range for slice of length {}",
+        slice.len()
+    );
+
+    let heap_len = n + 1;
+    for idx in (0..heap_len / 2).rev() {
+        sift_down(slice, idx, heap_len);
+    }
+
+    for idx in heap_len..slice.len() {
+        if slice[idx] < slice[0] {
+            slice.swap(0, idx);
+            sift_down(slice, 0, heap_len);
+        }
+    }
+
+    &slice[0]
+}
+
+fn sift_down<T: Ord>(slice: &mut [T], mut idx: usize, len: usize) {
+    loop {
+        let left_child = 2 * idx + 1;
+        let right_child = 2 * idx + 2;
+        let mut largest = idx;
+
+        if left_child < len && slice[left_child] > slice[largest] {
+            largest = left_child;
+        }
+
+        if right_child < len && slice[right_child] > slice[largest] {
+            largest = right_child;
+        }
+
+        if largest == idx {
+            break;
+        }
+
+        slice.swap(idx, largest);
+        idx = largest;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heapsort_matches_sort() {
+        let sorted: Vec<u32> = (0..100).collect();
+        let reversed: Vec<u32> = (0..100).rev().collect();
+
+        for input in [sorted, reversed, vec![], vec![7], vec![3, 3, 3]] {
+            let mut expected = input.clone();
+            expected.sort();
+            let mut actual = input;
+            heapsort(&mut actual);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn selection_agrees_with_sorting() {
+        let values = vec![9, 4, 7, 1, 8, 2, 2, 6, 5, 3];
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        for (n, expected) in sorted.iter().enumerate() {
+            let mut slice = values.clone();
+            assert_eq!(select_nth_unstable_by_heap(&mut slice, n), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn selection_out_of_range() {
+        select_nth_unstable_by_heap(&mut [1, 2, 3], 3);
+    }
+}
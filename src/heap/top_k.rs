@@ -0,0 +1,179 @@
+//! Collector keeping only the highest-priority items seen so far
+
+use crate::heap::priority_queue::PriorityItem;
+use crate::heap::BinaryHeap;
+use crate::utils::{Clear, Peek, PeekMut, Size};
+use std::fmt;
+
+/// Keeps the `k` highest-priority items pushed into it
+///
+/// The retained items sit in a min-heap, so the cheap end is the lowest one:
+/// [`min`](Self::min) is the threshold an incoming item has to reach, and
+/// [`evict_min`](Self::evict_min) removes it. Once full, an item below the
+/// threshold is dropped and any other item replaces the current minimum.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::heap::TopK;
+///
+/// let mut scores = TopK::new(2);
+/// scores.push("ada", 91);
+/// scores.push("bob", 78);
+/// scores.push("cy", 85);
+///
+/// assert_eq!(scores.min(), Some(&"cy"));
+/// assert_eq!(scores.into_sorted_vec(), vec!["ada", "cy"]);
+/// ```
+pub struct TopK<T, P> {
+    heap: BinaryHeap<PriorityItem<T, P>>,
+    k: usize,
+}
+
+impl<T, P: Ord> TopK<T, P> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            heap: BinaryHeap::min_heap(),
+            k,
+        }
+    }
+
+    /// Returns the maximum number of items kept
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    pub fn push(&mut self, item: T, priority: P) {
+        let entry = PriorityItem { item, priority };
+        if self.heap.len() < self.k {
+            self.heap.push(entry);
+            return;
+        }
+
+        let Some(lowest) = self.heap.peek_mut() else {
+            return;
+        };
+        if entry < *lowest {
+            return;
+        }
+        *lowest = entry;
+        self.heap.sift_down_top();
+    }
+
+    /// Returns the lowest-priority item still retained
+    pub fn min(&self) -> Option<&T> {
+        self.heap.peek().map(|entry| &entry.item)
+    }
+
+    pub fn min_priority(&self) -> Option<&P> {
+        self.heap.peek().map(|entry| &entry.priority)
+    }
+
+    /// Removes and returns the lowest-priority item still retained
+    pub fn evict_min(&mut self) -> Option<T> {
+        self.heap.pop().map(|entry| entry.item)
+    }
+
+    /// Iterates over the retained items in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &P)> {
+        self.heap.iter().map(|entry| (&entry.item, &entry.priority))
+    }
+
+    /// Returns the retained items from highest to lowest priority
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .rev()
+            .map(|entry| entry.item)
+            .collect()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+}
+
+impl<T, P> Clear for TopK<T, P> {
+    fn clear(&mut self) {
+        self.heap.clear();
+    }
+}
+
+impl<T, P> Size for TopK<T, P> {
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+/// Reserves room from the iterator's `size_hint`, never more than `k` items
+impl<T, P: Ord> Extend<(T, P)> for TopK<T, P> {
+    fn extend<I: IntoIterator<Item = (T, P)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let room = self.k.saturating_sub(self.heap.len());
+        self.heap.reserve(iter.size_hint().0.min(room));
+        for (item, priority) in iter {
+            self.push(item, priority);
+        }
+    }
+}
+
+impl<T: fmt::Debug, P: fmt::Debug + Ord> fmt::Debug for TopK<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TopK")
+            .field("heap", &self.heap)
+            .field("k", &self.k)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_top_k() {
+        let mut top = TopK::new(5);
+        for priority in 0..100 {
+            top.push(priority * 10, priority);
+            assert!(top.len() <= 5);
+        }
+
+        assert_eq!(top.k(), 5);
+        assert_eq!(top.min(), Some(&950));
+        assert_eq!(top.min_priority(), Some(&95));
+        let mut priorities: Vec<_> = top.iter().map(|(_, &p)| p).collect();
+        priorities.sort_unstable();
+        assert_eq!(priorities, vec![95, 96, 97, 98, 99]);
+        assert_eq!(top.into_sorted_vec(), vec![990, 980, 970, 960, 950]);
+    }
+
+    #[test]
+    fn drops_lower_items_and_evicts_min() {
+        let mut top = TopK::new(2);
+        top.push("b", 5);
+        top.push("a", 9);
+        top.push("low", 1);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top.evict_min(), Some("b"));
+        assert_eq!(top.evict_min(), Some("a"));
+        assert_eq!(top.evict_min(), None);
+
+        let mut none = TopK::new(0);
+        none.push("x", 1);
+        assert!(none.is_empty());
+        assert_eq!(none.min(), None);
+    }
+
+    #[test]
+    fn extend_reserves_at_most_k() {
+        let mut top = TopK::new(3);
+        top.extend((0..500).map(|i| (i, i)));
+        assert!(top.capacity() < 500);
+        assert_eq!(top.len(), 3);
+
+        top.clear();
+        assert!(top.is_empty());
+        assert_eq!(top.k(), 3);
+    }
+}
@@ -24,6 +24,8 @@
 //! assert_eq!(stack.pop(), Some(42));
 //! ```
 
+mod macros;
+
 pub mod graph;
 pub mod hash;
 pub mod heap;
@@ -32,8 +34,9 @@ pub mod tree;
 pub mod utils;
 
 pub use graph::{Graph, WeightedGraph};
-pub use hash::{BloomFilter, HashMap, HashSet};
+pub use hash::{BloomFilter, HashMap, HashSet, OpenHashMap};
 pub use heap::{BinaryHeap, PriorityQueue};
 pub use linear::{Queue, Stack};
 pub use tree::{AvlTree, BinarySearchTree, RedBlackTree, Trie};
+pub use utils::display::{DisplayLimited, Limited};
 pub use utils::traits::*;
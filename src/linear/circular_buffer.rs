@@ -0,0 +1,176 @@
+//! Fixed-capacity ring buffer that overwrites its oldest element when full
+
+use crate::utils::{Capacity, Clear, Size};
+use std::fmt;
+
+/// A fixed-capacity FIFO buffer backed by a ring of slots
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::CircularBuffer;
+///
+/// let mut buffer = CircularBuffer::new(2);
+/// buffer.push_back(1);
+/// buffer.push_back(2);
+/// assert_eq!(buffer.push_back(3), Some(1));
+/// assert_eq!(buffer.pop_front(), Some(2));
+/// ```
+pub struct CircularBuffer<T> {
+    slots: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> CircularBuffer<T> {
+    /// Creates an empty buffer holding at most `capacity` elements
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "CircularBuffer capacity must be non-zero");
+        Self {
+            slots: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Appends an element, returning the evicted front element if the buffer was full
+    pub fn push_back(&mut self, item: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+
+        let tail = self.physical(self.len);
+        self.slots[tail] = Some(item);
+        self.len += 1;
+        evicted
+    }
+
+    /// Removes and returns the oldest element
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let item = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.slots.len();
+        self.len -= 1;
+        item
+    }
+
+    /// Removes and returns the newest element
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let tail = self.physical(self.len);
+        self.slots[tail].take()
+    }
+
+    /// Returns a reference to the oldest element
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the newest element
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|idx| self.get(idx))
+    }
+
+    /// Returns the element `idx` positions from the front
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if idx >= self.len {
+            return None;
+        }
+        self.slots[self.physical(idx)].as_ref()
+    }
+
+    /// Returns an iterator from the oldest to the newest element
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len).filter_map(move |idx| self.get(idx))
+    }
+
+    fn physical(&self, idx: usize) -> usize {
+        (self.head + idx) % self.slots.len()
+    }
+}
+
+impl<T> Clear for CircularBuffer<T> {
+    fn clear(&mut self) {
+        for slot in &mut self.slots {
+            *slot = None;
+        }
+        self.head = 0;
+        self.len = 0;
+    }
+}
+
+impl<T> Size for CircularBuffer<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T> Capacity for CircularBuffer<T> {
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CircularBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_pop_wraps_around() {
+        let mut buffer = CircularBuffer::new(3);
+        assert!(buffer.is_empty());
+
+        for i in 0..3 {
+            assert_eq!(buffer.push_back(i), None);
+        }
+        assert!(buffer.is_full());
+
+        assert_eq!(buffer.push_back(3), Some(0));
+        assert_eq!(buffer.push_back(4), Some(1));
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(buffer.front(), Some(&2));
+        assert_eq!(buffer.back(), Some(&4));
+
+        assert_eq!(buffer.pop_front(), Some(2));
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.get(1), Some(&4));
+        assert_eq!(buffer.get(2), None);
+
+        assert_eq!(buffer.pop_back(), Some(4));
+        assert_eq!(buffer.push_back(5), None);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![3, 5]);
+    }
+
+    #[test]
+    fn clear_resets_buffer() {
+        let mut buffer = CircularBuffer::new(2);
+        buffer.push_back("a");
+        buffer.push_back("b");
+        buffer.push_back("c");
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.capacity(), 2);
+        assert_eq!(buffer.pop_front(), None);
+        assert_eq!(buffer.back(), None);
+    }
+}
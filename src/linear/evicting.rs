@@ -0,0 +1,199 @@
+//! Bounded stack and queue that evict their oldest element when full
+
+use crate::linear::CircularBuffer;
+use crate::utils::{Capacity, Clear, Peek, Size};
+use std::fmt;
+
+/// A LIFO stack holding at most `capacity` elements
+///
+/// Pushing onto a full stack evicts the bottom (oldest) element, which makes
+/// it suitable for undo histories.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::EvictingStack;
+///
+/// let mut history = EvictingStack::new(2);
+/// history.push("a");
+/// history.push("b");
+/// assert_eq!(history.push("c"), Some("a"));
+/// assert_eq!(history.pop(), Some("c"));
+/// ```
+pub struct EvictingStack<T> {
+    buffer: CircularBuffer<T>,
+}
+
+impl<T> EvictingStack<T> {
+    /// Creates an empty stack holding at most `capacity` elements
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buffer: CircularBuffer::new(capacity),
+        }
+    }
+
+    /// Pushes an element, returning the bottom element if it was evicted
+    pub fn push(&mut self, item: T) -> Option<T> {
+        self.buffer.push_back(item)
+    }
+
+    /// Removes and returns the top element
+    pub fn pop(&mut self) -> Option<T> {
+        self.buffer.pop_back()
+    }
+
+    /// Returns an iterator from the bottom to the top of the stack
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.buffer.iter()
+    }
+}
+
+impl<T> Clear for EvictingStack<T> {
+    fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl<T> Size for EvictingStack<T> {
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+impl<T> Capacity for EvictingStack<T> {
+    fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+impl<T> Peek<T> for EvictingStack<T> {
+    fn peek(&self) -> Option<&T> {
+        self.buffer.back()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for EvictingStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A FIFO queue holding at most `capacity` elements
+///
+/// Pushing onto a full queue evicts the front (oldest) element, which makes
+/// it suitable for keeping the last N log lines.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::EvictingQueue;
+///
+/// let mut lines = EvictingQueue::new(2);
+/// lines.push(1);
+/// lines.push(2);
+/// assert_eq!(lines.push(3), Some(1));
+/// assert_eq!(lines.pop(), Some(2));
+/// ```
+pub struct EvictingQueue<T> {
+    buffer: CircularBuffer<T>,
+}
+
+impl<T> EvictingQueue<T> {
+    /// Creates an empty queue holding at most `capacity` elements
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buffer: CircularBuffer::new(capacity),
+        }
+    }
+
+    /// Adds an element to the back, returning the front element if it was evicted
+    pub fn push(&mut self, item: T) -> Option<T> {
+        self.buffer.push_back(item)
+    }
+
+    /// Removes and returns the front element
+    pub fn pop(&mut self) -> Option<T> {
+        self.buffer.pop_front()
+    }
+
+    /// Returns an iterator from the front to the back of the queue
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.buffer.iter()
+    }
+}
+
+impl<T> Clear for EvictingQueue<T> {
+    fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl<T> Size for EvictingQueue<T> {
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+impl<T> Capacity for EvictingQueue<T> {
+    fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+impl<T> Peek<T> for EvictingQueue<T> {
+    fn peek(&self) -> Option<&T> {
+        self.buffer.front()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for EvictingQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_keeps_last_pushes() {
+        let mut history = EvictingStack::new(3);
+        let evicted: Vec<_> = (0..10).filter_map(|i| history.push(i)).collect();
+
+        assert_eq!(evicted, (0..7).collect::<Vec<_>>());
+        assert_eq!(history.iter().cloned().collect::<Vec<_>>(), vec![7, 8, 9]);
+        assert!(history.is_full());
+        assert_eq!(history.peek(), Some(&9));
+
+        assert_eq!(history.pop(), Some(9));
+        assert_eq!(history.push(10), None);
+        assert_eq!(history.push(11), Some(7));
+        assert_eq!(history.iter().cloned().collect::<Vec<_>>(), vec![8, 10, 11]);
+    }
+
+    #[test]
+    fn queue_keeps_last_pushes() {
+        let mut lines = EvictingQueue::new(3);
+        let evicted: Vec<_> = (0..10).filter_map(|i| lines.push(i)).collect();
+
+        assert_eq!(evicted, (0..7).collect::<Vec<_>>());
+        assert_eq!(lines.iter().cloned().collect::<Vec<_>>(), vec![7, 8, 9]);
+        assert_eq!(lines.peek(), Some(&7));
+        assert_eq!(lines.capacity(), 3);
+
+        assert_eq!(lines.pop(), Some(7));
+        assert_eq!(lines.len(), 2);
+        lines.clear();
+        assert!(lines.is_empty());
+        assert_eq!(lines.pop(), None);
+    }
+}
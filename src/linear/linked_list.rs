@@ -12,6 +12,9 @@ struct Node<T> {
 
 /// A singly linked list
 ///
+/// Each node owns the next one through a `Box`, so the safe API cannot build
+/// a cycle and traversals always terminate.
+///
 /// # Examples
 ///
 /// ```rust
@@ -37,6 +40,16 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Creates a list holding clones of `items`, with the first item at the front
+    pub fn from_slice(items: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut list = Self::new();
+        list.extend(items.iter().cloned());
+        list
+    }
+
     /// Adds an element to the front of the list
     pub fn push_front(&mut self, data: T) {
         let new_node = Box::new(Node {
@@ -47,6 +60,18 @@ impl<T> LinkedList<T> {
         self.size += 1;
     }
 
+    /// Adds an element to the back of the list
+    ///
+    /// This walks the whole list, so it runs in O(n).
+    pub fn push_back(&mut self, data: T) {
+        let mut cursor = &mut self.head;
+        while let Some(node) = cursor {
+            cursor = &mut node.next;
+        }
+        *cursor = Some(Box::new(Node { data, next: None }));
+        self.size += 1;
+    }
+
     /// Removes and returns the front element
     pub fn pop_front(&mut self) -> Option<T> {
         self.head.take().map(|node| {
@@ -66,8 +91,94 @@ impl<T> LinkedList<T> {
         self.head.as_mut().map(|node| &mut node.data)
     }
 
+    /// Pushes the items to the front so they keep their iteration order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_ds_lib_bee::linear::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_front(4);
+    /// list.extend_front([1, 2, 3]);
+    /// assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let items: Vec<T> = iter.into_iter().collect();
+        for item in items.into_iter().rev() {
+            self.push_front(item);
+        }
+    }
+
+    /// Removes consecutive duplicate elements, keeping the first of each run
+    ///
+    /// Only adjacent duplicates are removed, so an unsorted list may still
+    /// contain repeated values afterwards.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that map to the same key
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns `true`
+    ///
+    /// As with `Vec::dedup_by`, the closure receives the candidate element
+    /// first and the retained element it is compared against second.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut removed = 0;
+        let mut current = self.head.as_mut();
+
+        while let Some(node) = current {
+            while let Some(next) = node.next.as_mut() {
+                if !same_bucket(&mut next.data, &mut node.data) {
+                    break;
+                }
+                let duplicate = node.next.take().unwrap();
+                node.next = duplicate.next;
+                removed += 1;
+            }
+            current = node.next.as_mut();
+        }
+
+        self.size -= removed;
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, relinking the
+    /// list around the removed nodes
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut removed = 0;
+        let mut link = &mut self.head;
+
+        loop {
+            match link {
+                None => break,
+                Some(node) if f(&node.data) => link = &mut link.as_mut().unwrap().next,
+                Some(_) => {
+                    let node = link.take().unwrap();
+                    *link = node.next;
+                    removed += 1;
+                }
+            }
+        }
+
+        self.size -= removed;
+    }
+
     /// Returns an iterator over the list
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             current: self.head.as_deref(),
         }
@@ -112,6 +223,35 @@ impl<T: PartialEq> PartialEq for LinkedList<T> {
 
 impl<T: Eq> Eq for LinkedList<T> {}
 
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut cursor = &mut self.head;
+        while let Some(node) = cursor {
+            cursor = &mut node.next;
+        }
+        for data in iter {
+            let node = cursor.insert(Box::new(Node { data, next: None }));
+            cursor = &mut node.next;
+            self.size += 1;
+        }
+    }
+}
+
+/// Copies each borrowed item onto the tail of the list
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::LinkedList;
+///
+/// let mut list: LinkedList<i32> = LinkedList::new();
+/// list.extend(&[1, 2, 3]);
+/// assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3]);
+/// ```
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
 /// An iterator over the elements of a LinkedList
 pub struct Iter<'a, T> {
     current: Option<&'a Node<T>>,
@@ -200,4 +340,115 @@ mod tests {
         let collected: Vec<_> = list.into_iter().collect();
         assert_eq!(collected, vec![3, 2, 1]);
     }
+
+    #[test]
+    fn push_back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut list = LinkedList::new();
+        for value in [1, 1, 2, 3, 3, 3, 1] {
+            list.push_back(value);
+        }
+
+        list.dedup();
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 1]);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn dedup_collapses_runs() {
+        let mut list = LinkedList::new();
+        for _ in 0..5 {
+            list.push_back(7);
+        }
+        list.dedup();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.front(), Some(&7));
+
+        let mut list = LinkedList::new();
+        for value in [3, 1, 3, 3, 1] {
+            list.push_back(value);
+        }
+        list.dedup();
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![3, 1, 3, 1]);
+    }
+
+    #[test]
+    fn dedup_by_and_key() {
+        let mut list = LinkedList::new();
+        for value in [10, 11, 20, 25, 29, 30] {
+            list.push_back(value);
+        }
+        list.dedup_by_key(|value| *value / 10);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![10, 20, 30]);
+
+        let mut list = LinkedList::new();
+        for word in ["Foo", "foo", "BAR", "Bar", "baz"] {
+            list.push_back(word.to_string());
+        }
+        list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.iter().cloned().collect::<Vec<_>>(),
+            vec!["Foo", "BAR", "baz"]
+        );
+    }
+
+    #[test]
+    fn extend_front_and_back() {
+        let mut list = LinkedList::new();
+        list.extend_front(vec![3, 4]);
+        list.extend_front(1..=2);
+        list.extend_front(Vec::new());
+        list.extend(&[5, 6]);
+        list.extend(7..=8);
+
+        assert_eq!(list.len(), 8);
+        assert_eq!(
+            list.iter().cloned().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn from_slice_keeps_order() {
+        let list = LinkedList::from_slice(&[1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let empty: LinkedList<String> = LinkedList::from_slice(&[]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn retain_relinks_around_removed_nodes() {
+        let mut list = LinkedList::from_slice(&[1, 2, 3, 4, 5, 6, 7]);
+        list.retain(|&item| item % 2 == 0);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![2, 4, 6]);
+
+        list.push_back(8);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().last(), Some(&8));
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+    }
 }
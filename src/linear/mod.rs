@@ -1,10 +1,18 @@
 //! Linear data structures with sequential element access patterns
 
+pub mod circular_buffer;
+pub mod evicting;
 pub mod linked_list;
+pub mod moving_median;
 pub mod queue;
+pub mod sliding_window;
 pub mod stack;
 
 // Re-export main types
+pub use circular_buffer::CircularBuffer;
+pub use evicting::{EvictingQueue, EvictingStack};
 pub use linked_list::LinkedList;
+pub use moving_median::MovingMedian;
 pub use queue::Queue;
-pub use stack::Stack;
+pub use sliding_window::SlidingWindow;
+pub use stack::{Checkpoint, Stack};
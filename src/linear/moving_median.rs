@@ -0,0 +1,219 @@
+//! Sliding-window median over a stream using two heaps with lazy deletion
+
+use crate::heap::BinaryHeap;
+use crate::linear::CircularBuffer;
+use crate::utils::{Capacity, Clear, Drain, Peek, Size};
+use crate::HashSet;
+
+// Ordered by value, then by arrival, so equal values stay distinguishable
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Sample<T> {
+    value: T,
+    sequence: u64,
+}
+
+/// Tracks the median of the last `size` pushed samples
+///
+/// The window is split between a max-heap holding the lower half and a
+/// min-heap holding the upper half. Samples leaving the window are only
+/// marked as expired and discarded once they surface at a heap top, so
+/// `push` runs in amortized O(log size).
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::MovingMedian;
+///
+/// let mut median = MovingMedian::new(3);
+/// for sample in [5, 1, 9, 4] {
+///     median.push(sample);
+/// }
+/// // Window is [1, 9, 4]
+/// assert_eq!(median.median(), Some(&4));
+/// ```
+pub struct MovingMedian<T> {
+    window: CircularBuffer<Sample<T>>,
+    lower: BinaryHeap<Sample<T>>,
+    upper: BinaryHeap<Sample<T>>,
+    // Live samples in each half; the heaps may also hold expired ones
+    lower_len: usize,
+    upper_len: usize,
+    expired: HashSet<u64>,
+    next_sequence: u64,
+}
+
+impl<T: Ord + Clone> MovingMedian<T> {
+    /// Creates a tracker over the last `size` samples
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn new(size: usize) -> Self {
+        Self {
+            window: CircularBuffer::new(size),
+            lower: BinaryHeap::max_heap(),
+            upper: BinaryHeap::min_heap(),
+            lower_len: 0,
+            upper_len: 0,
+            expired: HashSet::new(),
+            next_sequence: 0,
+        }
+    }
+
+    /// Adds a sample, returning the sample that fell out of the window, if any
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let sample = Sample {
+            value,
+            sequence: self.next_sequence,
+        };
+        self.next_sequence += 1;
+
+        if self.lower.peek().map_or(true, |top| sample <= *top) {
+            self.lower.push(sample.clone());
+            self.lower_len += 1;
+        } else {
+            self.upper.push(sample.clone());
+            self.upper_len += 1;
+        }
+
+        let evicted = self.window.push_back(sample);
+        if let Some(old) = &evicted {
+            // Heap tops are always live, and every sample in `lower` orders
+            // before every sample in `upper`
+            if self.lower.peek().is_some_and(|top| old <= top) {
+                self.lower_len -= 1;
+            } else {
+                self.upper_len -= 1;
+            }
+            self.expired.insert(old.sequence);
+            Self::prune(&mut self.lower, &mut self.expired);
+            Self::prune(&mut self.upper, &mut self.expired);
+        }
+
+        self.rebalance();
+        if self.lower.len() + self.upper.len() > 2 * self.window.capacity() {
+            self.compact();
+        }
+        evicted.map(|sample| sample.value)
+    }
+
+    /// Returns the median of the window, taking the lower of the two middle
+    /// samples when the window holds an even number of them
+    pub fn median(&self) -> Option<&T> {
+        self.lower.peek().map(|sample| &sample.value)
+    }
+
+    /// Returns the two middle samples in ascending order; both are the same
+    /// sample when the window holds an odd number of them
+    pub fn middle_pair(&self) -> Option<(&T, &T)> {
+        let low = self.median()?;
+        if self.lower_len > self.upper_len {
+            Some((low, low))
+        } else {
+            self.upper.peek().map(|high| (low, &high.value))
+        }
+    }
+
+    /// Returns the maximum number of samples the window holds
+    pub fn window_size(&self) -> usize {
+        self.window.capacity()
+    }
+
+    /// Returns an iterator from the oldest to the newest sample in the window
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.window.iter().map(|sample| &sample.value)
+    }
+
+    // Keeps `lower` holding as many live samples as `upper`, or one more
+    fn rebalance(&mut self) {
+        while self.lower_len > self.upper_len + 1 {
+            let moved = self.lower.pop().expect("lower half has live samples");
+            self.upper.push(moved);
+            self.lower_len -= 1;
+            self.upper_len += 1;
+            Self::prune(&mut self.lower, &mut self.expired);
+        }
+        while self.upper_len > self.lower_len {
+            let moved = self.upper.pop().expect("upper half has live samples");
+            self.lower.push(moved);
+            self.upper_len -= 1;
+            self.lower_len += 1;
+            Self::prune(&mut self.upper, &mut self.expired);
+        }
+    }
+
+    fn prune(heap: &mut BinaryHeap<Sample<T>>, expired: &mut HashSet<u64>) {
+        while let Some(top) = heap.peek() {
+            if !expired.remove(&top.sequence) {
+                break;
+            }
+            heap.pop();
+        }
+    }
+
+    // Drops every expired sample still buried in the heaps
+    fn compact(&mut self) {
+        for heap in [&mut self.lower, &mut self.upper] {
+            let live: Vec<Sample<T>> = heap
+                .drain()
+                .filter(|sample| !self.expired.contains(&sample.sequence))
+                .collect();
+            heap.extend(live);
+        }
+        self.expired.clear();
+    }
+}
+
+impl<T> Clear for MovingMedian<T> {
+    fn clear(&mut self) {
+        self.window.clear();
+        self.lower.clear();
+        self.upper.clear();
+        self.lower_len = 0;
+        self.upper_len = 0;
+        self.expired.clear();
+    }
+}
+
+impl<T> Size for MovingMedian<T> {
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Lcg;
+
+    #[test]
+    fn expired_samples_do_not_pile_up() {
+        let mut rng = Lcg::new(7);
+        for size in [1, 4, 64] {
+            let mut tracker = MovingMedian::new(size);
+            for _ in 0..500 {
+                tracker.push(rng.below(50));
+                assert!(tracker.lower.len() + tracker.upper.len() <= 2 * size + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn push_evicts_and_clear() {
+        let mut tracker = MovingMedian::new(2);
+        assert_eq!(tracker.median(), None);
+        assert_eq!(tracker.middle_pair(), None);
+
+        assert_eq!(tracker.push(10), None);
+        assert_eq!(tracker.push(20), None);
+        assert_eq!(tracker.middle_pair(), Some((&10, &20)));
+        assert_eq!(tracker.push(30), Some(10));
+        assert_eq!(tracker.iter().copied().collect::<Vec<_>>(), vec![20, 30]);
+        assert_eq!(tracker.median(), Some(&20));
+
+        tracker.clear();
+        assert!(tracker.is_empty());
+        tracker.push(5);
+        assert_eq!(tracker.middle_pair(), Some((&5, &5)));
+    }
+}
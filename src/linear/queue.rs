@@ -1,6 +1,6 @@
 //! Queue implementation with FIFO (First In, First Out) semantics
 
-use crate::utils::{Clear, Peek, Size};
+use crate::utils::{Clear, Drain, Peek, PeekMut, Size};
 
 /// A queue data structure with FIFO semantics
 ///
@@ -71,6 +71,11 @@ impl<T> Queue<T> {
         }
     }
 
+    /// Returns a mutable reference to the front element
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.data.get_mut(self.front)
+    }
+
     /// Returns a reference to the back element without removing it
     pub fn back(&self) -> Option<&T> {
         if self.data.len() > self.front {
@@ -80,10 +85,50 @@ impl<T> Queue<T> {
         }
     }
 
+    /// Returns the element `idx` positions behind the front
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.live().get(idx)
+    }
+
+    /// Returns true if the queue holds an element equal to `item`
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.live().contains(item)
+    }
+
+    /// Returns the distance from the front of the first element matching `predicate`
+    pub fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.live().iter().position(predicate)
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving
+    /// their front-to-back order
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        let front = self.front.min(self.data.len());
+        self.data.drain(..front);
+        self.front = 0;
+        self.data.retain(f);
+    }
+
     /// Returns the current capacity of the queue
     pub fn capacity(&self) -> usize {
         self.data.capacity()
     }
+
+    /// Estimates the bytes held by the queue, counting allocated capacity
+    /// but not memory owned by the elements themselves
+    pub fn memory_usage_bytes(&self) -> usize {
+        std::mem::size_of::<Self>() + self.data.capacity() * std::mem::size_of::<T>()
+    }
+
+    fn live(&self) -> &[T] {
+        self.data.get(self.front..).unwrap_or(&[])
+    }
 }
 
 impl<T> Default for Queue<T> {
@@ -115,6 +160,50 @@ impl<T> Peek<T> for Queue<T> {
     }
 }
 
+impl<T> PeekMut<T> for Queue<T> {
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.front_mut()
+    }
+}
+
+impl<T> Drain for Queue<T> {
+    type Item = T;
+
+    /// Yields the elements from front to back
+    fn drain(&mut self) -> Box<dyn Iterator<Item = T> + '_> {
+        let front = std::mem::take(&mut self.front);
+        Box::new(self.data.drain(..).skip(front))
+    }
+}
+
+/// The first element of the vector becomes the front of the queue
+impl<T> From<Vec<T>> for Queue<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self { data, front: 0 }
+    }
+}
+
+impl<T> Extend<T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.data.extend(iter);
+    }
+}
+
+/// Borrowed items join the back of the queue in iteration order
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::Queue;
+///
+/// let mut queue = Queue::new();
+/// queue.extend(&[1, 2, 3]);
+/// assert_eq!(queue.dequeue(), Some(1));
+/// ```
+impl<'a, T: Copy + 'a> Extend<&'a T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.data.extend(iter.into_iter().copied());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +249,73 @@ mod tests {
         assert_eq!(queue.front(), Some(&2));
         assert_eq!(queue.back(), Some(&3));
     }
+
+    #[test]
+    fn peek_mut_front() {
+        let mut queue = Queue::new();
+        assert_eq!(queue.peek_mut(), None);
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.dequeue();
+        queue.enqueue(3);
+
+        if let Some(front) = queue.peek_mut() {
+            *front *= 10;
+        }
+
+        assert_eq!(queue.dequeue(), Some(20));
+        assert_eq!(queue.dequeue(), Some(3));
+    }
+
+    #[test]
+    fn from_vec_keeps_first_in_front() {
+        let mut queue = Queue::from(vec![1, 2, 3]);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(4);
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+    }
+
+    #[test]
+    fn logical_indexing_after_dequeues() {
+        let mut queue = Queue::from((0..40).collect::<Vec<_>>());
+        for _ in 0..25 {
+            queue.dequeue();
+        }
+
+        assert_eq!(queue.get(0), queue.front());
+        assert_eq!(queue.get(0), Some(&25));
+        assert_eq!(queue.get(14), Some(&39));
+        assert_eq!(queue.get(15), None);
+        assert_eq!(queue.position(|&item| item == 30), Some(5));
+        assert_eq!(queue.position(|&item| item == 3), None);
+        assert!(queue.contains(&39));
+        assert!(!queue.contains(&24));
+    }
+
+    #[test]
+    fn retain_after_dequeues() {
+        let mut queue = Queue::from((0..10).collect::<Vec<_>>());
+        queue.dequeue();
+        queue.dequeue();
+        queue.retain(|&item| item % 3 != 0);
+
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.front(), Some(&2));
+        assert_eq!(queue.get(4), Some(&8));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(4));
+    }
+
+    #[test]
+    fn memory_usage_follows_capacity() {
+        let queue = Queue::from((0..100u32).collect::<Vec<_>>());
+        assert_eq!(
+            queue.memory_usage_bytes(),
+            std::mem::size_of::<Queue<u32>>() + queue.capacity() * 4
+        );
+    }
 }
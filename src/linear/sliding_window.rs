@@ -0,0 +1,157 @@
+//! Sliding window over a stream with amortized O(1) min and max
+
+use crate::linear::CircularBuffer;
+use crate::utils::{Capacity, Clear, Size};
+use std::collections::VecDeque;
+use std::iter::Sum;
+
+/// Keeps the last `size` pushed values and tracks their minimum and maximum
+///
+/// Minimum and maximum use monotonic deques of `(sequence, value)` pairs, so
+/// each value is added and removed from them at most once.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::SlidingWindow;
+///
+/// let mut window = SlidingWindow::new(3);
+/// for value in [4, 2, 7, 5] {
+///     window.push(value);
+/// }
+/// assert_eq!(window.min(), Some(&2));
+/// assert_eq!(window.max(), Some(&7));
+/// assert_eq!(window.sum(), 14);
+/// ```
+pub struct SlidingWindow<T> {
+    window: CircularBuffer<T>,
+    pushed: usize,
+    min_candidates: VecDeque<(usize, T)>,
+    max_candidates: VecDeque<(usize, T)>,
+}
+
+impl<T: Clone + PartialOrd> SlidingWindow<T> {
+    /// Creates a window holding the last `size` values
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn new(size: usize) -> Self {
+        Self {
+            window: CircularBuffer::new(size),
+            pushed: 0,
+            min_candidates: VecDeque::new(),
+            max_candidates: VecDeque::new(),
+        }
+    }
+
+    /// Adds a value, returning the value that fell out of the window, if any
+    pub fn push(&mut self, item: T) -> Option<T> {
+        let sequence = self.pushed;
+        self.pushed += 1;
+
+        while self.min_candidates.back().is_some_and(|(_, v)| *v > item) {
+            self.min_candidates.pop_back();
+        }
+        self.min_candidates.push_back((sequence, item.clone()));
+
+        while self.max_candidates.back().is_some_and(|(_, v)| *v < item) {
+            self.max_candidates.pop_back();
+        }
+        self.max_candidates.push_back((sequence, item.clone()));
+
+        let evicted = self.window.push_back(item);
+
+        let oldest = self.pushed - self.window.len();
+        while self
+            .min_candidates
+            .front()
+            .is_some_and(|(s, _)| *s < oldest)
+        {
+            self.min_candidates.pop_front();
+        }
+        while self
+            .max_candidates
+            .front()
+            .is_some_and(|(s, _)| *s < oldest)
+        {
+            self.max_candidates.pop_front();
+        }
+
+        evicted
+    }
+
+    pub fn min(&self) -> Option<&T> {
+        self.min_candidates.front().map(|(_, v)| v)
+    }
+
+    pub fn max(&self) -> Option<&T> {
+        self.max_candidates.front().map(|(_, v)| v)
+    }
+
+    /// Returns the maximum number of values the window holds
+    pub fn window_size(&self) -> usize {
+        self.window.capacity()
+    }
+
+    /// Returns an iterator from the oldest to the newest value in the window
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.window.iter()
+    }
+
+    pub fn sum(&self) -> T
+    where
+        T: Sum<T>,
+    {
+        self.iter().cloned().sum()
+    }
+
+    /// Returns the mean of the values in the window, or `None` if it is empty
+    pub fn mean(&self) -> Option<f64>
+    where
+        T: Into<f64>,
+    {
+        if self.window.is_empty() {
+            return None;
+        }
+        let total: f64 = self.iter().cloned().map(Into::into).sum();
+        Some(total / self.window.len() as f64)
+    }
+}
+
+impl<T> Clear for SlidingWindow<T> {
+    fn clear(&mut self) {
+        self.window.clear();
+        self.min_candidates.clear();
+        self.max_candidates.clear();
+    }
+}
+
+impl<T> Size for SlidingWindow<T> {
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_evicts_and_mean() {
+        let mut window = SlidingWindow::new(2);
+        assert_eq!(window.mean(), None);
+        assert_eq!(window.min(), None);
+
+        assert_eq!(window.push(1.0), None);
+        assert_eq!(window.push(3.0), None);
+        assert_eq!(window.push(8.0), Some(1.0));
+        assert_eq!(window.mean(), Some(5.5));
+        assert_eq!(window.iter().cloned().collect::<Vec<_>>(), vec![3.0, 8.0]);
+
+        window.clear();
+        assert!(window.is_empty());
+        window.push(-1.0);
+        assert_eq!(window.max(), Some(&-1.0));
+    }
+}
@@ -1,6 +1,10 @@
 //! Stack implementation with LIFO (Last In, First Out) semantics
 
-use crate::utils::{Clear, Peek, PeekMut, Size};
+use crate::utils::{Clear, Drain, Peek, PeekMut, Size};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Shared by every stack, so a checkpoint never matches one on another stack
+static NEXT_CHECKPOINT: AtomicU64 = AtomicU64::new(0);
 
 /// A stack data structure with LIFO semantics
 ///
@@ -16,22 +20,39 @@ use crate::utils::{Clear, Peek, PeekMut, Size};
 /// assert_eq!(stack.pop(), Some(1));
 /// assert_eq!(stack.pop(), None);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Stack<T> {
     data: Vec<T>,
+    // Open checkpoints as `(depth, id)`, oldest first and never deeper than `data`
+    checkpoints: Vec<(usize, u64)>,
+}
+
+/// A saved stack depth returned by [`Stack::checkpoint`]
+///
+/// A checkpoint stays valid until it is rolled back to or committed, an
+/// enclosing checkpoint is, or the stack is popped below its depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    depth: usize,
+    id: u64,
+}
+
+impl Checkpoint {
+    /// Returns the stack length at the time the checkpoint was taken
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
 }
 
 impl<T> Stack<T> {
     /// Creates a new empty stack
     pub fn new() -> Self {
-        Self { data: Vec::new() }
+        Self::from(Vec::new())
     }
 
     /// Creates a new stack with the specified capacity
     pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            data: Vec::with_capacity(capacity),
-        }
+        Self::from(Vec::with_capacity(capacity))
     }
 
     /// Pushes an element onto the top of the stack
@@ -41,13 +62,86 @@ impl<T> Stack<T> {
 
     /// Removes and returns the top element from the stack
     pub fn pop(&mut self) -> Option<T> {
-        self.data.pop()
+        let item = self.data.pop();
+        self.forget_checkpoints_above(self.data.len());
+        item
+    }
+
+    /// Records the current depth so later pushes can be undone with
+    /// [`Stack::rollback_to`]
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        let checkpoint = Checkpoint {
+            depth: self.data.len(),
+            id: NEXT_CHECKPOINT.fetch_add(1, Ordering::Relaxed),
+        };
+        self.checkpoints.push((checkpoint.depth, checkpoint.id));
+        checkpoint
+    }
+
+    /// Truncates the stack back to the depth of `checkpoint`, returning the
+    /// removed items bottom-to-top
+    ///
+    /// Only pushes are undone: an element below the checkpoint changed through
+    /// `peek_mut` keeps its new value. The checkpoint and every checkpoint
+    /// taken after it are closed. Returns `None`, leaving the stack unchanged,
+    /// if the checkpoint is no longer valid or was taken on another stack.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> Option<Vec<T>> {
+        let position = self.checkpoint_position(checkpoint)?;
+        let (depth, _) = self.checkpoints[position];
+        self.checkpoints.truncate(position);
+        Some(self.data.split_off(depth))
+    }
+
+    /// Keeps everything pushed since `checkpoint` and closes it along with
+    /// every checkpoint taken after it
+    ///
+    /// Returns false if the checkpoint is no longer valid or was taken on
+    /// another stack.
+    pub fn commit(&mut self, checkpoint: Checkpoint) -> bool {
+        match self.checkpoint_position(checkpoint) {
+            Some(position) => {
+                self.checkpoints.truncate(position);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn checkpoint_position(&self, checkpoint: Checkpoint) -> Option<usize> {
+        self.checkpoints
+            .iter()
+            .rposition(|&entry| entry == (checkpoint.depth, checkpoint.id))
+    }
+
+    fn forget_checkpoints_above(&mut self, len: usize) {
+        while self
+            .checkpoints
+            .last()
+            .is_some_and(|&(depth, _)| depth > len)
+        {
+            self.checkpoints.pop();
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving
+    /// their bottom-to-top order
+    ///
+    /// Any removal may reach below a checkpoint, so all checkpoints are closed.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.data.retain(f);
+        self.checkpoints.clear();
     }
 
     /// Returns the current capacity of the stack
     pub fn capacity(&self) -> usize {
         self.data.capacity()
     }
+
+    /// Estimates the bytes held by the stack, counting allocated capacity
+    /// but not memory owned by the elements themselves
+    pub fn memory_usage_bytes(&self) -> usize {
+        std::mem::size_of::<Self>() + self.data.capacity() * std::mem::size_of::<T>()
+    }
 }
 
 impl<T> Default for Stack<T> {
@@ -56,9 +150,19 @@ impl<T> Default for Stack<T> {
     }
 }
 
+impl<T: PartialEq> PartialEq for Stack<T> {
+    /// Compares the elements only, ignoring open checkpoints
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T: Eq> Eq for Stack<T> {}
+
 impl<T> Clear for Stack<T> {
     fn clear(&mut self) {
         self.data.clear();
+        self.forget_checkpoints_above(0);
     }
 }
 
@@ -80,6 +184,47 @@ impl<T> PeekMut<T> for Stack<T> {
     }
 }
 
+impl<T> Drain for Stack<T> {
+    type Item = T;
+
+    /// Yields the elements from the top of the stack down
+    fn drain(&mut self) -> Box<dyn Iterator<Item = T> + '_> {
+        self.forget_checkpoints_above(0);
+        Box::new(self.data.drain(..).rev())
+    }
+}
+
+/// The last element of the vector becomes the top of the stack
+impl<T> From<Vec<T>> for Stack<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self {
+            data,
+            checkpoints: Vec::new(),
+        }
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.data.extend(iter);
+    }
+}
+
+/// The last borrowed item ends up on top of the stack
+///
+/// ```rust
+/// use rust_ds_lib_bee::linear::Stack;
+///
+/// let mut stack = Stack::new();
+/// stack.extend(&[1, 2, 3]);
+/// assert_eq!(stack.pop(), Some(3));
+/// ```
+impl<'a, T: Copy + 'a> Extend<&'a T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.data.extend(iter.into_iter().copied());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,4 +263,126 @@ mod tests {
         stack.push(100);
         assert_eq!(stack.peek(), Some(&100));
     }
+
+    #[test]
+    fn from_vec_keeps_last_on_top() {
+        let mut stack = Stack::from(vec![1, 2, 3]);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+    }
+
+    #[test]
+    fn retain_keeps_bottom_to_top_order() {
+        let mut stack = Stack::from((0..10).collect::<Vec<_>>());
+        stack.retain(|&item| item % 2 == 0);
+
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack, Stack::from(vec![0, 2, 4, 6, 8]));
+        assert_eq!(stack.peek(), Some(&8));
+    }
+
+    #[test]
+    fn nested_checkpoints() {
+        let mut stack = Stack::from(vec![1]);
+        let outer = stack.checkpoint();
+        stack.push(2);
+        let inner = stack.checkpoint();
+        stack.push(3);
+        stack.push(4);
+
+        assert_eq!(inner.depth(), 2);
+        assert_eq!(stack.rollback_to(inner), Some(vec![3, 4]));
+        assert_eq!(stack, Stack::from(vec![1, 2]));
+
+        let inner = stack.checkpoint();
+        stack.push(5);
+        assert!(stack.commit(inner));
+        assert_eq!(stack.len(), 3);
+
+        assert_eq!(stack.rollback_to(outer), Some(vec![2, 5]));
+        assert_eq!(stack, Stack::from(vec![1]));
+    }
+
+    #[test]
+    fn stale_checkpoints_are_rejected() {
+        let mut stack = Stack::new();
+        let outer = stack.checkpoint();
+        stack.push('a');
+        let inner = stack.checkpoint();
+        stack.push('b');
+
+        assert!(stack.commit(outer));
+        assert!(!stack.commit(inner));
+        assert_eq!(stack.rollback_to(inner), None);
+        assert_eq!(stack.rollback_to(outer), None);
+        assert_eq!(stack.len(), 2);
+
+        let below = stack.checkpoint();
+        stack.pop();
+        stack.pop();
+        stack.push('c');
+        stack.push('d');
+        assert_eq!(stack.rollback_to(below), None);
+        assert_eq!(stack.peek(), Some(&'d'));
+
+        stack.clear();
+        let at_bottom = stack.checkpoint();
+        stack.push('e');
+        stack.clear();
+        stack.push('f');
+        assert_eq!(stack.rollback_to(at_bottom), Some(vec!['f']));
+    }
+
+    #[test]
+    fn foreign_checkpoints_are_rejected() {
+        let mut deep = Stack::from(vec![1, 2, 3, 4]);
+        let foreign = deep.checkpoint();
+
+        let mut shallow = Stack::new();
+        let own = shallow.checkpoint();
+        shallow.push(9);
+        assert_eq!(shallow.rollback_to(foreign), None);
+        assert!(!shallow.commit(foreign));
+        assert_eq!(shallow.len(), 1);
+        assert_eq!(shallow.rollback_to(own), Some(vec![9]));
+
+        let mut other = Stack::from(vec![7]);
+        let own = other.checkpoint();
+        other.extend([8, 9, 10, 11]);
+        assert_eq!(other.rollback_to(foreign), None);
+        assert_eq!(other.rollback_to(own), Some(vec![8, 9, 10, 11]));
+
+        deep.push(5);
+        assert_eq!(deep.rollback_to(foreign), Some(vec![5]));
+    }
+
+    #[test]
+    fn fresh_stacks_reject_each_others_checkpoints() {
+        let mut first: Stack<i32> = Stack::new();
+        let mut second: Stack<i32> = Stack::new();
+        let from_first = first.checkpoint();
+        let from_second = second.checkpoint();
+
+        second.push(1);
+        assert_eq!(second.rollback_to(from_first), None);
+        assert!(!first.commit(from_second));
+        assert_eq!(second.len(), 1);
+
+        assert!(first.commit(from_first));
+        assert_eq!(second.rollback_to(from_second), Some(vec![1]));
+    }
+
+    #[test]
+    fn memory_usage_follows_capacity() {
+        let mut stack: Stack<u64> = Stack::with_capacity(64);
+        assert_eq!(
+            stack.memory_usage_bytes(),
+            std::mem::size_of::<Stack<u64>>() + stack.capacity() * 8
+        );
+
+        stack.extend(0..1_000);
+        assert!(stack.memory_usage_bytes() >= 1_000 * 8);
+    }
 }
@@ -0,0 +1,218 @@
+//! Multi-pattern string search over a trie with failure links
+
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub pattern_index: usize,
+    /// Byte offset of the first byte of the match in the haystack
+    pub start: usize,
+    /// Byte offset one past the last byte of the match in the haystack
+    pub end: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    children: HashMap<char, usize>,
+    fail: usize,
+    outputs: Vec<usize>,
+}
+
+impl Node {
+    fn new() -> Self {
+        Self {
+            children: HashMap::new(),
+            fail: 0,
+            outputs: Vec::new(),
+        }
+    }
+}
+
+/// An Aho-Corasick automaton finding every occurrence of a set of patterns
+///
+/// The patterns are stored in a trie whose nodes live in a flat arena, so the
+/// failure links computed by a breadth-first pass can refer to nodes by index.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::tree::AhoCorasick;
+///
+/// let automaton = AhoCorasick::new(["he", "she", "hers"]);
+/// let found: Vec<_> = automaton
+///     .find_all("ushers")
+///     .iter()
+///     .map(|m| &"ushers"[m.start..m.end])
+///     .collect();
+/// assert_eq!(found, vec!["she", "he", "hers"]);
+/// ```
+pub struct AhoCorasick {
+    nodes: Vec<Node>,
+    pattern_lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Builds the automaton; empty patterns never match
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut nodes = vec![Node::new()];
+        let mut pattern_lengths = Vec::new();
+
+        for (index, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            pattern_lengths.push(pattern.len());
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut current = 0;
+            for ch in pattern.chars() {
+                current = match nodes[current].children.get(&ch) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(Node::new());
+                        let child = nodes.len() - 1;
+                        nodes[current].children.insert(ch, child);
+                        child
+                    }
+                };
+            }
+            nodes[current].outputs.push(index);
+        }
+
+        Self::link_failures(&mut nodes);
+
+        Self {
+            nodes,
+            pattern_lengths,
+        }
+    }
+
+    fn link_failures(nodes: &mut [Node]) {
+        let mut queue: VecDeque<usize> = nodes[0].children.values().copied().collect();
+
+        while let Some(current) = queue.pop_front() {
+            let children: Vec<(char, usize)> = nodes[current]
+                .children
+                .iter()
+                .map(|(&ch, &child)| (ch, child))
+                .collect();
+
+            for (ch, child) in children {
+                let mut fallback = nodes[current].fail;
+                let fail = loop {
+                    if let Some(&next) = nodes[fallback].children.get(&ch) {
+                        break next;
+                    }
+                    if fallback == 0 {
+                        break 0;
+                    }
+                    fallback = nodes[fallback].fail;
+                };
+
+                nodes[child].fail = fail;
+                let inherited = nodes[fail].outputs.clone();
+                nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        self.pattern_lengths.len()
+    }
+
+    /// Returns every occurrence of every pattern, including overlapping ones,
+    /// ordered by end position and then from longest to shortest
+    pub fn find_all(&self, haystack: &str) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut state = 0;
+
+        for (offset, ch) in haystack.char_indices() {
+            loop {
+                if let Some(&next) = self.nodes[state].children.get(&ch) {
+                    state = next;
+                    break;
+                }
+                if state == 0 {
+                    break;
+                }
+                state = self.nodes[state].fail;
+            }
+
+            let end = offset + ch.len_utf8();
+            for &pattern_index in &self.nodes[state].outputs {
+                matches.push(Match {
+                    pattern_index,
+                    start: end - self.pattern_lengths[pattern_index],
+                    end,
+                });
+            }
+        }
+
+        matches
+    }
+
+    pub fn is_match(&self, haystack: &str) -> bool {
+        !self.find_all(haystack).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matched<'a>(automaton: &AhoCorasick, haystack: &'a str) -> Vec<(usize, &'a str)> {
+        automaton
+            .find_all(haystack)
+            .iter()
+            .map(|m| (m.pattern_index, &haystack[m.start..m.end]))
+            .collect()
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        let automaton = AhoCorasick::new(["he", "she", "his", "hers"]);
+        assert_eq!(automaton.pattern_count(), 4);
+        assert_eq!(
+            matched(&automaton, "ushers"),
+            vec![(1, "she"), (0, "he"), (3, "hers")]
+        );
+        assert!(!automaton.is_match("xyz"));
+    }
+
+    #[test]
+    fn prefix_patterns() {
+        let automaton = AhoCorasick::new(["a", "ab", "abc", "", "bc"]);
+        assert_eq!(
+            matched(&automaton, "abcab"),
+            vec![
+                (0, "a"),
+                (1, "ab"),
+                (2, "abc"),
+                (4, "bc"),
+                (0, "a"),
+                (1, "ab")
+            ]
+        );
+
+        let repeated = AhoCorasick::new(["aa"]);
+        let starts: Vec<_> = repeated.find_all("aaaa").iter().map(|m| m.start).collect();
+        assert_eq!(starts, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn unicode_haystack() {
+        let automaton = AhoCorasick::new(["café", "é", "日本"]);
+        let haystack = "un café à 日本語";
+        assert_eq!(
+            matched(&automaton, haystack),
+            vec![(0, "café"), (1, "é"), (2, "日本")]
+        );
+        let m = automaton.find_all(haystack)[2];
+        assert_eq!((m.start, m.end), (12, 18));
+    }
+}
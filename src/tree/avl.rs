@@ -1,11 +1,16 @@
+use crate::tree::ordered::{self, BinaryNode};
+use crate::tree::render;
 use crate::utils::{Clear, Size};
 use std::cmp::{max, Ordering};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone)]
 struct Node<T> {
     data: T,
     height: i32,
+    // Number of nodes in the subtree rooted here
+    size: usize,
     left: Option<Box<Node<T>>>,
     right: Option<Box<Node<T>>>,
 }
@@ -15,15 +20,21 @@ impl<T> Node<T> {
         Self {
             data,
             height: 1,
+            size: 1,
             left: None,
             right: None,
         }
     }
 
-    fn update_height(&mut self) {
+    fn update(&mut self) {
         let left_height = self.left.as_ref().map_or(0, |n| n.height);
         let right_height = self.right.as_ref().map_or(0, |n| n.height);
         self.height = 1 + max(left_height, right_height);
+        self.size = 1 + Self::size_of(&self.left) + Self::size_of(&self.right);
+    }
+
+    fn size_of(node: &Option<Box<Node<T>>>) -> usize {
+        node.as_ref().map_or(0, |n| n.size)
     }
 
     fn balance_factor(&self) -> i32 {
@@ -31,6 +42,34 @@ impl<T> Node<T> {
         let right_height = self.right.as_ref().map_or(0, |n| n.height);
         left_height - right_height
     }
+
+    fn joined(data: T, left: Option<Box<Self>>, right: Option<Box<Self>>) -> Box<Self> {
+        let mut node = Box::new(Self::new(data));
+        node.left = left;
+        node.right = right;
+        node.update();
+        node
+    }
+}
+
+impl<T> BinaryNode for Node<T> {
+    type Item = T;
+
+    fn data(&self) -> &T {
+        &self.data
+    }
+
+    fn left(&self) -> &Option<Box<Self>> {
+        &self.left
+    }
+
+    fn right(&self) -> &Option<Box<Self>> {
+        &self.right
+    }
+
+    fn into_parts(self: Box<Self>) -> (T, Option<Box<Self>>, Option<Box<Self>>) {
+        (self.data, self.left, self.right)
+    }
 }
 
 pub struct AvlTree<T> {
@@ -76,35 +115,59 @@ impl<T: Ord> AvlTree<T> {
                     }
                 };
 
-                n.update_height();
+                n.update();
                 (Some(Self::balance(n)), inserted)
             }
         }
     }
 
     pub fn remove(&mut self, data: &T) -> bool {
-        let (new_root, removed) = Self::remove_recursive(self.root.take(), data);
+        self.remove_with(&mut |node| data.cmp(node)).is_some()
+    }
+
+    /// Removes and returns the element whose projected key equals `key`
+    ///
+    /// `project` must be consistent with the tree's ordering.
+    pub fn remove_by<K, F>(&mut self, key: &K, project: F) -> Option<T>
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        self.remove_with(&mut |node| key.cmp(project(node)))
+    }
+
+    fn remove_with<F>(&mut self, cmp: &mut F) -> Option<T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (new_root, removed) = Self::remove_recursive(self.root.take(), cmp);
         self.root = new_root;
-        if removed {
+        if removed.is_some() {
             self.size -= 1;
         }
         removed
     }
 
-    fn remove_recursive(node: Option<Box<Node<T>>>, data: &T) -> (Option<Box<Node<T>>>, bool) {
+    fn remove_recursive<F>(
+        node: Option<Box<Node<T>>>,
+        cmp: &mut F,
+    ) -> (Option<Box<Node<T>>>, Option<T>)
+    where
+        F: FnMut(&T) -> Ordering,
+    {
         match node {
-            None => (None, false),
-            Some(mut n) => match data.cmp(&n.data) {
+            None => (None, None),
+            Some(mut n) => match cmp(&n.data) {
                 Ordering::Less => {
-                    let (left, removed) = Self::remove_recursive(n.left.take(), data);
+                    let (left, removed) = Self::remove_recursive(n.left.take(), cmp);
                     n.left = left;
-                    n.update_height();
+                    n.update();
                     (Some(Self::balance(n)), removed)
                 }
                 Ordering::Greater => {
-                    let (right, removed) = Self::remove_recursive(n.right.take(), data);
+                    let (right, removed) = Self::remove_recursive(n.right.take(), cmp);
                     n.right = right;
-                    n.update_height();
+                    n.update();
                     (Some(Self::balance(n)), removed)
                 }
                 Ordering::Equal => {
@@ -116,11 +179,11 @@ impl<T: Ord> AvlTree<T> {
                             let (mut successor, new_right) = Self::extract_min(right);
                             successor.left = Some(left);
                             successor.right = new_right;
-                            successor.update_height();
+                            successor.update();
                             Some(Self::balance(successor))
                         }
                     };
-                    (result, true)
+                    (result, Some(n.data))
                 }
             },
         }
@@ -135,7 +198,7 @@ impl<T: Ord> AvlTree<T> {
             Some(left) => {
                 let (min_node, new_left) = Self::extract_min(left);
                 node.left = new_left;
-                node.update_height();
+                node.update();
                 (min_node, Some(Self::balance(node)))
             }
         }
@@ -166,18 +229,18 @@ impl<T: Ord> AvlTree<T> {
     fn rotate_left(mut node: Box<Node<T>>) -> Box<Node<T>> {
         let mut new_root = node.right.take().unwrap();
         node.right = new_root.left.take();
-        node.update_height();
+        node.update();
         new_root.left = Some(node);
-        new_root.update_height();
+        new_root.update();
         new_root
     }
 
     fn rotate_right(mut node: Box<Node<T>>) -> Box<Node<T>> {
         let mut new_root = node.left.take().unwrap();
         node.left = new_root.right.take();
-        node.update_height();
+        node.update();
         new_root.right = Some(node);
-        new_root.update_height();
+        new_root.update();
         new_root
     }
 
@@ -196,6 +259,62 @@ impl<T: Ord> AvlTree<T> {
         }
     }
 
+    pub fn get(&self, key: &T) -> Option<&T> {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            match key.cmp(&node.data) {
+                Ordering::Less => current = &node.left,
+                Ordering::Greater => current = &node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+
+    /// Returns a mutable reference to the stored element equal to `key`
+    ///
+    /// The element must not be modified in a way that changes its ordering
+    /// relative to other elements, or the tree will no longer find it.
+    pub fn get_mut(&mut self, key: &T) -> Option<&mut T> {
+        let mut current = &mut self.root;
+        while let Some(node) = current {
+            match key.cmp(&node.data) {
+                Ordering::Less => current = &mut node.left,
+                Ordering::Greater => current = &mut node.right,
+                Ordering::Equal => return Some(&mut node.data),
+            }
+        }
+        None
+    }
+
+    /// Returns the element whose projected key equals `key`
+    ///
+    /// `project` must be consistent with the tree's ordering, e.g. returning
+    /// the field that `Ord` compares.
+    pub fn get_by<K, F>(&self, key: &K, project: F) -> Option<&T>
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            match key.cmp(project(&node.data)) {
+                Ordering::Less => current = &node.left,
+                Ordering::Greater => current = &node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+
+    pub fn contains_by<K, F>(&self, key: &K, project: F) -> bool
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        self.get_by(key, project).is_some()
+    }
+
     pub fn min(&self) -> Option<&T> {
         Self::min_recursive(&self.root)
     }
@@ -230,6 +349,39 @@ impl<T: Ord> AvlTree<T> {
         }
     }
 
+    /// Returns the number of elements strictly less than `value` in O(log n)
+    pub fn rank(&self, value: &T) -> usize {
+        let mut rank = 0;
+        let mut current = &self.root;
+        while let Some(node) = current {
+            if *value <= node.data {
+                current = &node.left;
+            } else {
+                rank += Node::size_of(&node.left) + 1;
+                current = &node.right;
+            }
+        }
+        rank
+    }
+
+    /// Returns the element at sorted position `index` in O(log n), or `None`
+    /// if `index` is out of bounds
+    pub fn select(&self, mut index: usize) -> Option<&T> {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            let left_size = Node::size_of(&node.left);
+            match index.cmp(&left_size) {
+                Ordering::Less => current = &node.left,
+                Ordering::Equal => return Some(&node.data),
+                Ordering::Greater => {
+                    index -= left_size + 1;
+                    current = &node.right;
+                }
+            }
+        }
+        None
+    }
+
     pub fn height(&self) -> usize {
         self.root.as_ref().map_or(0, |n| n.height as usize)
     }
@@ -248,17 +400,42 @@ impl<T: Ord> AvlTree<T> {
         }
     }
 
-    pub fn iter(&self) -> InOrderIter<T> {
-        let mut stack = Vec::new();
-        Self::push_left_spine(&self.root, &mut stack);
-        InOrderIter { stack }
+    /// Consumes the tree and returns its elements in sorted order
+    ///
+    /// Nodes are taken apart in place, so `T` does not need to be `Clone`.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        ordered::into_sorted_vec(self.root.take(), self.size)
     }
 
-    fn push_left_spine<'a>(mut node: &'a Option<Box<Node<T>>>, stack: &mut Vec<&'a Node<T>>) {
-        while let Some(n) = node {
-            stack.push(n);
-            node = &n.left;
-        }
+    /// Keeps only the elements for which `f` returns `true`
+    ///
+    /// The survivors are rebuilt into a perfectly balanced tree in O(n).
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.size = ordered::retain(&mut self.root, self.size, f, Node::joined);
+    }
+
+    pub fn iter(&self) -> InOrderIter<'_, T> {
+        InOrderIter::new(&self.root)
+    }
+
+    /// Returns an in-order iterator starting at the first element not less than `start`
+    pub fn iter_from(&self, start: &T) -> InOrderIter<'_, T> {
+        InOrderIter::lower_bound(&self.root, start)
+    }
+
+    /// Returns an in-order iterator yielding each element with its depth,
+    /// where the root is at depth 0
+    pub fn iter_with_depth(&self) -> impl Iterator<Item = (usize, &T)> {
+        ordered::iter_with_depth(&self.root)
+    }
+
+    /// Draws the tree sideways, with the right subtree above each node and
+    /// children indented one level deeper than their parent
+    pub fn render_ascii(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        render::sideways(self.iter_with_depth())
     }
 }
 
@@ -290,20 +467,24 @@ impl<T: fmt::Debug> fmt::Debug for AvlTree<T> {
     }
 }
 
-pub struct InOrderIter<'a, T> {
-    stack: Vec<&'a Node<T>>,
+ordered::sorted_display!(AvlTree);
+
+ordered::in_order_iter!();
+
+/// Trees are equal when they hold the same elements, whatever their shape
+impl<T: Ord> PartialEq for AvlTree<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
 }
 
-impl<'a, T: Ord> Iterator for InOrderIter<'a, T> {
-    type Item = &'a T;
+impl<T: Ord> Eq for AvlTree<T> {}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.stack.pop() {
-            let result = &node.data;
-            AvlTree::push_left_spine(&node.right, &mut self.stack);
-            Some(result)
-        } else {
-            None
+impl<T: Ord + Hash> Hash for AvlTree<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for item in self.iter() {
+            item.hash(state);
         }
     }
 }
@@ -329,6 +510,7 @@ impl<T: Ord> Extend<T> for AvlTree<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::rng::Lcg;
 
     #[test]
     fn new_tree_is_empty() {
@@ -459,4 +641,97 @@ mod tests {
 
         assert_eq!(tree.len(), 50);
     }
+
+    #[test]
+    fn equality_and_hash_ignore_shape() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let ascending: AvlTree<_> = (1..=20).collect();
+        let shuffled: AvlTree<_> = (1..=20).map(|i| (i * 7) % 20 + 1).collect();
+        assert_eq!(ascending, shuffled);
+        assert_eq!(hash_of(&ascending), hash_of(&shuffled));
+
+        let mut fewer = shuffled;
+        fewer.remove(&20);
+        assert_ne!(ascending, fewer);
+        assert_ne!(hash_of(&ascending), hash_of(&fewer));
+        assert_eq!(AvlTree::<i32>::new(), AvlTree::new());
+    }
+
+    #[test]
+    fn depth_iteration_and_rendering() {
+        let tree: AvlTree<_> = (1..=7).collect();
+        let depths: Vec<_> = tree.iter_with_depth().map(|(d, &v)| (d, v)).collect();
+        assert_eq!(
+            depths,
+            vec![(2, 1), (1, 2), (2, 3), (0, 4), (2, 5), (1, 6), (2, 7)]
+        );
+        assert_eq!(
+            tree.render_ascii(),
+            "        7\n    6\n        5\n4\n        3\n    2\n        1\n"
+        );
+
+        let mut single = AvlTree::new();
+        assert_eq!(single.render_ascii(), "");
+        single.insert("root");
+        assert_eq!(single.render_ascii(), "root\n");
+    }
+
+    #[test]
+    fn cloned_iterators_find_pair_sums() {
+        let mut rng = Lcg::new(17);
+        let tree: AvlTree<i64> = (0..60).map(|_| rng.below(200) as i64).collect();
+        let values: Vec<i64> = tree.iter().copied().collect();
+
+        for target in [0, 57, 150, 199, 250, 398, 401] {
+            let brute = values
+                .iter()
+                .enumerate()
+                .any(|(i, a)| values[i + 1..].iter().any(|b| a + b == target));
+
+            let mut outer = tree.iter();
+            let mut found = false;
+            while let Some(&a) = outer.next() {
+                // Resume from the outer position without rescanning the prefix
+                let mut inner = outer.clone();
+                while inner.peek().is_some_and(|&b| a + b < target) {
+                    inner.next();
+                }
+                if inner.peek() == Some(&(target - a)) {
+                    found = true;
+                    break;
+                }
+            }
+
+            assert_eq!(found, brute, "target {target}");
+        }
+    }
+
+    #[test]
+    fn rank_and_select_track_updates() {
+        let mut tree: AvlTree<i32> = (0..64).map(|i| i * 2).collect();
+        tree.remove(&10);
+        tree.remove(&64);
+        tree.insert(11);
+
+        let sorted: Vec<i32> = tree.iter().copied().collect();
+        for (index, value) in sorted.iter().enumerate() {
+            assert_eq!(tree.rank(value), index);
+            assert_eq!(tree.select(index), Some(value));
+        }
+        assert_eq!(tree.rank(&10), 5);
+        assert_eq!(tree.rank(&-1), 0);
+        assert_eq!(tree.rank(&1000), tree.len());
+        assert_eq!(tree.select(tree.len()), None);
+
+        tree.retain(|value| value % 3 == 0);
+        assert_eq!(tree.select(1), Some(&6));
+        assert_eq!(tree.rank(&7), 2);
+    }
 }
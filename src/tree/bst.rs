@@ -1,5 +1,7 @@
 //! Binary Search Tree implementation with ordered operations
 
+use crate::tree::ordered::{self, BinaryNode};
+use crate::tree::render;
 use crate::utils::{Clear, Size};
 use std::cmp::Ordering;
 use std::fmt;
@@ -19,6 +21,30 @@ impl<T> Node<T> {
             right: None,
         }
     }
+
+    fn joined(data: T, left: Option<Box<Self>>, right: Option<Box<Self>>) -> Box<Self> {
+        Box::new(Self { data, left, right })
+    }
+}
+
+impl<T> BinaryNode for Node<T> {
+    type Item = T;
+
+    fn data(&self) -> &T {
+        &self.data
+    }
+
+    fn left(&self) -> &Option<Box<Self>> {
+        &self.left
+    }
+
+    fn right(&self) -> &Option<Box<Self>> {
+        &self.right
+    }
+
+    fn into_parts(self: Box<Self>) -> (T, Option<Box<Self>>, Option<Box<Self>>) {
+        (self.data, self.left, self.right)
+    }
 }
 
 /// A Binary Search Tree maintaining ordered data
@@ -78,21 +104,43 @@ where
     }
 
     pub fn remove(&mut self, data: &T) -> bool {
-        let removed = Self::remove_recursive(&mut self.root, data);
-        if removed {
+        self.remove_with(&mut |node| data.cmp(node)).is_some()
+    }
+
+    /// Removes and returns the element whose projected key equals `key`
+    ///
+    /// `project` must be consistent with the tree's ordering.
+    pub fn remove_by<K, F>(&mut self, key: &K, project: F) -> Option<T>
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        self.remove_with(&mut |node| key.cmp(project(node)))
+    }
+
+    fn remove_with<F>(&mut self, cmp: &mut F) -> Option<T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let removed = Self::remove_recursive(&mut self.root, cmp);
+        if removed.is_some() {
             self.size -= 1;
         }
         removed
     }
 
-    fn remove_recursive(node: &mut Option<Box<Node<T>>>, data: &T) -> bool {
+    fn remove_recursive<F>(node: &mut Option<Box<Node<T>>>, cmp: &mut F) -> Option<T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
         match node {
-            None => false,
-            Some(ref mut n) => match data.cmp(&n.data) {
-                Ordering::Less => Self::remove_recursive(&mut n.left, data),
-                Ordering::Greater => Self::remove_recursive(&mut n.right, data),
+            None => None,
+            Some(ref mut n) => match cmp(&n.data) {
+                Ordering::Less => Self::remove_recursive(&mut n.left, cmp),
+                Ordering::Greater => Self::remove_recursive(&mut n.right, cmp),
                 Ordering::Equal => {
-                    *node = match (n.left.take(), n.right.take()) {
+                    let mut removed = node.take().unwrap();
+                    *node = match (removed.left.take(), removed.right.take()) {
                         (None, None) => None,
                         (Some(left), None) => Some(left),
                         (None, Some(right)) => Some(right),
@@ -112,7 +160,7 @@ where
                             }
                         }
                     };
-                    true
+                    Some(removed.data)
                 }
             },
         }
@@ -148,6 +196,120 @@ where
         }
     }
 
+    /// Returns a reference to the stored element equal to `key`
+    pub fn get(&self, key: &T) -> Option<&T> {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            match key.cmp(&node.data) {
+                Ordering::Less => current = &node.left,
+                Ordering::Greater => current = &node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+
+    /// Returns a mutable reference to the stored element equal to `key`
+    ///
+    /// The element must not be modified in a way that changes its ordering
+    /// relative to other elements, or the tree will no longer find it.
+    pub fn get_mut(&mut self, key: &T) -> Option<&mut T> {
+        let mut current = &mut self.root;
+        while let Some(node) = current {
+            match key.cmp(&node.data) {
+                Ordering::Less => current = &mut node.left,
+                Ordering::Greater => current = &mut node.right,
+                Ordering::Equal => return Some(&mut node.data),
+            }
+        }
+        None
+    }
+
+    /// Returns the element whose projected key equals `key`
+    ///
+    /// `project` must be consistent with the tree's ordering, e.g. returning
+    /// the field that `Ord` compares.
+    pub fn get_by<K, F>(&self, key: &K, project: F) -> Option<&T>
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            match key.cmp(project(&node.data)) {
+                Ordering::Less => current = &node.left,
+                Ordering::Greater => current = &node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+
+    pub fn contains_by<K, F>(&self, key: &K, project: F) -> bool
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        self.get_by(key, project).is_some()
+    }
+
+    /// Returns the values compared on the way down from the root, not
+    /// including the match, together with the matching element
+    pub fn find_with_path(&self, value: &T) -> Option<(Vec<&T>, &T)> {
+        let (path, found) = self.search_path(value);
+        found.map(|found| (path, found))
+    }
+
+    /// Describes each comparison made while searching for `value`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_ds_lib_bee::tree::BinarySearchTree;
+    ///
+    /// let tree: BinarySearchTree<_> = [8, 3, 10].into_iter().collect();
+    /// assert_eq!(
+    ///     tree.explain_contains(&3),
+    ///     "3 < 8, go left\n3 == 3, found"
+    /// );
+    /// ```
+    pub fn explain_contains(&self, value: &T) -> String
+    where
+        T: fmt::Debug,
+    {
+        let (path, found) = self.search_path(value);
+        let mut steps: Vec<String> = path
+            .into_iter()
+            .map(|node| {
+                if value < node {
+                    format!("{value:?} < {node:?}, go left")
+                } else {
+                    format!("{value:?} > {node:?}, go right")
+                }
+            })
+            .collect();
+
+        steps.push(match found {
+            Some(node) => format!("{value:?} == {node:?}, found"),
+            None => format!("reached an empty subtree, {value:?} not found"),
+        });
+        steps.join("\n")
+    }
+
+    fn search_path(&self, value: &T) -> (Vec<&T>, Option<&T>) {
+        let mut path = Vec::new();
+        let mut current = &self.root;
+        while let Some(node) = current {
+            match value.cmp(&node.data) {
+                Ordering::Less => current = &node.left,
+                Ordering::Greater => current = &node.right,
+                Ordering::Equal => return (path, Some(&node.data)),
+            }
+            path.push(&node.data);
+        }
+        (path, None)
+    }
+
     pub fn min(&self) -> Option<&T> {
         Self::min_recursive(&self.root)
     }
@@ -198,17 +360,42 @@ where
         }
     }
 
-    pub fn iter(&self) -> InOrderIter<T> {
-        let mut stack = Vec::new();
-        Self::push_left_spine(&self.root, &mut stack);
-        InOrderIter { stack }
+    /// Consumes the tree and returns its elements in sorted order
+    ///
+    /// Nodes are taken apart in place, so `T` does not need to be `Clone`.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        ordered::into_sorted_vec(self.root.take(), self.size)
     }
 
-    fn push_left_spine<'a>(mut node: &'a Option<Box<Node<T>>>, stack: &mut Vec<&'a Node<T>>) {
-        while let Some(n) = node {
-            stack.push(n);
-            node = &n.left;
-        }
+    /// Keeps only the elements for which `f` returns `true`
+    ///
+    /// The survivors are rebuilt into a perfectly balanced tree in O(n).
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.size = ordered::retain(&mut self.root, self.size, f, Node::joined);
+    }
+
+    pub fn iter(&self) -> InOrderIter<'_, T> {
+        InOrderIter::new(&self.root)
+    }
+
+    /// Returns an in-order iterator starting at the first element not less than `start`
+    pub fn iter_from(&self, start: &T) -> InOrderIter<'_, T> {
+        InOrderIter::lower_bound(&self.root, start)
+    }
+
+    /// Returns an in-order iterator yielding each element with its depth,
+    /// where the root is at depth 0
+    pub fn iter_with_depth(&self) -> impl Iterator<Item = (usize, &T)> {
+        ordered::iter_with_depth(&self.root)
+    }
+
+    /// Draws the tree sideways, with the right subtree above each node and
+    /// children indented one level deeper than their parent
+    pub fn render_ascii(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        render::sideways(self.iter_with_depth())
     }
 }
 
@@ -240,23 +427,9 @@ impl<T: fmt::Debug> fmt::Debug for BinarySearchTree<T> {
     }
 }
 
-pub struct InOrderIter<'a, T> {
-    stack: Vec<&'a Node<T>>,
-}
-
-impl<'a, T: Ord> Iterator for InOrderIter<'a, T> {
-    type Item = &'a T;
+ordered::sorted_display!(BinarySearchTree);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.stack.pop() {
-            let result = &node.data;
-            BinarySearchTree::push_left_spine(&node.right, &mut self.stack);
-            Some(result)
-        } else {
-            None
-        }
-    }
-}
+ordered::in_order_iter!();
 
 impl<T: Ord> FromIterator<T> for BinarySearchTree<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
@@ -405,4 +578,45 @@ mod tests {
         assert_eq!(tree.len(), 0);
         assert!(!tree.contains(&5));
     }
+
+    #[test]
+    fn find_with_path_records_descent() {
+        let tree: BinarySearchTree<_> = [8, 3, 10, 1, 6, 14, 4, 7, 13].into_iter().collect();
+
+        let (path, found) = tree.find_with_path(&7).unwrap();
+        assert_eq!(path, vec![&8, &3, &6]);
+        assert_eq!(found, &7);
+
+        let (path, found) = tree.find_with_path(&8).unwrap();
+        assert!(path.is_empty());
+        assert_eq!(found, &8);
+
+        assert_eq!(tree.find_with_path(&5), None);
+        assert_eq!(tree.search_path(&5).0, vec![&8, &3, &6, &4]);
+    }
+
+    #[test]
+    fn explain_contains_trail() {
+        let tree: BinarySearchTree<_> = [8, 3, 10, 1, 6, 14, 4, 7, 13].into_iter().collect();
+
+        assert_eq!(
+            tree.explain_contains(&13),
+            "13 > 8, go right\n13 > 10, go right\n13 < 14, go left\n13 == 13, found"
+        );
+        assert_eq!(
+            tree.explain_contains(&2),
+            "2 < 8, go left\n2 < 3, go left\n2 > 1, go right\nreached an empty subtree, 2 not found"
+        );
+        assert_eq!(
+            BinarySearchTree::new().explain_contains(&1),
+            "reached an empty subtree, 1 not found"
+        );
+    }
+
+    #[test]
+    fn render_ascii_shows_shape() {
+        let tree: BinarySearchTree<_> = [1, 2, 3].into_iter().collect();
+        assert_eq!(tree.render_ascii(), "        3\n    2\n1\n");
+        assert_eq!(tree.iter_with_depth().map(|(d, _)| d).max(), Some(2));
+    }
 }
@@ -0,0 +1,30 @@
+//! Test fixtures shared by the ordered tree tests
+
+use std::cmp::Ordering;
+
+/// A record ordered by `id` alone, so `balance` can change in place
+#[derive(Debug)]
+pub(crate) struct Account {
+    pub(crate) id: u32,
+    pub(crate) balance: i64,
+}
+
+impl PartialEq for Account {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Account {}
+
+impl PartialOrd for Account {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Account {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
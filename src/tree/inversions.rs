@@ -0,0 +1,50 @@
+//! Sortedness measures built on the order statistics of [`AvlTree`]
+
+use crate::tree::AvlTree;
+use crate::utils::Size;
+
+/// Counts the pairs `i < j` with `values[i] > values[j]` in O(n log n)
+///
+/// Each element is inserted into an [`AvlTree`] tagged with its index, so
+/// equal values stay distinct, and [`AvlTree::rank`] gives how many earlier
+/// elements are not greater than it. Sorted input has no inversions and
+/// reversed input of length `n` has `n * (n - 1) / 2`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::tree::count_inversions;
+///
+/// assert_eq!(count_inversions(&[1, 2, 3]), 0);
+/// assert_eq!(count_inversions(&[3, 1, 2]), 2);
+/// ```
+pub fn count_inversions<T: Ord>(values: &[T]) -> u64 {
+    let mut seen = AvlTree::new();
+    let mut inversions = 0;
+
+    for (index, value) in values.iter().enumerate() {
+        // Every earlier entry has a smaller index, so this counts the earlier
+        // values less than or equal to `value`
+        let not_greater = seen.rank(&(value, usize::MAX));
+        inversions += (seen.len() - not_greater) as u64;
+        seen.insert((value, index));
+    }
+    inversions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_and_reversed() {
+        let sorted: Vec<u32> = (0..200).collect();
+        assert_eq!(count_inversions(&sorted), 0);
+
+        let reversed: Vec<u32> = (0..200).rev().collect();
+        assert_eq!(count_inversions(&reversed), 200 * 199 / 2);
+
+        assert_eq!(count_inversions::<u8>(&[]), 0);
+        assert_eq!(count_inversions(&[5, 5, 5]), 0);
+    }
+}
@@ -1,11 +1,21 @@
 //! Tree-based data structures
 
+pub mod aho_corasick;
 pub mod avl;
 pub mod bst;
+#[cfg(test)]
+mod fixtures;
+pub mod inversions;
+mod ordered;
 pub mod red_black;
+mod render;
+pub mod suffix_trie;
 pub mod trie;
 
+pub use aho_corasick::AhoCorasick;
 pub use avl::AvlTree;
 pub use bst::BinarySearchTree;
+pub use inversions::count_inversions;
 pub use red_black::RedBlackTree;
+pub use suffix_trie::SuffixTrie;
 pub use trie::Trie;
@@ -0,0 +1,388 @@
+//! Traversal and formatting code shared by the ordered binary trees
+//!
+//! [`BinarySearchTree`](crate::tree::BinarySearchTree),
+//! [`AvlTree`](crate::tree::AvlTree) and
+//! [`RedBlackTree`](crate::tree::RedBlackTree) only differ in how they
+//! rebalance; walking the nodes in order is the same for all three, so it is
+//! written once here against [`BinaryNode`].
+
+/// Read access to a tree node, plus a way to take it apart
+pub(crate) trait BinaryNode: Sized {
+    type Item;
+
+    fn data(&self) -> &Self::Item;
+    fn left(&self) -> &Option<Box<Self>>;
+    fn right(&self) -> &Option<Box<Self>>;
+
+    /// Splits the node into its element and its left and right subtrees
+    fn into_parts(self: Box<Self>) -> (Self::Item, Option<Box<Self>>, Option<Box<Self>>);
+}
+
+/// Pushes `node` and its chain of left children, so the top of the stack is
+/// the smallest element of the subtree
+pub(crate) fn push_left_spine<'a, N: BinaryNode>(
+    mut node: &'a Option<Box<N>>,
+    stack: &mut Vec<&'a N>,
+) {
+    while let Some(n) = node {
+        stack.push(n);
+        node = n.left();
+    }
+}
+
+/// Pushes the ancestors an in-order walk from the first element not less
+/// than `start` still has to visit
+pub(crate) fn push_lower_bound_path<'a, N>(
+    mut node: &'a Option<Box<N>>,
+    start: &N::Item,
+    stack: &mut Vec<&'a N>,
+) where
+    N: BinaryNode,
+    N::Item: Ord,
+{
+    while let Some(n) = node {
+        if n.data() >= start {
+            stack.push(n);
+            node = n.left();
+        } else {
+            node = n.right();
+        }
+    }
+}
+
+/// Walks the tree in order, pairing each element with its depth below `root`
+pub(crate) fn iter_with_depth<N: BinaryNode>(
+    root: &Option<Box<N>>,
+) -> impl Iterator<Item = (usize, &N::Item)> {
+    let mut stack: Vec<(&N, usize)> = Vec::new();
+    let mut current = root.as_deref();
+    let mut depth = 0;
+
+    std::iter::from_fn(move || {
+        while let Some(node) = current {
+            stack.push((node, depth));
+            current = node.left().as_deref();
+            depth += 1;
+        }
+
+        let (node, node_depth) = stack.pop()?;
+        current = node.right().as_deref();
+        depth = node_depth + 1;
+        Some((node_depth, node.data()))
+    })
+}
+
+/// Takes the tree apart node by node, moving the elements out in order
+pub(crate) fn into_sorted_vec<N: BinaryNode>(root: Option<Box<N>>, len: usize) -> Vec<N::Item> {
+    let mut sorted = Vec::with_capacity(len);
+    let mut stack = Vec::new();
+    let mut current = root;
+
+    loop {
+        while let Some(node) = current {
+            let (data, left, right) = node.into_parts();
+            current = left;
+            stack.push((data, right));
+        }
+        let Some((data, right)) = stack.pop() else {
+            break;
+        };
+        current = right;
+        sorted.push(data);
+    }
+
+    sorted
+}
+
+/// Drops the elements `keep` rejects and rebuilds the survivors into a
+/// perfectly balanced tree in O(n), returning the new length
+///
+/// `join` builds a node from an element and its finished subtrees, so trees
+/// that cache per-node data can fill it in on the way up.
+pub(crate) fn retain<N, F, J>(root: &mut Option<Box<N>>, len: usize, mut keep: F, join: J) -> usize
+where
+    N: BinaryNode,
+    F: FnMut(&N::Item) -> bool,
+    J: Fn(N::Item, Option<Box<N>>, Option<Box<N>>) -> Box<N>,
+{
+    let mut survivors = into_sorted_vec(root.take(), len);
+    survivors.retain(|item| keep(item));
+
+    let len = survivors.len();
+    *root = build_balanced(&mut survivors.into_iter(), len, &join);
+    len
+}
+
+/// Builds a balanced subtree from the next `len` items of a sorted iterator
+fn build_balanced<N, I, J>(items: &mut I, len: usize, join: &J) -> Option<Box<N>>
+where
+    N: BinaryNode,
+    I: Iterator<Item = N::Item>,
+    J: Fn(N::Item, Option<Box<N>>, Option<Box<N>>) -> Box<N>,
+{
+    if len == 0 {
+        return None;
+    }
+
+    let left = build_balanced(items, len / 2, join);
+    let data = items.next()?;
+    let right = build_balanced(items, len - len / 2 - 1, join);
+    Some(join(data, left, right))
+}
+
+/// Defines `InOrderIter` for a tree module whose `Node<T>` implements
+/// [`BinaryNode`]
+macro_rules! in_order_iter {
+    () => {
+        pub struct InOrderIter<'a, T> {
+            stack: Vec<&'a Node<T>>,
+        }
+
+        impl<'a, T> InOrderIter<'a, T> {
+            fn new(root: &'a Option<Box<Node<T>>>) -> Self {
+                let mut stack = Vec::new();
+                $crate::tree::ordered::push_left_spine(root, &mut stack);
+                Self { stack }
+            }
+
+            fn lower_bound(root: &'a Option<Box<Node<T>>>, start: &T) -> Self
+            where
+                T: Ord,
+            {
+                let mut stack = Vec::new();
+                $crate::tree::ordered::push_lower_bound_path(root, start, &mut stack);
+                Self { stack }
+            }
+
+            /// Returns the element the next call to `next` will yield
+            pub fn peek(&self) -> Option<&'a T> {
+                self.stack.last().map(|node| &node.data)
+            }
+        }
+
+        /// Cloning copies only the pending ancestor stack, so the copy resumes
+        /// from the same position without touching the tree
+        impl<T> Clone for InOrderIter<'_, T> {
+            fn clone(&self) -> Self {
+                Self {
+                    stack: self.stack.clone(),
+                }
+            }
+        }
+
+        impl<'a, T: Ord> Iterator for InOrderIter<'a, T> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let node = self.stack.pop()?;
+                $crate::tree::ordered::push_left_spine(&node.right, &mut self.stack);
+                Some(&node.data)
+            }
+        }
+    };
+}
+
+pub(crate) use in_order_iter;
+
+/// Implements `Display` and [`DisplayLimited`](crate::utils::DisplayLimited)
+/// for a tree with an in-order `iter`
+macro_rules! sorted_display {
+    ($tree:ident) => {
+        impl<T: Ord + std::fmt::Display> $crate::utils::DisplayLimited for $tree<T> {
+            fn fmt_with_limit(
+                &self,
+                f: &mut std::fmt::Formatter<'_>,
+                limit: usize,
+            ) -> std::fmt::Result {
+                $crate::utils::display::write_list(f, "[", "]", self.iter(), limit)
+            }
+        }
+
+        /// Shows the elements in sorted order, eliding past
+        /// [`DISPLAY_LIMIT`](crate::utils::DISPLAY_LIMIT)
+        impl<T: Ord + std::fmt::Display> std::fmt::Display for $tree<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                $crate::utils::DisplayLimited::fmt_with_limit(self, f, $crate::utils::DISPLAY_LIMIT)
+            }
+        }
+    };
+}
+
+pub(crate) use sorted_display;
+
+#[cfg(test)]
+mod tests {
+    use crate::tree::fixtures::Account;
+    use crate::tree::{AvlTree, BinarySearchTree, RedBlackTree};
+    use crate::utils::{DisplayLimited, Size, DISPLAY_LIMIT};
+
+    macro_rules! traversal_tests {
+        ($($module:ident: $tree:ident),* $(,)?) => {$(
+            mod $module {
+                use super::*;
+
+                #[test]
+                fn iter_from_pages() {
+                    let n = 10_000;
+                    let tree: $tree<i32> = (0..n).map(|i| (i * 7919) % n).collect();
+                    let sorted: Vec<i32> = (0..n).collect();
+
+                    let mut visited = Vec::new();
+                    let mut next = 0;
+                    for expected in sorted.chunks(100) {
+                        let page: Vec<i32> = tree.iter_from(&next).take(100).cloned().collect();
+                        assert_eq!(page, expected);
+                        next = page.last().unwrap() + 1;
+                        visited.extend(page);
+                    }
+                    assert_eq!(visited, sorted);
+
+                    assert_eq!(tree.iter_from(&n).next(), None);
+                    assert_eq!(tree.iter_from(&-5).next(), Some(&0));
+                }
+
+                #[test]
+                fn iter_from_between_elements() {
+                    let tree: $tree<i32> = [10, 20, 30, 40].into_iter().collect();
+                    let values: Vec<_> = tree.iter_from(&25).cloned().collect();
+                    assert_eq!(values, vec![30, 40]);
+
+                    let empty: $tree<i32> = $tree::new();
+                    assert_eq!(empty.iter_from(&0).next(), None);
+                }
+
+                #[test]
+                fn iterator_clone_and_peek() {
+                    let tree: $tree<i32> = [4, 2, 6, 1, 3, 5, 7].into_iter().collect();
+                    let mut iter = tree.iter_from(&3);
+                    assert_eq!(iter.peek(), Some(&3));
+                    iter.next();
+
+                    let checkpoint = iter.clone();
+                    assert_eq!(iter.collect::<Vec<_>>(), vec![&4, &5, &6, &7]);
+                    assert_eq!(checkpoint.peek(), Some(&4));
+                    assert_eq!(checkpoint.count(), 4);
+                }
+
+                #[test]
+                fn depths_follow_iteration_order() {
+                    let tree: $tree<i32> = (0..50).map(|i| (i * 17) % 50).collect();
+                    let (depths, values): (Vec<usize>, Vec<i32>) =
+                        tree.iter_with_depth().map(|(d, &v)| (d, v)).unzip();
+
+                    assert_eq!(values, (0..50).collect::<Vec<_>>());
+                    assert_eq!(depths.iter().filter(|&&d| d == 0).count(), 1);
+                    assert_eq!(depths.iter().max().map(|d| d + 1), Some(tree.height()));
+                    assert_eq!($tree::<i32>::new().iter_with_depth().next(), None);
+                }
+
+                #[test]
+                fn display_is_sorted_and_limited() {
+                    let tree: $tree<i32> = [5, 2, 8, 1].into_iter().collect();
+                    assert_eq!(tree.to_string(), "[1, 2, 5, 8]");
+                    assert_eq!(tree.fmt_limited(3).to_string(), "[1, 2, 5, ...]");
+                    assert_eq!($tree::<i32>::new().to_string(), "[]");
+
+                    let large: $tree<usize> = (0..100).collect();
+                    assert!(large
+                        .to_string()
+                        .ends_with(&format!("{}, ...]", DISPLAY_LIMIT - 1)));
+                }
+
+                #[test]
+                fn into_sorted_vec_moves_elements() {
+                    // A payload without `Clone` proves no element is copied
+                    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+                    struct Word(String);
+
+                    let mut tree = $tree::new();
+                    for word in ["pear", "apple", "fig", "kiwi", "banana", "cherry"] {
+                        tree.insert(Word(word.to_string()));
+                    }
+
+                    let sorted: Vec<String> =
+                        tree.into_sorted_vec().into_iter().map(|w| w.0).collect();
+                    assert_eq!(sorted, ["apple", "banana", "cherry", "fig", "kiwi", "pear"]);
+                    assert!($tree::<Word>::new().into_sorted_vec().is_empty());
+                }
+
+                #[test]
+                fn get_and_get_mut() {
+                    let mut tree = $tree::new();
+                    for (id, balance) in [(5, 50), (3, 30), (7, 70), (1, 10)] {
+                        tree.insert(Account { id, balance });
+                    }
+
+                    let probe = Account { id: 3, balance: 0 };
+                    assert_eq!(tree.get(&probe).map(|a| a.balance), Some(30));
+                    assert!(tree.get(&Account { id: 4, balance: 0 }).is_none());
+
+                    if let Some(account) = tree.get_mut(&probe) {
+                        account.balance += 12;
+                    }
+                    assert_eq!(tree.get(&probe).map(|a| a.balance), Some(42));
+                    assert!(tree.get_mut(&Account { id: 9, balance: 0 }).is_none());
+                    assert_eq!(tree.len(), 4);
+                }
+
+                #[test]
+                fn lookup_and_remove_by_key() {
+                    let mut tree = $tree::new();
+                    for (id, balance) in [(5, 50), (3, 30), (7, 70), (1, 10), (4, 40)] {
+                        tree.insert(Account { id, balance });
+                    }
+
+                    assert_eq!(tree.get_by(&7, |a| &a.id).map(|a| a.balance), Some(70));
+                    assert!(tree.contains_by(&1, |a| &a.id));
+                    assert!(!tree.contains_by(&2, |a| &a.id));
+
+                    let removed = tree.remove_by(&3, |a| &a.id).unwrap();
+                    assert_eq!((removed.id, removed.balance), (3, 30));
+                    assert!(tree.remove_by(&3, |a| &a.id).is_none());
+                    assert_eq!(tree.len(), 4);
+                    assert_eq!(
+                        tree.iter().map(|a| a.id).collect::<Vec<_>>(),
+                        vec![1, 4, 5, 7]
+                    );
+                }
+            }
+        )*};
+    }
+
+    traversal_tests!(bst: BinarySearchTree, avl: AvlTree, red_black: RedBlackTree);
+
+    macro_rules! retain_tests {
+        ($($module:ident: $tree:ident),* $(,)?) => {$(
+            mod $module {
+                use super::*;
+
+                #[test]
+                fn retain_even_values() {
+                    let mut tree: $tree<i32> = (0..100).collect();
+                    tree.retain(|value| value % 2 == 0);
+
+                    assert_eq!(tree.len(), 50);
+                    assert_eq!(
+                        tree.iter().copied().collect::<Vec<_>>(),
+                        (0..100).step_by(2).collect::<Vec<_>>()
+                    );
+                    // 50 elements fit in 6 full levels
+                    assert!(tree.height() <= 6);
+                    assert!(!tree.contains(&51));
+
+                    tree.insert(51);
+                    assert!(tree.contains(&51));
+                    tree.retain(|_| false);
+                    assert!(tree.is_empty());
+                    assert_eq!(tree.min(), None);
+                }
+            }
+        )*};
+    }
+
+    mod retain {
+        use super::*;
+
+        retain_tests!(bst: BinarySearchTree, avl: AvlTree);
+    }
+}
@@ -1,6 +1,9 @@
+use crate::tree::ordered::{self, BinaryNode};
+use crate::tree::render;
 use crate::utils::{Clear, Size};
 use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Color {
@@ -8,6 +11,15 @@ enum Color {
     Black,
 }
 
+impl Color {
+    fn flipped(self) -> Self {
+        match self {
+            Color::Red => Color::Black,
+            Color::Black => Color::Red,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Node<T> {
     data: T,
@@ -35,6 +47,26 @@ impl<T> Node<T> {
     }
 }
 
+impl<T> BinaryNode for Node<T> {
+    type Item = T;
+
+    fn data(&self) -> &T {
+        &self.data
+    }
+
+    fn left(&self) -> &Option<Box<Self>> {
+        &self.left
+    }
+
+    fn right(&self) -> &Option<Box<Self>> {
+        &self.right
+    }
+
+    fn into_parts(self: Box<Self>) -> (T, Option<Box<Self>>, Option<Box<Self>>) {
+        (self.data, self.left, self.right)
+    }
+}
+
 pub struct RedBlackTree<T> {
     root: Option<Box<Node<T>>>,
     size: usize,
@@ -81,13 +113,129 @@ impl<T: Ord> RedBlackTree<T> {
                     }
                 };
 
-                let balanced = Self::balance_after_insert(n);
-                (Some(balanced), inserted)
+                (Some(Self::balance(n)), inserted)
+            }
+        }
+    }
+
+    pub fn remove(&mut self, data: &T) -> bool {
+        self.remove_with(&mut |node| data.cmp(node)).is_some()
+    }
+
+    /// Removes and returns the element whose projected key equals `key`
+    ///
+    /// `project` must be consistent with the tree's ordering.
+    pub fn remove_by<K, F>(&mut self, key: &K, project: F) -> Option<T>
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        self.remove_with(&mut |node| key.cmp(project(node)))
+    }
+
+    fn remove_with<F>(&mut self, cmp: &mut F) -> Option<T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        // The top-down deletion below relies on the key being present
+        self.find_with(cmp)?;
+
+        let mut root = self.root.take().unwrap();
+        if !Self::is_red_optional(&root.left) && !Self::is_red_optional(&root.right) {
+            root.color = Color::Red;
+        }
+
+        let (new_root, removed) = Self::remove_recursive(root, cmp);
+        self.root = new_root;
+        if let Some(ref mut root) = self.root {
+            root.color = Color::Black;
+        }
+        self.size -= 1;
+        Some(removed)
+    }
+
+    fn remove_recursive<F>(mut node: Box<Node<T>>, cmp: &mut F) -> (Option<Box<Node<T>>>, T)
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let removed = if cmp(&node.data) == Ordering::Less {
+            if !Self::is_red_optional(&node.left) && !Self::is_left_left_red(&node) {
+                node = Self::move_red_left(node);
+            }
+            let (left, removed) = Self::remove_recursive(node.left.take().unwrap(), cmp);
+            node.left = left;
+            removed
+        } else {
+            if Self::is_red_optional(&node.left) {
+                node = Self::rotate_right(node);
             }
+            if cmp(&node.data) == Ordering::Equal && node.right.is_none() {
+                return (None, node.data);
+            }
+            if !Self::is_red_optional(&node.right)
+                && !node
+                    .right
+                    .as_ref()
+                    .is_some_and(|right| Self::is_red_optional(&right.left))
+            {
+                node = Self::move_red_right(node);
+            }
+            if cmp(&node.data) == Ordering::Equal {
+                let (right, min) = Self::remove_min(node.right.take().unwrap());
+                node.right = right;
+                std::mem::replace(&mut node.data, min)
+            } else {
+                let (right, removed) = Self::remove_recursive(node.right.take().unwrap(), cmp);
+                node.right = right;
+                removed
+            }
+        };
+
+        (Some(Self::balance(node)), removed)
+    }
+
+    fn remove_min(mut node: Box<Node<T>>) -> (Option<Box<Node<T>>>, T) {
+        if node.left.is_none() {
+            return (None, node.data);
+        }
+        if !Self::is_red_optional(&node.left) && !Self::is_left_left_red(&node) {
+            node = Self::move_red_left(node);
         }
+        let (left, min) = Self::remove_min(node.left.take().unwrap());
+        node.left = left;
+        (Some(Self::balance(node)), min)
     }
 
-    fn balance_after_insert(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    fn move_red_left(mut node: Box<Node<T>>) -> Box<Node<T>> {
+        Self::flip_colors(&mut node);
+        if node
+            .right
+            .as_ref()
+            .is_some_and(|right| Self::is_red_optional(&right.left))
+        {
+            node.right = Some(Self::rotate_right(node.right.take().unwrap()));
+            node = Self::rotate_left(node);
+            Self::flip_colors(&mut node);
+        }
+        node
+    }
+
+    fn move_red_right(mut node: Box<Node<T>>) -> Box<Node<T>> {
+        Self::flip_colors(&mut node);
+        if Self::is_left_left_red(&node) {
+            node = Self::rotate_right(node);
+            Self::flip_colors(&mut node);
+        }
+        node
+    }
+
+    fn is_left_left_red(node: &Node<T>) -> bool {
+        node.left
+            .as_ref()
+            .is_some_and(|left| Self::is_red_optional(&left.left))
+    }
+
+    fn balance(mut node: Box<Node<T>>) -> Box<Node<T>> {
         if Self::is_red_optional(&node.right) && !Self::is_red_optional(&node.left) {
             node = Self::rotate_left(node);
         }
@@ -127,12 +275,12 @@ impl<T: Ord> RedBlackTree<T> {
     }
 
     fn flip_colors(node: &mut Box<Node<T>>) {
-        node.color = Color::Red;
+        node.color = node.color.flipped();
         if let Some(ref mut left) = node.left {
-            left.color = Color::Black;
+            left.color = left.color.flipped();
         }
         if let Some(ref mut right) = node.right {
-            right.color = Color::Black;
+            right.color = right.color.flipped();
         }
     }
 
@@ -155,6 +303,61 @@ impl<T: Ord> RedBlackTree<T> {
         }
     }
 
+    pub fn get(&self, key: &T) -> Option<&T> {
+        self.find_with(&mut |node| key.cmp(node))
+    }
+
+    /// Returns a mutable reference to the stored element equal to `key`
+    ///
+    /// The element must not be modified in a way that changes its ordering
+    /// relative to other elements, or the tree will no longer find it.
+    pub fn get_mut(&mut self, key: &T) -> Option<&mut T> {
+        let mut current = &mut self.root;
+        while let Some(node) = current {
+            match key.cmp(&node.data) {
+                Ordering::Less => current = &mut node.left,
+                Ordering::Greater => current = &mut node.right,
+                Ordering::Equal => return Some(&mut node.data),
+            }
+        }
+        None
+    }
+
+    /// Returns the element whose projected key equals `key`
+    ///
+    /// `project` must be consistent with the tree's ordering, e.g. returning
+    /// the field that `Ord` compares.
+    pub fn get_by<K, F>(&self, key: &K, project: F) -> Option<&T>
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        self.find_with(&mut |node| key.cmp(project(node)))
+    }
+
+    pub fn contains_by<K, F>(&self, key: &K, project: F) -> bool
+    where
+        K: Ord,
+        F: Fn(&T) -> &K,
+    {
+        self.get_by(key, project).is_some()
+    }
+
+    fn find_with<F>(&self, cmp: &mut F) -> Option<&T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            match cmp(&node.data) {
+                Ordering::Less => current = &node.left,
+                Ordering::Greater => current = &node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+
     pub fn min(&self) -> Option<&T> {
         Self::min_recursive(&self.root)
     }
@@ -240,17 +443,35 @@ impl<T: Ord> RedBlackTree<T> {
         }
     }
 
-    pub fn iter(&self) -> InOrderIter<T> {
-        let mut stack = Vec::new();
-        Self::push_left_spine(&self.root, &mut stack);
-        InOrderIter { stack }
+    /// Consumes the tree and returns its elements in sorted order
+    ///
+    /// Nodes are taken apart in place, so `T` does not need to be `Clone`.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        ordered::into_sorted_vec(self.root.take(), self.size)
     }
 
-    fn push_left_spine<'a>(mut node: &'a Option<Box<Node<T>>>, stack: &mut Vec<&'a Node<T>>) {
-        while let Some(n) = node {
-            stack.push(n);
-            node = &n.left;
-        }
+    pub fn iter(&self) -> InOrderIter<'_, T> {
+        InOrderIter::new(&self.root)
+    }
+
+    /// Returns an in-order iterator starting at the first element not less than `start`
+    pub fn iter_from(&self, start: &T) -> InOrderIter<'_, T> {
+        InOrderIter::lower_bound(&self.root, start)
+    }
+
+    /// Returns an in-order iterator yielding each element with its depth,
+    /// where the root is at depth 0
+    pub fn iter_with_depth(&self) -> impl Iterator<Item = (usize, &T)> {
+        ordered::iter_with_depth(&self.root)
+    }
+
+    /// Draws the tree sideways, with the right subtree above each node and
+    /// children indented one level deeper than their parent
+    pub fn render_ascii(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        render::sideways(self.iter_with_depth())
     }
 }
 
@@ -282,20 +503,24 @@ impl<T: fmt::Debug> fmt::Debug for RedBlackTree<T> {
     }
 }
 
-pub struct InOrderIter<'a, T> {
-    stack: Vec<&'a Node<T>>,
+ordered::sorted_display!(RedBlackTree);
+
+ordered::in_order_iter!();
+
+/// Trees are equal when they hold the same elements, whatever their shape
+impl<T: Ord> PartialEq for RedBlackTree<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
 }
 
-impl<'a, T: Ord> Iterator for InOrderIter<'a, T> {
-    type Item = &'a T;
+impl<T: Ord> Eq for RedBlackTree<T> {}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.stack.pop() {
-            let result = &node.data;
-            RedBlackTree::push_left_spine(&node.right, &mut self.stack);
-            Some(result)
-        } else {
-            None
+impl<T: Ord + Hash> Hash for RedBlackTree<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for item in self.iter() {
+            item.hash(state);
         }
     }
 }
@@ -321,6 +546,7 @@ impl<T: Ord> Extend<T> for RedBlackTree<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::rng::Lcg;
 
     #[test]
     fn new_tree_is_empty() {
@@ -355,6 +581,50 @@ mod tests {
         assert!(tree.height() <= 2 * (tree.len() as f64).log2().ceil() as usize);
     }
 
+    #[test]
+    fn remove_maintains_red_black_properties() {
+        let n = 500;
+        let mut tree: RedBlackTree<i32> = (0..n).map(|i| (i * 7919) % n).collect();
+
+        assert!(!tree.remove(&n));
+        for (step, i) in (0..n).map(|i| (i * 104_729) % n).enumerate() {
+            assert!(tree.remove(&i));
+            assert!(!tree.contains(&i));
+            assert_eq!(tree.len(), (n as usize) - step - 1);
+            assert!(tree.is_valid_red_black_tree());
+        }
+        assert!(tree.is_empty());
+    }
+
+    fn assert_left_leaning(node: &Option<Box<Node<i32>>>) {
+        if let Some(node) = node {
+            assert!(!node.right.as_ref().is_some_and(|right| right.is_red()));
+            assert_left_leaning(&node.left);
+            assert_left_leaning(&node.right);
+        }
+    }
+
+    #[test]
+    fn interleaved_insert_remove_keeps_invariants() {
+        let mut tree = RedBlackTree::new();
+        let mut expected = std::collections::BTreeSet::new();
+        let mut rng = Lcg::new(17);
+
+        for _ in 0..2000 {
+            let value = rng.below(200) as i32;
+            if rng.below(2) == 0 {
+                assert_eq!(tree.insert(value), expected.insert(value));
+            } else {
+                assert_eq!(tree.remove(&value), expected.remove(&value));
+            }
+
+            assert!(tree.is_valid_red_black_tree());
+            assert_left_leaning(&tree.root);
+            assert_eq!(tree.len(), expected.len());
+        }
+        assert!(tree.iter().eq(expected.iter()));
+    }
+
     #[test]
     fn contains_operations() {
         let mut tree = RedBlackTree::new();
@@ -406,4 +676,38 @@ mod tests {
         assert_eq!(tree.len(), 1000);
         assert!(tree.height() <= 20);
     }
+
+    #[test]
+    fn equality_and_hash_ignore_shape() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let ascending: RedBlackTree<_> = (1..=20).collect();
+        let shuffled: RedBlackTree<_> = (1..=20).map(|i| (i * 7) % 20 + 1).collect();
+        assert_eq!(ascending, shuffled);
+        assert_eq!(hash_of(&ascending), hash_of(&shuffled));
+
+        let mut fewer = shuffled;
+        fewer.remove(&20);
+        assert_ne!(ascending, fewer);
+        assert_ne!(hash_of(&ascending), hash_of(&fewer));
+        assert_eq!(RedBlackTree::<i32>::new(), RedBlackTree::new());
+    }
+
+    #[test]
+    fn render_ascii_after_rotations() {
+        let tree: RedBlackTree<_> = [1, 2, 3].into_iter().collect();
+        assert_eq!(tree.render_ascii(), "    3\n2\n    1\n");
+        assert_eq!(
+            tree.iter_with_depth()
+                .map(|(d, &v)| (d, v))
+                .collect::<Vec<_>>(),
+            vec![(1, 1), (0, 2), (1, 3)]
+        );
+    }
 }
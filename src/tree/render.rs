@@ -0,0 +1,25 @@
+//! Text rendering shared by the ordered trees
+
+use std::fmt::{Display, Write};
+
+const INDENT: &str = "    ";
+
+/// Draws a tree sideways from its in-order `(depth, value)` sequence
+///
+/// The right subtree ends up above its parent and the left subtree below, so
+/// turning the output 90° clockwise shows the usual top-down picture.
+pub(crate) fn sideways<'a, T, I>(in_order: I) -> String
+where
+    T: Display + 'a,
+    I: Iterator<Item = (usize, &'a T)>,
+{
+    let rows: Vec<_> = in_order.collect();
+    let mut output = String::new();
+    for (depth, value) in rows.into_iter().rev() {
+        for _ in 0..depth {
+            output.push_str(INDENT);
+        }
+        let _ = writeln!(output, "{value}");
+    }
+    output
+}
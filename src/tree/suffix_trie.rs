@@ -0,0 +1,129 @@
+//! Trie over reversed words for suffix queries
+
+use crate::tree::Trie;
+use crate::utils::{Clear, Size};
+use std::fmt;
+
+fn reversed(word: &str) -> String {
+    word.chars().rev().collect()
+}
+
+/// A trie that stores each word reversed, so suffix queries become prefix queries
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::tree::SuffixTrie;
+///
+/// let trie: SuffixTrie = ["walking", "walked"].into_iter().collect();
+/// assert!(trie.ends_with("ing"));
+/// assert_eq!(trie.find_words_with_suffix("ed"), vec!["walked"]);
+/// ```
+pub struct SuffixTrie {
+    reversed: Trie,
+}
+
+impl SuffixTrie {
+    pub fn new() -> Self {
+        Self {
+            reversed: Trie::new(),
+        }
+    }
+
+    pub fn insert(&mut self, word: &str) -> bool {
+        self.reversed.insert(&reversed(word))
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.reversed.contains(&reversed(word))
+    }
+
+    pub fn remove(&mut self, word: &str) -> bool {
+        self.reversed.remove(&reversed(word))
+    }
+
+    /// Returns true if any stored word ends with `suffix`
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.reversed.starts_with(&reversed(suffix))
+    }
+
+    /// Returns the stored words ending with `suffix`, sorted
+    pub fn find_words_with_suffix(&self, suffix: &str) -> Vec<String> {
+        let mut words: Vec<String> = self
+            .reversed
+            .find_words_with_prefix(&reversed(suffix))
+            .iter()
+            .map(|word| reversed(word))
+            .collect();
+        words.sort();
+        words
+    }
+}
+
+impl Default for SuffixTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clear for SuffixTrie {
+    fn clear(&mut self) {
+        self.reversed.clear();
+    }
+}
+
+impl Size for SuffixTrie {
+    fn len(&self) -> usize {
+        self.reversed.len()
+    }
+}
+
+impl fmt::Debug for SuffixTrie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuffixTrie")
+            .field("words", &self.find_words_with_suffix(""))
+            .finish()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for SuffixTrie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = SuffixTrie::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_words_by_suffix() {
+        let mut trie: SuffixTrie = ["testing", "running", "sing", "sang"].into_iter().collect();
+
+        assert_eq!(
+            trie.find_words_with_suffix("ing"),
+            vec!["running", "sing", "testing"]
+        );
+        assert_eq!(trie.find_words_with_suffix("nning"), vec!["running"]);
+        assert!(trie.find_words_with_suffix("xyz").is_empty());
+        assert!(trie.ends_with("ang"));
+        assert!(!trie.ends_with("sings"));
+
+        assert!(trie.contains("sing"));
+        assert!(!trie.contains("ing"));
+        assert!(trie.remove("sing"));
+        assert_eq!(trie.len(), 3);
+        assert_eq!(trie.find_words_with_suffix("sing"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn reverses_by_character() {
+        let trie: SuffixTrie = ["café", "thé", "日本語"].into_iter().collect();
+        assert_eq!(trie.find_words_with_suffix("é"), vec!["café", "thé"]);
+        assert!(trie.ends_with("本語"));
+    }
+}
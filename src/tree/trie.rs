@@ -1,11 +1,13 @@
+use crate::utils::display::{self, DisplayLimited, DISPLAY_LIMIT};
 use crate::utils::{Clear, Size};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 #[derive(Debug, Clone)]
 struct TrieNode {
     children: HashMap<char, TrieNode>,
     is_end_of_word: bool,
+    word_count: usize,
 }
 
 impl TrieNode {
@@ -13,6 +15,7 @@ impl TrieNode {
         Self {
             children: HashMap::new(),
             is_end_of_word: false,
+            word_count: 0,
         }
     }
 }
@@ -31,32 +34,77 @@ impl Trie {
     }
 
     pub fn insert(&mut self, word: &str) -> bool {
+        if self.contains(word) {
+            return false;
+        }
+
         let mut current = &mut self.root;
+        current.word_count += 1;
 
         for ch in word.chars() {
             current = current.children.entry(ch).or_insert_with(TrieNode::new);
+            current.word_count += 1;
         }
 
-        if current.is_end_of_word {
-            false
-        } else {
-            current.is_end_of_word = true;
-            self.word_count += 1;
-            true
-        }
+        current.is_end_of_word = true;
+        self.word_count += 1;
+        true
     }
 
     pub fn contains(&self, word: &str) -> bool {
         self.find_node(word).is_some_and(|node| node.is_end_of_word)
     }
 
+    pub fn contains_all<'a>(&self, words: impl IntoIterator<Item = &'a str>) -> bool {
+        words.into_iter().all(|word| self.contains(word))
+    }
+
+    /// Returns the words that are not stored, in input order
+    pub fn missing_from<'a>(&self, words: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+        words
+            .into_iter()
+            .filter(|word| !self.contains(word))
+            .collect()
+    }
+
+    /// Returns the words stored in both tries, in unspecified order
+    ///
+    /// Both tries are walked together and only shared branches are visited,
+    /// so the cost depends on the overlap rather than on either trie's size.
+    pub fn intersect_words(&self, other: &Trie) -> Vec<String> {
+        let mut common = Vec::new();
+        let mut buffer = String::new();
+        Self::intersect_nodes(&self.root, &other.root, &mut buffer, &mut common);
+        common
+    }
+
+    fn intersect_nodes(a: &TrieNode, b: &TrieNode, buffer: &mut String, common: &mut Vec<String>) {
+        if a.is_end_of_word && b.is_end_of_word {
+            common.push(buffer.clone());
+        }
+
+        let (smaller, larger) = if a.children.len() <= b.children.len() {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        for (ch, child) in &smaller.children {
+            if let Some(other_child) = larger.children.get(ch) {
+                buffer.push(*ch);
+                Self::intersect_nodes(child, other_child, buffer, common);
+                buffer.pop();
+            }
+        }
+    }
+
     pub fn starts_with(&self, prefix: &str) -> bool {
         self.find_node(prefix).is_some()
     }
 
     pub fn remove(&mut self, word: &str) -> bool {
         if self.contains(word) {
-            Self::remove_recursive_static(&mut self.root, word, 0);
+            let chars: Vec<char> = word.chars().collect();
+            Self::remove_recursive_static(&mut self.root, &chars, 0);
             self.word_count -= 1;
             true
         } else {
@@ -64,7 +112,9 @@ impl Trie {
         }
     }
 
-    fn remove_recursive_static(node: &mut TrieNode, word: &str, index: usize) -> bool {
+    fn remove_recursive_static(node: &mut TrieNode, word: &[char], index: usize) -> bool {
+        node.word_count -= 1;
+
         if index == word.len() {
             if node.is_end_of_word {
                 node.is_end_of_word = false;
@@ -73,7 +123,7 @@ impl Trie {
             return false;
         }
 
-        let ch = word.chars().nth(index).unwrap();
+        let ch = word[index];
 
         if let Some(child) = node.children.get_mut(&ch) {
             let should_delete_child = Self::remove_recursive_static(child, word, index + 1);
@@ -92,21 +142,54 @@ impl Trie {
         let mut result = Vec::new();
 
         if let Some(prefix_node) = self.find_node(prefix) {
-            Self::collect_words(prefix_node, prefix, &mut result);
+            let mut buffer = prefix.to_string();
+            Self::visit_words(prefix_node, &mut buffer, &mut |word| {
+                result.push(word.to_string())
+            });
         }
 
         result
     }
 
-    fn collect_words(node: &TrieNode, current_word: &str, result: &mut Vec<String>) {
+    /// Calls `f` with every stored word in unspecified order
+    ///
+    /// Words are built in one reusable buffer and passed as borrowed slices,
+    /// so no `String` is allocated per word.
+    pub fn for_each_word<F: FnMut(&str)>(&self, mut f: F) {
+        let mut buffer = String::new();
+        Self::visit_words(&self.root, &mut buffer, &mut f);
+    }
+
+    fn visit_words<F: FnMut(&str)>(node: &TrieNode, buffer: &mut String, f: &mut F) {
         if node.is_end_of_word {
-            result.push(current_word.to_string());
+            f(buffer);
         }
 
         for (ch, child_node) in &node.children {
-            let mut next_word = current_word.to_string();
-            next_word.push(*ch);
-            Self::collect_words(child_node, &next_word, result);
+            buffer.push(*ch);
+            Self::visit_words(child_node, buffer, f);
+            buffer.pop();
+        }
+    }
+
+    /// Collects up to `count` words in sorted order, stopping early
+    fn collect_sorted(node: &TrieNode, buffer: &mut String, count: usize, words: &mut Vec<String>) {
+        if words.len() == count {
+            return;
+        }
+        if node.is_end_of_word {
+            words.push(buffer.clone());
+        }
+
+        let mut children: Vec<_> = node.children.iter().collect();
+        children.sort_unstable_by_key(|(ch, _)| **ch);
+        for (ch, child) in children {
+            if words.len() == count {
+                return;
+            }
+            buffer.push(*ch);
+            Self::collect_sorted(child, buffer, count, words);
+            buffer.pop();
         }
     }
 
@@ -123,16 +206,120 @@ impl Trie {
         Some(current)
     }
 
+    /// Returns the shortest prefix of `word` that no other stored word shares.
+    ///
+    /// A word that is itself a prefix of another stored word is returned in full.
+    /// Returns `None` if `word` is not stored.
+    pub fn shortest_unique_prefix(&self, word: &str) -> Option<String> {
+        if !self.contains(word) {
+            return None;
+        }
+
+        let mut prefix = String::new();
+        let mut current = &self.root;
+
+        for ch in word.chars() {
+            current = &current.children[&ch];
+            prefix.push(ch);
+            if current.word_count == 1 {
+                return Some(prefix);
+            }
+        }
+
+        Some(prefix)
+    }
+
+    /// Returns `(word, shortest_unique_prefix)` pairs for every stored word.
+    pub fn all_shortest_unique_prefixes(&self) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+        let mut current_word = String::new();
+        Self::collect_unique_prefixes(&self.root, &mut current_word, None, &mut result);
+        result
+    }
+
+    fn collect_unique_prefixes(
+        node: &TrieNode,
+        current_word: &mut String,
+        unique_len: Option<usize>,
+        result: &mut Vec<(String, String)>,
+    ) {
+        if node.is_end_of_word {
+            let prefix_len = unique_len.unwrap_or(current_word.len());
+            result.push((current_word.clone(), current_word[..prefix_len].to_string()));
+        }
+
+        for (ch, child) in &node.children {
+            current_word.push(*ch);
+            let child_unique_len = match unique_len {
+                None if child.word_count == 1 => Some(current_word.len()),
+                other => other,
+            };
+            Self::collect_unique_prefixes(child, current_word, child_unique_len, result);
+            current_word.pop();
+        }
+    }
+
     pub fn word_count(&self) -> usize {
         self.word_count
     }
 
     pub fn all_words(&self) -> Vec<String> {
+        let mut result = Vec::with_capacity(self.word_count);
+        self.for_each_word(|word| result.push(word.to_string()));
+        result
+    }
+
+    pub fn to_vec_sorted(&self) -> Vec<String> {
+        let mut words = self.all_words();
+        words.sort();
+        words
+    }
+
+    /// Returns every stored word that is a prefix of `word`, shortest first.
+    pub fn prefixes_of(&self, word: &str) -> Vec<String> {
         let mut result = Vec::new();
-        Self::collect_words(&self.root, "", &mut result);
+        let mut current = &self.root;
+
+        if current.is_end_of_word {
+            result.push(String::new());
+        }
+
+        for (i, ch) in word.char_indices() {
+            match current.children.get(&ch) {
+                Some(node) => current = node,
+                None => break,
+            }
+
+            if current.is_end_of_word {
+                result.push(word[..i + ch.len_utf8()].to_string());
+            }
+        }
+
         result
     }
 
+    pub fn alphabet(&self) -> HashSet<char> {
+        self.char_frequencies().into_keys().collect()
+    }
+
+    /// Counts character occurrences across all stored words.
+    ///
+    /// Characters on shared prefix nodes are counted once per word, so
+    /// `["abc", "abd"]` yields two `a`s and two `b`s.
+    pub fn char_frequencies(&self) -> HashMap<char, usize> {
+        let mut frequencies = HashMap::new();
+        let mut stack = vec![&self.root];
+
+        while let Some(node) = stack.pop() {
+            for (ch, child) in &node.children {
+                *frequencies.entry(*ch).or_insert(0) += child.word_count;
+                stack.push(child);
+            }
+        }
+
+        frequencies
+    }
+
     pub fn longest_common_prefix(&self) -> String {
         let mut result = String::new();
         let mut current = &self.root;
@@ -145,6 +332,46 @@ impl Trie {
 
         result
     }
+
+    /// Returns the prefix of at least `min_len` characters shared by the most
+    /// stored words, together with that count
+    ///
+    /// Among prefixes with the same count the longest wins, then the
+    /// lexicographically smallest. Returns `None` if no stored word has
+    /// `min_len` characters.
+    pub fn most_common_prefix(&self, min_len: usize) -> Option<(String, usize)> {
+        let mut best: Option<(String, usize)> = None;
+        let mut prefix = String::new();
+        Self::collect_common_prefix(&self.root, 0, min_len, &mut prefix, &mut best);
+        best
+    }
+
+    fn collect_common_prefix(
+        node: &TrieNode,
+        depth: usize,
+        min_len: usize,
+        prefix: &mut String,
+        best: &mut Option<(String, usize)>,
+    ) {
+        if depth >= min_len && node.word_count > 0 {
+            let better = match best {
+                None => true,
+                Some((best_prefix, best_count)) => (node.word_count, depth)
+                    .cmp(&(*best_count, best_prefix.chars().count()))
+                    .then_with(|| best_prefix.as_str().cmp(prefix))
+                    .is_gt(),
+            };
+            if better {
+                *best = Some((prefix.clone(), node.word_count));
+            }
+        }
+
+        for (ch, child) in &node.children {
+            prefix.push(*ch);
+            Self::collect_common_prefix(child, depth + 1, min_len, prefix, best);
+            prefix.pop();
+        }
+    }
 }
 
 impl Default for Trie {
@@ -175,6 +402,22 @@ impl fmt::Debug for Trie {
     }
 }
 
+impl DisplayLimited for Trie {
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        let mut words = Vec::new();
+        let mut buffer = String::new();
+        Self::collect_sorted(&self.root, &mut buffer, limit.saturating_add(1), &mut words);
+        display::write_list(f, "[", "]", words, limit)
+    }
+}
+
+/// Shows the words in sorted order, eliding past [`DISPLAY_LIMIT`]
+impl fmt::Display for Trie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_limit(f, DISPLAY_LIMIT)
+    }
+}
+
 impl FromIterator<String> for Trie {
     fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
         let mut trie = Trie::new();
@@ -306,6 +549,44 @@ mod tests {
         assert!(words.contains(&"card".to_string()));
     }
 
+    #[test]
+    fn prefixes_of() {
+        let trie: Trie = ["a", "ap", "app", "apple", "banana"].into_iter().collect();
+
+        assert_eq!(trie.prefixes_of("apple"), vec!["a", "ap", "app", "apple"]);
+        assert_eq!(trie.prefixes_of("apricot"), vec!["a", "ap"]);
+        assert_eq!(
+            trie.prefixes_of("applesauce"),
+            vec!["a", "ap", "app", "apple"]
+        );
+        assert!(trie.prefixes_of("cherry").is_empty());
+        assert!(trie.prefixes_of("").is_empty());
+    }
+
+    #[test]
+    fn to_vec_sorted() {
+        let trie: Trie = ["pear", "apple", "peach", "app"].into_iter().collect();
+        assert_eq!(trie.to_vec_sorted(), vec!["app", "apple", "peach", "pear"]);
+    }
+
+    #[test]
+    fn alphabet_and_char_frequencies() {
+        let mut trie: Trie = ["abc", "abd"].into_iter().collect();
+
+        assert_eq!(trie.alphabet(), HashSet::from(['a', 'b', 'c', 'd']));
+        assert_eq!(
+            trie.char_frequencies(),
+            HashMap::from([('a', 2), ('b', 2), ('c', 1), ('d', 1)])
+        );
+
+        trie.insert("banana");
+        trie.remove("abd");
+        let frequencies = trie.char_frequencies();
+        assert_eq!(frequencies[&'a'], 4);
+        assert_eq!(frequencies[&'n'], 2);
+        assert!(!trie.alphabet().contains(&'d'));
+    }
+
     #[test]
     fn longest_common_prefix() {
         let mut trie = Trie::new();
@@ -320,6 +601,61 @@ mod tests {
         assert_eq!(single_word.longest_common_prefix(), "hello");
     }
 
+    #[test]
+    fn shortest_unique_prefix() {
+        let trie: Trie = ["dog", "dot", "do"].into_iter().collect();
+
+        assert_eq!(trie.shortest_unique_prefix("dog"), Some("dog".to_string()));
+        assert_eq!(trie.shortest_unique_prefix("dot"), Some("dot".to_string()));
+        assert_eq!(trie.shortest_unique_prefix("do"), Some("do".to_string()));
+        assert_eq!(trie.shortest_unique_prefix("d"), None);
+
+        let commands: Trie = ["checkout", "cherry-pick", "commit", "clone", "branch"]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            commands.shortest_unique_prefix("checkout"),
+            Some("chec".to_string())
+        );
+        assert_eq!(
+            commands.shortest_unique_prefix("cherry-pick"),
+            Some("cher".to_string())
+        );
+        assert_eq!(
+            commands.shortest_unique_prefix("commit"),
+            Some("co".to_string())
+        );
+        assert_eq!(
+            commands.shortest_unique_prefix("clone"),
+            Some("cl".to_string())
+        );
+        assert_eq!(
+            commands.shortest_unique_prefix("branch"),
+            Some("b".to_string())
+        );
+    }
+
+    #[test]
+    fn all_shortest_unique_prefixes() {
+        let mut trie: Trie = ["zebra", "dog", "duck", "dove"].into_iter().collect();
+
+        let mut prefixes = trie.all_shortest_unique_prefixes();
+        prefixes.sort();
+        let expected: Vec<(String, String)> = [
+            ("dog", "dog"),
+            ("dove", "dov"),
+            ("duck", "du"),
+            ("zebra", "z"),
+        ]
+        .iter()
+        .map(|(w, p)| (w.to_string(), p.to_string()))
+        .collect();
+        assert_eq!(prefixes, expected);
+
+        trie.remove("dove");
+        assert_eq!(trie.shortest_unique_prefix("dog"), Some("do".to_string()));
+    }
+
     #[test]
     fn from_iterator() {
         let words = vec!["hello", "world", "help"];
@@ -356,4 +692,74 @@ mod tests {
         assert!(trie.starts_with(""));
         assert!(trie.starts_with("a"));
     }
+
+    #[test]
+    fn most_common_prefix() {
+        let trie: Trie = ["interview", "internet", "internal", "car"]
+            .into_iter()
+            .collect();
+
+        assert_eq!(trie.most_common_prefix(4), Some(("inter".to_string(), 3)));
+        assert_eq!(trie.most_common_prefix(6), Some(("intern".to_string(), 2)));
+        assert_eq!(
+            trie.most_common_prefix(9),
+            Some(("interview".to_string(), 1))
+        );
+        assert_eq!(trie.most_common_prefix(10), None);
+        assert_eq!(Trie::new().most_common_prefix(0), None);
+    }
+
+    #[test]
+    fn for_each_word_reuses_buffer() {
+        let trie: Trie = ["car", "cart", "care", "cat", "dog", "do", "é"]
+            .into_iter()
+            .collect();
+
+        let mut count = 0;
+        let mut seen = Vec::new();
+        trie.for_each_word(|word| {
+            count += 1;
+            assert!(trie.contains(word), "{word} leaked from a sibling branch");
+            seen.push(word.to_string());
+        });
+
+        assert_eq!(count, trie.word_count());
+        seen.sort();
+        assert_eq!(seen, ["car", "care", "cart", "cat", "do", "dog", "é"]);
+    }
+
+    #[test]
+    fn display_sorted_words() {
+        let trie: Trie = ["cat", "car", "apple", "care"].into_iter().collect();
+        assert_eq!(trie.to_string(), "[apple, car, care, cat]");
+        assert_eq!(trie.fmt_limited(2).to_string(), "[apple, car, ...]");
+        assert_eq!(Trie::new().to_string(), "[]");
+    }
+
+    #[test]
+    fn batch_membership_and_intersection() {
+        let english: Trie = ["cat", "car", "cart", "dog", "do", "zebra"]
+            .into_iter()
+            .collect();
+        let typed = ["dog", "cta", "car", "carts", "do"];
+
+        assert!(english.contains_all(["cat", "do"]));
+        assert!(!english.contains_all(typed));
+        assert_eq!(english.missing_from(typed), vec!["cta", "carts"]);
+
+        let other: Trie = ["cart", "do", "dot", "zeb", "zebra", "ca"]
+            .into_iter()
+            .collect();
+        let mut common = english.intersect_words(&other);
+        common.sort();
+
+        let left: HashSet<String> = english.all_words().into_iter().collect();
+        let right: HashSet<String> = other.all_words().into_iter().collect();
+        let mut expected: Vec<String> = left.intersection(&right).cloned().collect();
+        expected.sort();
+
+        assert_eq!(common, expected);
+        assert_eq!(common, ["cart", "do", "zebra"]);
+        assert!(english.intersect_words(&Trie::new()).is_empty());
+    }
 }
@@ -0,0 +1,147 @@
+//! Compact `Display` rendering shared by the collections
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Number of elements a collection's `Display` output shows before eliding the rest
+pub const DISPLAY_LIMIT: usize = 32;
+
+/// A trait for collections whose `Display` output can be capped
+///
+/// `Display` uses [`DISPLAY_LIMIT`]; [`fmt_limited`](Self::fmt_limited) picks
+/// another cap, so logging a huge collection stays short.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_ds_lib_bee::{BinarySearchTree, DisplayLimited};
+///
+/// let tree: BinarySearchTree<i32> = (1..=5).collect();
+/// assert_eq!(tree.to_string(), "[1, 2, 3, 4, 5]");
+/// assert_eq!(tree.fmt_limited(2).to_string(), "[1, 2, ...]");
+/// ```
+pub trait DisplayLimited {
+    /// Writes at most `limit` elements, followed by `...` if any were left out
+    fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result;
+
+    /// Returns a wrapper whose `Display` output shows at most `limit` elements
+    fn fmt_limited(&self, limit: usize) -> Limited<'_, Self> {
+        Limited { inner: self, limit }
+    }
+}
+
+/// `Display` adapter returned by [`DisplayLimited::fmt_limited`]
+pub struct Limited<'a, C: ?Sized> {
+    inner: &'a C,
+    limit: usize,
+}
+
+impl<C: DisplayLimited + ?Sized> fmt::Display for Limited<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt_with_limit(f, self.limit)
+    }
+}
+
+/// Displays through a closure, for items that need custom formatting
+pub(crate) struct DisplayFn<F>(F);
+
+pub(crate) fn display_fn<F>(f: F) -> DisplayFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    DisplayFn(f)
+}
+
+impl<F> fmt::Display for DisplayFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Writes `items` comma-separated between `open` and `close`, eliding
+/// everything past the first `limit`
+pub(crate) fn write_list<I>(
+    f: &mut fmt::Formatter<'_>,
+    open: &str,
+    close: &str,
+    items: I,
+    limit: usize,
+) -> fmt::Result
+where
+    I: IntoIterator,
+    I::Item: fmt::Display,
+{
+    f.write_str(open)?;
+
+    let mut items = items.into_iter();
+    for (i, item) in items.by_ref().take(limit).enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+
+    if items.next().is_some() {
+        if limit > 0 {
+            f.write_str(", ")?;
+        }
+        f.write_str("...")?;
+    }
+
+    f.write_str(close)
+}
+
+/// Keeps the `count` smallest items under `compare`, in sorted order
+///
+/// Selects before sorting, so rendering the head of a large collection does
+/// not sort all of it.
+pub(crate) fn smallest_sorted<T, F>(mut items: Vec<T>, count: usize, compare: F) -> Vec<T>
+where
+    F: Fn(&T, &T) -> Ordering + Copy,
+{
+    if count < items.len() {
+        if count > 0 {
+            items.select_nth_unstable_by(count - 1, compare);
+        }
+        items.truncate(count);
+    }
+    items.sort_by(compare);
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Numbers(Vec<i32>);
+
+    impl DisplayLimited for Numbers {
+        fn fmt_with_limit(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+            write_list(f, "[", "]", &self.0, limit)
+        }
+    }
+
+    #[test]
+    fn write_list_elides_past_limit() {
+        let numbers = Numbers(vec![3, 1, 2]);
+
+        assert_eq!(numbers.fmt_limited(3).to_string(), "[3, 1, 2]");
+        assert_eq!(numbers.fmt_limited(2).to_string(), "[3, 1, ...]");
+        assert_eq!(numbers.fmt_limited(0).to_string(), "[...]");
+        assert_eq!(Numbers(vec![]).fmt_limited(0).to_string(), "[]");
+    }
+
+    #[test]
+    fn smallest_sorted_selects_head() {
+        let items = vec![9, 4, 7, 1, 8, 2];
+        assert_eq!(smallest_sorted(items.clone(), 3, i32::cmp), vec![1, 2, 4]);
+        assert_eq!(
+            smallest_sorted(items.clone(), 0, i32::cmp),
+            Vec::<i32>::new()
+        );
+        assert_eq!(smallest_sorted(items, 10, i32::cmp), vec![1, 2, 4, 7, 8, 9]);
+    }
+}
@@ -1,5 +1,8 @@
 //! Common utilities and traits used across data structures
 
+pub mod display;
+pub(crate) mod rng;
 pub mod traits;
 
+pub use display::{DisplayLimited, Limited, DISPLAY_LIMIT};
 pub use traits::*;
@@ -0,0 +1,29 @@
+//! Small seeded generator for reproducible sampling and test data
+
+/// A 64-bit linear congruential generator
+///
+/// Cheap and deterministic for a given seed, which is all sampling and
+/// randomized tests need; the output is predictable, so it must not be used
+/// where that matters.
+#[derive(Debug, Clone)]
+pub(crate) struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Advances the generator and returns its 31 high bits, which are far
+    /// better distributed than the low ones
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        self.state >> 33
+    }
+
+    /// Returns a value in `0..bound`
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
@@ -39,3 +39,72 @@ pub trait PeekMut<T> {
     /// Returns a mutable reference to the element that would be returned by the next pop/dequeue operation
     fn peek_mut(&mut self) -> Option<&mut T>;
 }
+
+/// A trait for containers that can move all of their elements out at once
+pub trait Drain {
+    /// The type of element yielded by the drain
+    type Item;
+
+    /// Removes all elements, yielding them through the returned iterator
+    ///
+    /// The container is empty afterwards, even if the iterator is dropped early.
+    fn drain(&mut self) -> Box<dyn Iterator<Item = Self::Item> + '_>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinaryHeap, HashMap, HashSet, Queue, Stack};
+
+    fn drain_all<C: Drain + Size>(container: &mut C) -> Vec<C::Item> {
+        let drained = container.drain().collect();
+        assert!(container.is_empty());
+        drained
+    }
+
+    #[test]
+    fn drain_empties_containers() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(drain_all(&mut stack), vec![2, 1]);
+
+        let mut queue = Queue::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        queue.dequeue();
+        assert_eq!(drain_all(&mut queue), vec![2, 3]);
+
+        let mut heap: BinaryHeap<_> = (0..10).collect();
+        let mut drained = drain_all(&mut heap);
+        drained.sort();
+        assert_eq!(drained, (0..10).collect::<Vec<_>>());
+
+        let mut map: HashMap<_, _> = (0..100).map(|i| (i, i * 2)).collect();
+        let mut drained = drain_all(&mut map);
+        drained.sort();
+        assert_eq!(drained, (0..100).map(|i| (i, i * 2)).collect::<Vec<_>>());
+        map.insert(1, 1);
+        assert_eq!(map.get(&1), Some(&1));
+
+        let mut set: HashSet<_> = ["a", "b"].into_iter().collect();
+        let mut drained = drain_all(&mut set);
+        drained.sort();
+        assert_eq!(drained, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn partial_drain_still_empties() {
+        let mut map: HashMap<_, _> = (0..10).map(|i| (i, i)).collect();
+        assert!(map.drain().next().is_some());
+        assert!(map.is_empty());
+        assert!(map.iter().next().is_none());
+
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        drop(stack.drain());
+        assert!(stack.is_empty());
+    }
+}
@@ -0,0 +1,382 @@
+//! Differential tests driving random operation sequences against std oracles
+//!
+//! Every operation has a compact text form. When a run diverges from the
+//! oracle, the assertion message contains the script executed so far, which
+//! can be pasted into one of the `replay_*` tests below to reproduce it.
+
+use proptest::prelude::*;
+use rust_ds_lib_bee::*;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap as StdBinaryHeap, HashMap as StdHashMap};
+use std::fmt;
+
+const RANGE_TAKE: usize = 4;
+
+#[derive(Debug, Clone, Copy)]
+enum TreeOp {
+    Insert(i16),
+    Remove(i16),
+    Contains(i16),
+    RangeFrom(i16),
+    Min,
+    Max,
+}
+
+impl fmt::Display for TreeOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeOp::Insert(v) => write!(f, "+{v}"),
+            TreeOp::Remove(v) => write!(f, "-{v}"),
+            TreeOp::Contains(v) => write!(f, "?{v}"),
+            TreeOp::RangeFrom(v) => write!(f, ">{v}"),
+            TreeOp::Min => write!(f, "m"),
+            TreeOp::Max => write!(f, "M"),
+        }
+    }
+}
+
+impl TreeOp {
+    fn parse(token: &str) -> Self {
+        let value = || token[1..].parse().expect("invalid value in script");
+        match &token[..1] {
+            "+" => TreeOp::Insert(value()),
+            "-" => TreeOp::Remove(value()),
+            "?" => TreeOp::Contains(value()),
+            ">" => TreeOp::RangeFrom(value()),
+            "m" => TreeOp::Min,
+            "M" => TreeOp::Max,
+            other => panic!("unknown tree op `{other}`"),
+        }
+    }
+}
+
+fn tree_op() -> impl Strategy<Value = TreeOp> {
+    // A small key space keeps duplicate inserts and hits on remove frequent
+    let value = 0..64i16;
+    prop_oneof![
+        4 => value.clone().prop_map(TreeOp::Insert),
+        3 => value.clone().prop_map(TreeOp::Remove),
+        2 => value.clone().prop_map(TreeOp::Contains),
+        1 => value.prop_map(TreeOp::RangeFrom),
+        1 => Just(TreeOp::Min),
+        1 => Just(TreeOp::Max),
+    ]
+}
+
+trait OrderedSet: Default {
+    const NAME: &'static str;
+
+    fn insert(&mut self, value: i16) -> bool;
+    fn remove(&mut self, value: &i16) -> bool;
+    fn contains(&self, value: &i16) -> bool;
+    fn range_from(&self, start: &i16) -> Vec<i16>;
+    fn min(&self) -> Option<i16>;
+    fn max(&self) -> Option<i16>;
+    fn len(&self) -> usize;
+    fn invariants_hold(&self) -> bool;
+}
+
+macro_rules! ordered_set {
+    ($tree:ident, $invariants:expr) => {
+        impl OrderedSet for $tree<i16> {
+            const NAME: &'static str = stringify!($tree);
+
+            fn insert(&mut self, value: i16) -> bool {
+                $tree::insert(self, value)
+            }
+
+            fn remove(&mut self, value: &i16) -> bool {
+                $tree::remove(self, value)
+            }
+
+            fn contains(&self, value: &i16) -> bool {
+                $tree::contains(self, value)
+            }
+
+            fn range_from(&self, start: &i16) -> Vec<i16> {
+                self.iter_from(start).take(RANGE_TAKE).cloned().collect()
+            }
+
+            fn min(&self) -> Option<i16> {
+                $tree::min(self).cloned()
+            }
+
+            fn max(&self) -> Option<i16> {
+                $tree::max(self).cloned()
+            }
+
+            fn len(&self) -> usize {
+                Size::len(self)
+            }
+
+            fn invariants_hold(&self) -> bool {
+                let check: fn(&$tree<i16>) -> bool = $invariants;
+                check(self)
+            }
+        }
+    };
+}
+
+ordered_set!(BinarySearchTree, |tree| tree
+    .iter()
+    .zip(tree.iter().skip(1))
+    .all(|(a, b)| a < b));
+ordered_set!(AvlTree, |tree| tree.is_balanced());
+ordered_set!(RedBlackTree, |tree| tree.is_valid_red_black_tree());
+
+fn check_tree<S: OrderedSet>(ops: &[TreeOp]) -> Result<(), TestCaseError> {
+    let mut tree = S::default();
+    let mut oracle = BTreeSet::new();
+    let mut script = Vec::with_capacity(ops.len());
+
+    for &op in ops {
+        script.push(op.to_string());
+        let context = || format!("{} diverged after script `{}`", S::NAME, script.join(" "));
+
+        match op {
+            TreeOp::Insert(v) => prop_assert_eq!(tree.insert(v), oracle.insert(v), "{}", context()),
+            TreeOp::Remove(v) => {
+                prop_assert_eq!(tree.remove(&v), oracle.remove(&v), "{}", context())
+            }
+            TreeOp::Contains(v) => {
+                prop_assert_eq!(tree.contains(&v), oracle.contains(&v), "{}", context())
+            }
+            TreeOp::RangeFrom(v) => {
+                let expected: Vec<_> = oracle.range(v..).take(RANGE_TAKE).cloned().collect();
+                prop_assert_eq!(tree.range_from(&v), expected, "{}", context());
+            }
+            TreeOp::Min => prop_assert_eq!(tree.min(), oracle.first().cloned(), "{}", context()),
+            TreeOp::Max => prop_assert_eq!(tree.max(), oracle.last().cloned(), "{}", context()),
+        }
+
+        prop_assert_eq!(tree.len(), oracle.len(), "{}", context());
+        prop_assert!(tree.invariants_hold(), "{}: invariant broken", context());
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HeapOp {
+    Push(i16),
+    Pop,
+    Peek,
+}
+
+impl fmt::Display for HeapOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeapOp::Push(v) => write!(f, "+{v}"),
+            HeapOp::Pop => write!(f, "p"),
+            HeapOp::Peek => write!(f, "k"),
+        }
+    }
+}
+
+impl HeapOp {
+    fn parse(token: &str) -> Self {
+        match &token[..1] {
+            "+" => HeapOp::Push(token[1..].parse().expect("invalid value in script")),
+            "p" => HeapOp::Pop,
+            "k" => HeapOp::Peek,
+            other => panic!("unknown heap op `{other}`"),
+        }
+    }
+}
+
+fn heap_op() -> impl Strategy<Value = HeapOp> {
+    prop_oneof![
+        3 => (0..64i16).prop_map(HeapOp::Push),
+        2 => Just(HeapOp::Pop),
+        1 => Just(HeapOp::Peek),
+    ]
+}
+
+fn check_heaps(ops: &[HeapOp]) -> Result<(), TestCaseError> {
+    let mut max_heap = BinaryHeap::max_heap();
+    let mut min_heap = BinaryHeap::min_heap();
+    let mut max_oracle = StdBinaryHeap::new();
+    let mut min_oracle = StdBinaryHeap::new();
+    let mut script = Vec::with_capacity(ops.len());
+
+    for &op in ops {
+        script.push(op.to_string());
+        let context = || format!("BinaryHeap diverged after script `{}`", script.join(" "));
+
+        match op {
+            HeapOp::Push(v) => {
+                max_heap.push(v);
+                min_heap.push(v);
+                max_oracle.push(v);
+                min_oracle.push(Reverse(v));
+            }
+            HeapOp::Pop => {
+                prop_assert_eq!(max_heap.pop(), max_oracle.pop(), "max: {}", context());
+                prop_assert_eq!(
+                    min_heap.pop(),
+                    min_oracle.pop().map(|Reverse(v)| v),
+                    "min: {}",
+                    context()
+                );
+            }
+            HeapOp::Peek => {
+                prop_assert_eq!(max_heap.peek(), max_oracle.peek(), "max: {}", context());
+                prop_assert_eq!(
+                    min_heap.peek(),
+                    min_oracle.peek().map(|Reverse(v)| v),
+                    "min: {}",
+                    context()
+                );
+            }
+        }
+
+        prop_assert_eq!(max_heap.len(), max_oracle.len(), "{}", context());
+        prop_assert_eq!(min_heap.len(), min_oracle.len(), "{}", context());
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MapOp {
+    Insert(i16, i32),
+    Remove(i16),
+    Get(i16),
+}
+
+impl fmt::Display for MapOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapOp::Insert(k, v) => write!(f, "+{k}={v}"),
+            MapOp::Remove(k) => write!(f, "-{k}"),
+            MapOp::Get(k) => write!(f, "?{k}"),
+        }
+    }
+}
+
+impl MapOp {
+    fn parse(token: &str) -> Self {
+        let key = |text: &str| text.parse().expect("invalid key in script");
+        match &token[..1] {
+            "+" => {
+                let (k, v) = token[1..]
+                    .split_once('=')
+                    .expect("insert needs `key=value`");
+                MapOp::Insert(key(k), v.parse().expect("invalid value in script"))
+            }
+            "-" => MapOp::Remove(key(&token[1..])),
+            "?" => MapOp::Get(key(&token[1..])),
+            other => panic!("unknown map op `{other}`"),
+        }
+    }
+}
+
+fn map_op() -> impl Strategy<Value = MapOp> {
+    let key = 0..128i16;
+    prop_oneof![
+        4 => (key.clone(), any::<i32>()).prop_map(|(k, v)| MapOp::Insert(k, v)),
+        2 => key.clone().prop_map(MapOp::Remove),
+        2 => key.prop_map(MapOp::Get),
+    ]
+}
+
+fn check_map(ops: &[MapOp]) -> Result<(), TestCaseError> {
+    // Few initial buckets so runs cross several resizes
+    let mut map = HashMap::with_capacity(2);
+    let mut oracle = StdHashMap::new();
+    let mut script = Vec::with_capacity(ops.len());
+
+    for &op in ops {
+        script.push(op.to_string());
+        let context = || format!("HashMap diverged after script `{}`", script.join(" "));
+
+        match op {
+            MapOp::Insert(k, v) => {
+                prop_assert_eq!(map.insert(k, v), oracle.insert(k, v), "{}", context())
+            }
+            MapOp::Remove(k) => prop_assert_eq!(map.remove(&k), oracle.remove(&k), "{}", context()),
+            MapOp::Get(k) => prop_assert_eq!(map.get(&k), oracle.get(&k), "{}", context()),
+        }
+
+        prop_assert_eq!(map.len(), oracle.len(), "{}", context());
+    }
+
+    let mut entries: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+    let mut expected: Vec<_> = oracle.into_iter().collect();
+    entries.sort_unstable();
+    expected.sort_unstable();
+    prop_assert_eq!(
+        entries,
+        expected,
+        "final contents differ after `{}`",
+        script.join(" ")
+    );
+
+    Ok(())
+}
+
+fn parse_script<Op>(script: &str, parse: fn(&str) -> Op) -> Vec<Op> {
+    script.split_whitespace().map(parse).collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn bst_matches_btreeset(ops in prop::collection::vec(tree_op(), 0..200)) {
+        check_tree::<BinarySearchTree<i16>>(&ops)?;
+    }
+
+    #[test]
+    fn avl_matches_btreeset(ops in prop::collection::vec(tree_op(), 0..200)) {
+        check_tree::<AvlTree<i16>>(&ops)?;
+    }
+
+    #[test]
+    fn red_black_matches_btreeset(ops in prop::collection::vec(tree_op(), 0..200)) {
+        check_tree::<RedBlackTree<i16>>(&ops)?;
+    }
+
+    #[test]
+    fn binary_heap_matches_std(ops in prop::collection::vec(heap_op(), 0..200)) {
+        check_heaps(&ops)?;
+    }
+
+    #[test]
+    fn hashmap_matches_std(ops in prop::collection::vec(map_op(), 0..300)) {
+        check_map(&ops)?;
+    }
+}
+
+#[test]
+fn replay_tree_script() {
+    let ops = parse_script("+5 +3 +8 +3 -5 ?5 >4 m M -3 -8 m +1", TreeOp::parse);
+    check_tree::<BinarySearchTree<i16>>(&ops).unwrap();
+    check_tree::<AvlTree<i16>>(&ops).unwrap();
+    check_tree::<RedBlackTree<i16>>(&ops).unwrap();
+}
+
+#[test]
+fn replay_heap_script() {
+    let ops = parse_script("+4 +9 +1 k p +9 p p k p p", HeapOp::parse);
+    check_heaps(&ops).unwrap();
+}
+
+#[test]
+fn replay_map_script() {
+    let ops = parse_script("+1=10 +2=20 +1=11 ?1 -2 ?2 -2 +3=-7", MapOp::parse);
+    check_map(&ops).unwrap();
+}
+
+#[test]
+fn scripts_round_trip() {
+    let tree_script = "+5 -3 ?7 >2 m M";
+    let tree_ops = parse_script(tree_script, TreeOp::parse);
+    let rendered: Vec<_> = tree_ops.iter().map(ToString::to_string).collect();
+    assert_eq!(rendered.join(" "), tree_script);
+
+    let map_script = "+1=-4 -1 ?2";
+    let map_ops = parse_script(map_script, MapOp::parse);
+    let rendered: Vec<_> = map_ops.iter().map(ToString::to_string).collect();
+    assert_eq!(rendered.join(" "), map_script);
+}
@@ -104,6 +104,22 @@ proptest! {
         }
     }
 
+    #[test]
+    fn heapsort_matches_std_sort(values in prop::collection::vec(0..100i32, 0..200)) {
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut actual = values.clone();
+        heap::heapsort(&mut actual);
+        prop_assert_eq!(&actual, &expected);
+
+        if !values.is_empty() {
+            let n = values.len() / 2;
+            let mut slice = values;
+            prop_assert_eq!(heap::select_nth_unstable_by_heap(&mut slice, n), &expected[n]);
+        }
+    }
+
     #[test]
     fn trie_prefix_properties(words in prop::collection::vec("[a-z]{1,10}", 0..50)) {
         let mut trie = Trie::new();
@@ -156,7 +172,8 @@ proptest! {
             prop_assert!(filter.contains(value), "False negative for inserted value: {}", value);
         }
 
-        prop_assert_eq!(filter.len(), values.len());
+        let distinct: std::collections::HashSet<_> = values.iter().collect();
+        prop_assert!(filter.len() <= distinct.len());
     }
 
     #[test]
@@ -175,4 +192,51 @@ proptest! {
 
         prop_assert_eq!(sorted_values, expected);
     }
+
+    #[test]
+    fn sliding_window_matches_brute_force(
+        values in prop::collection::vec(-100..100i32, 1..200),
+        size in 1..50usize,
+    ) {
+        let mut window = linear::SlidingWindow::new(size);
+        for (i, &value) in values.iter().enumerate() {
+            window.push(value);
+
+            let expected = &values[(i + 1).saturating_sub(size)..=i];
+            prop_assert_eq!(window.len(), expected.len());
+            prop_assert_eq!(window.min(), expected.iter().min());
+            prop_assert_eq!(window.max(), expected.iter().max());
+            prop_assert_eq!(window.sum(), expected.iter().sum::<i32>());
+        }
+    }
+
+    #[test]
+    fn moving_median_matches_brute_force(
+        values in prop::collection::vec(0..50i32, 1..200),
+        size in 1..50usize,
+    ) {
+        let mut tracker = linear::MovingMedian::new(size);
+        for (i, &value) in values.iter().enumerate() {
+            tracker.push(value);
+
+            let mut window = values[(i + 1).saturating_sub(size)..=i].to_vec();
+            window.sort();
+            let n = window.len();
+            prop_assert_eq!(tracker.len(), n);
+            prop_assert_eq!(tracker.median(), Some(&window[(n - 1) / 2]));
+            prop_assert_eq!(
+                tracker.middle_pair(),
+                Some((&window[(n - 1) / 2], &window[n / 2]))
+            );
+        }
+    }
+
+    #[test]
+    fn inversions_match_brute_force(values in prop::collection::vec(0..20u32, 0..300)) {
+        let brute_force = (0..values.len())
+            .flat_map(|i| (i + 1..values.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| values[i] > values[j])
+            .count() as u64;
+        prop_assert_eq!(tree::count_inversions(&values), brute_force);
+    }
 }